@@ -78,7 +78,7 @@ setup_i64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 
 teardown_i64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocator) -> time.Benchmark_Error {
 	test_i64 := i64(9223372036854775807) // i64::MAX
-	len := num_format.itoa_i64(test_i64, raw_data(buf_i64), c.uint(ITOA_BUFFER_SIZE))
+	len := num_format.itoa_i64(test_i64, raw_data(buf_i64), c.size_t(ITOA_BUFFER_SIZE))
 	if len > 0 {
 		fmt.printf("Sample i64 output: %s\n", string(buf_i64[:len]))
 	}
@@ -90,7 +90,7 @@ bench_i64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 	test_i64 := i64(9223372036854775807) // i64::MAX
 
 	for _ in 0 ..< COUNT_ITERATIONS {
-		_ = num_format.itoa_i64(test_i64, raw_data(buf_i64), c.uint(ITOA_BUFFER_SIZE))
+		_ = num_format.itoa_i64(test_i64, raw_data(buf_i64), c.size_t(ITOA_BUFFER_SIZE))
 	}
 	options.count = COUNT_ITERATIONS
 
@@ -104,7 +104,7 @@ setup_u64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 
 teardown_u64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocator) -> time.Benchmark_Error {
 	test_u64 := u64(18446744073709551615) // u64::MAX
-	len := num_format.itoa_u64(test_u64, raw_data(buf_u64), c.uint(ITOA_BUFFER_SIZE))
+	len := num_format.itoa_u64(test_u64, raw_data(buf_u64), c.size_t(ITOA_BUFFER_SIZE))
 	if len > 0 {
 		fmt.printf("Sample u64 output: %s\n", string(buf_u64[:len]))
 	}
@@ -116,7 +116,7 @@ bench_u64 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 	test_u64 := u64(18446744073709551615) // u64::MAX
 
 	for _ in 0 ..< COUNT_ITERATIONS {
-		_ = num_format.itoa_u64(test_u64, raw_data(buf_u64), c.uint(ITOA_BUFFER_SIZE))
+		_ = num_format.itoa_u64(test_u64, raw_data(buf_u64), c.size_t(ITOA_BUFFER_SIZE))
 	}
 	options.count = COUNT_ITERATIONS
 
@@ -130,7 +130,7 @@ setup_i32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 
 teardown_i32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocator) -> time.Benchmark_Error {
 	test_i32 := i32(2147483647) // i32::MAX
-	len := num_format.itoa_i32(test_i32, raw_data(buf_i32), c.uint(ITOA_BUFFER_SIZE))
+	len := num_format.itoa_i32(test_i32, raw_data(buf_i32), c.size_t(ITOA_BUFFER_SIZE))
 	if len > 0 {
 		fmt.printf("Sample i32 output: %s\n", string(buf_i32[:len]))
 	}
@@ -142,7 +142,7 @@ bench_i32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 	test_i32 := i32(2147483647) // i32::MAX
 
 	for _ in 0 ..< COUNT_ITERATIONS {
-		_ = num_format.itoa_i32(test_i32, raw_data(buf_i32), c.uint(ITOA_BUFFER_SIZE))
+		_ = num_format.itoa_i32(test_i32, raw_data(buf_i32), c.size_t(ITOA_BUFFER_SIZE))
 	}
 	options.count = COUNT_ITERATIONS
 
@@ -156,7 +156,7 @@ setup_u32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 
 teardown_u32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocator) -> time.Benchmark_Error {
 	test_u32 := u32(4294967295) // u32::MAX
-	len := num_format.itoa_u32(test_u32, raw_data(buf_u32), c.uint(ITOA_BUFFER_SIZE))
+	len := num_format.itoa_u32(test_u32, raw_data(buf_u32), c.size_t(ITOA_BUFFER_SIZE))
 	if len > 0 {
 		fmt.printf("Sample u32 output: %s\n", string(buf_u32[:len]))
 	}
@@ -168,7 +168,7 @@ bench_u32 :: proc(options: ^time.Benchmark_Options, allocator := context.allocat
 	test_u32 := u32(4294967295) // u32::MAX
 
 	for _ in 0 ..< COUNT_ITERATIONS {
-		_ = num_format.itoa_u32(test_u32, raw_data(buf_u32), c.uint(ITOA_BUFFER_SIZE))
+		_ = num_format.itoa_u32(test_u32, raw_data(buf_u32), c.size_t(ITOA_BUFFER_SIZE))
 	}
 	options.count = COUNT_ITERATIONS
 
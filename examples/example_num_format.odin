@@ -38,7 +38,7 @@ main :: proc() {
 		defer delete(buf)
 
 		value := 3.14159
-		len := num_format.format_f64(value, raw_data(buf), c.uint(cap(buf)))
+		len := num_format.format_f64(value, raw_data(buf), c.size_t(cap(buf)))
 
 		if len > 0 {
 			str := string(buf[:len])
@@ -56,7 +56,7 @@ main :: proc() {
 		defer delete(buf)
 
 		value := f32(2.71828)
-		len := num_format.format_f32(value, raw_data(buf), c.uint(cap(buf)))
+		len := num_format.format_f32(value, raw_data(buf), c.size_t(cap(buf)))
 
 		if len > 0 {
 			str := string(buf[:len])
@@ -114,7 +114,7 @@ main :: proc() {
 		value := 123.456
 
 		// Use optimized path (no NaN/inf checks)
-		len := num_format.format_finite_f64(value, raw_data(buf), c.uint(cap(buf)))
+		len := num_format.format_finite_f64(value, raw_data(buf), c.size_t(cap(buf)))
 
 		if len > 0 {
 			str := string(buf[:len])
@@ -130,7 +130,7 @@ main :: proc() {
 		defer delete(small_buf)
 
 		value := 123456.789
-		len := num_format.format_f64(value, raw_data(small_buf), c.uint(cap(small_buf)))
+		len := num_format.format_f64(value, raw_data(small_buf), c.size_t(cap(small_buf)))
 
 		if len == 0 {
 			fmt.println("  ✓ Correctly returned 0 for undersized buffer")
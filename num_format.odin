@@ -22,19 +22,350 @@ Memory requirements:
 
 Library requirements:
     - Link against: num_format_ffi library
-    - Include path: path to num_format.h
+    - C header: rust-ffi/include/num_format.h (generated, all lengths are size_t)
     - Platforms: Windows, Linux, macOS
 */
 
 package num_format
 
+import "base:intrinsics"
 import "core:c"
+import "core:fmt"
+import "core:io"
+import "core:strings"
+import "core:time"
 
 /* Constants */
 
 /// Recommended buffer size for all floating-point numbers
 BUFFER_SIZE :: 24
 
+/// Buffer size the itoa_* procedures require (i128::MAX_STR_LEN)
+ITOA_BUFFER_SIZE :: 40
+
+/// Buffer size that fits any integer in any radix (sign, prefix, 128 digits)
+ITOA_RADIX_BUFFER_SIZE :: 131
+
+/// Largest width the padded formatters accept
+ITOA_MAX_WIDTH :: 4096
+
+/// Buffer size that fits any RFC 3339 timestamp ("9999-12-31T23:59:59.999999999+23:59")
+TIMESTAMP_BUFFER_SIZE :: 35
+
+/// Largest scale format_scaled_i64 and parse_scaled_i64 accept (10^18 fits in i64)
+SCALED_MAX_SCALE :: 18
+
+/// Largest number of fraction digits format_bytes accepts
+BYTES_MAX_PRECISION :: 18
+
+/// Largest precision accepted by the fixed-notation formatters
+MAX_FIXED_PRECISION :: 1074
+
+/* Types */
+
+/// Options for the scientific-notation formatters (mirrors ZmijSciOptions)
+Sci_Options :: struct {
+	precision:      i32, // Digits after the mantissa's decimal point, or < 0 for shortest round-trip
+	uppercase:      bool, // Use 'E' instead of 'e'
+	exp_plus_sign:  bool, // Always print the exponent sign ("e+7")
+	min_exp_digits: u8, // Zero-pad the exponent to at least this many digits
+}
+
+/// Shortest round-trip mantissa with a plain exponent, e.g. "1.2345e-7"
+DEFAULT_SCI_OPTIONS :: Sci_Options {
+	precision      = -1,
+	min_exp_digits = 1,
+}
+
+/// Flags for the radix formatters (mirrors ITOA_RADIX_*)
+Radix_Flag :: enum u32 {
+	Uppercase       = 0, // Use 'A'-'Z' for digits above 9
+	Prefix          = 1, // "0x", "0o" or "0b" for base 16, 8 and 2
+	Twos_Complement = 2, // Print negatives as the bit pattern of their width
+}
+
+Radix_Flags :: bit_set[Radix_Flag;u32]
+
+/// Alignment for the padded integer formatters (mirrors ITOA_ALIGN_*)
+Align :: enum u8 {
+	Right    = 0, // Fill before the number
+	Left     = 1, // Fill after the number
+	Center   = 2, // Fill on both sides, the extra character going to the right
+	Zero_Pad = 3, // Pad with '0' between the sign and the digits ("-0042"), ignoring fill
+}
+
+/// Width, fill and sign options for the padded formatters (mirrors ItoaPadOptions)
+///
+/// The zero value means no padding and no forced sign.
+Pad_Options :: struct {
+	width:     u32, // Minimum width in characters, counting the sign (at most ITOA_MAX_WIDTH)
+	fill:      rune, // Fill character, 0 for a space
+	align:     Align,
+	plus_sign: bool, // Print '+' before non-negative values
+}
+
+/// Flags for the significant-digits formatters (mirrors ZMIJ_SIG_*)
+Sig_Flag :: enum u32 {
+	Keep_Trailing_Zeros = 0, // Keep trailing zeros and the decimal point, like "%#g"
+	Uppercase           = 1, // Use 'E' as the exponent marker, like "%G"
+}
+
+Sig_Flags :: bit_set[Sig_Flag;u32]
+
+/// Spelling presets for NaN and infinities (mirrors ZMIJ_NON_FINITE_*)
+Non_Finite :: enum u8 {
+	Zmij = 0, // "NaN", "inf", "-inf" like format_f64
+	C    = 1, // "nan", "inf", "-inf"
+	JS   = 2, // "NaN", "Infinity", "-Infinity"
+	YAML = 3, // ".nan", ".inf", "-.inf"
+	JSON = 4, // "null" for every non-finite value
+}
+
+/// Non-finite spellings and negative zero handling (mirrors ZmijFloatPolicy)
+///
+/// nil strings select the preset's spelling. Build one with make_float_policy;
+/// the zero value behaves like format_f64.
+Float_Policy :: struct {
+	preset:                  Non_Finite,
+	normalize_negative_zero: bool, // Print -0.0 as "0.0"
+	nan:                     [^]u8,
+	nan_len:                 c.size_t,
+	inf:                     [^]u8,
+	inf_len:                 c.size_t,
+	neg_inf:                 [^]u8,
+	neg_inf_len:             c.size_t,
+}
+
+/// Options for the JSON formatters (mirrors ZmijJsonOptions)
+///
+/// The zero value keeps `.0` on integral values and fails on NaN/infinity.
+/// Build one with make_json_options.
+Json_Options :: struct {
+	integers_without_fraction: bool, // Print integral values up to 2^53 without ".0"
+	non_finite:                [^]u8, // Token for NaN and infinities (nil to fail instead)
+	non_finite_len:            c.size_t,
+}
+
+/// Result of the parse procedures (mirrors NUM_PARSE_*)
+Parse_Error :: enum i32 {
+	None             = 0, // Parsed a finite value or an explicit NaN/infinity
+	Empty            = 1, // The input was empty
+	Invalid          = 2, // The input does not start with a number
+	Overflow         = 3, // Too large for the type; the value is saturated (±inf, integer maximum)
+	Underflow        = 4, // Non-zero but too small for a float type; the value is ±0
+	Neg_Overflow     = 5, // Below an integer type's minimum; the value is saturated
+	Excess_Precision = 6, // More non-zero fraction digits than the scale holds; the value is truncated
+}
+
+/// Separators and grouping pattern for the grouped formatters (mirrors NumGrouping)
+///
+/// Build one with make_grouping; a nil ^Grouping means `{",", ".", 3, 3}`.
+Grouping :: struct {
+	group_sep:       [^]u8, // Separator between digit groups (UTF-8, nil is empty)
+	group_sep_len:   c.size_t,
+	decimal_sep:     [^]u8, // Separator between integer and fraction digits (UTF-8, nil is empty)
+	decimal_sep_len: c.size_t,
+	primary:         u8, // Digits in the group next to the decimal point, 0 disables grouping
+	secondary:       u8, // Digits in every further group, 0 repeats primary (2 for Indian style)
+}
+
+/// Compiled format spec handle (opaque, mirrors NumSpec)
+///
+/// Create with compile_spec and release with destroy_spec.
+Spec :: struct {}
+
+/// Distinct number types that core:fmt prints through num_format
+///
+/// After register_fmt_formatters, `fmt.printf("%8.3f", Fast_F64(x))` uses the
+/// zmij/itoa paths instead of core:fmt's own conversion.
+Fast_F64 :: distinct f64
+Fast_F32 :: distinct f32
+Fast_I64 :: distinct i64
+Fast_U64 :: distinct u64
+
+/// Float notation of a Formatter (mirrors NUM_NOTATION_*)
+Notation :: enum u8 {
+	Shortest   = 0, // Shortest round-trip digits, positional or exponent like format_f64
+	Fixed      = 1, // Exactly `precision` digits after the decimal point (shortest if < 0)
+	Scientific = 2, // Always an exponent, `precision` mantissa digits (shortest if < 0)
+	General    = 3, // `precision` significant digits like printf "%g" (6 if < 0)
+}
+
+/// Configuration for formatter_new (mirrors NumFormatterConfig)
+///
+/// nil separators are empty, as in Grouping; a nil policy selects the spellings of format_f64.
+/// Prefer make_formatter, which takes Odin strings and named arguments.
+Formatter_Config :: struct {
+	decimal_sep:     [^]u8, // Separator between integer and fraction digits (UTF-8, nil is empty)
+	decimal_sep_len: c.size_t,
+	group_sep:       [^]u8, // Separator between digit groups (UTF-8, nil is empty)
+	group_sep_len:   c.size_t,
+	primary:         u8, // Digits in the group next to the decimal point, 0 disables grouping
+	secondary:       u8, // Digits in every further group, 0 repeats primary
+	policy:          ^Float_Policy, // NaN/infinity spellings and negative zero handling
+	notation:        Notation,
+	precision:       i32, // Digits for the notation, or < 0 for its default
+	exp_uppercase:   bool,
+	exp_plus_sign:   bool,
+	min_exp_digits:  u8,
+}
+
+/// Formatting profile handle (opaque, mirrors NumFormatter)
+///
+/// Create with make_formatter and release with destroy_formatter.
+Formatter :: struct {}
+
+/// Unit base for format_bytes (mirrors NUM_BYTES_IEC / NUM_BYTES_SI)
+Byte_Base :: enum u8 {
+	IEC = 0, // 1024-based: KiB, MiB, GiB
+	SI  = 1, // 1000-based: kB, MB, GB
+}
+
+/// Unit spelling for format_bytes (mirrors NUM_BYTES_SHORT / NUM_BYTES_LONG)
+Byte_Units :: enum u8 {
+	Short = 0, // "B", "KiB", "kB"
+	Long  = 1, // "bytes", "kibibytes", "kilobytes"
+}
+
+/// Options for format_bytes (mirrors NumByteSizeOptions)
+///
+/// The zero value prints IEC symbols without fraction digits: "2 GiB".
+Byte_Size_Options :: struct {
+	base:      Byte_Base,
+	units:     Byte_Units,
+	precision: u8, // Fraction digits for scaled values (0..=BYTES_MAX_PRECISION)
+	no_space:  bool, // Omit the space before the unit: "1.5GiB"
+}
+
+/// Suffix table for the compact formatters (mirrors NUM_COMPACT_*)
+Compact_Table :: enum u8 {
+	Short  = 0, // K, M, B, T
+	SI     = 1, // q r y z a f p n µ m k M G T P E Z Y R Q
+	Custom = 2, // Compact_Options.suffixes
+}
+
+/// One entry of a custom compact suffix table (mirrors NumCompactSuffix)
+Compact_Suffix :: struct {
+	exponent:   i32, // Power of ten the suffix stands for (3 for "K")
+	suffix:     [^]u8,
+	suffix_len: c.size_t,
+}
+
+/// Options for the compact formatters (mirrors NumCompactOptions)
+///
+/// The zero value uses short English suffixes and 3 significant digits.
+/// Build one with make_compact_options.
+Compact_Options :: struct {
+	table:         Compact_Table,
+	digits:        u8, // Significant digits, 0 for 3
+	separator:     [^]u8, // Between the number and a non-empty suffix or unit
+	separator_len: c.size_t,
+	unit:          [^]u8, // After the suffix, such as "s" or "Hz"
+	unit_len:      c.size_t,
+	suffixes:      [^]Compact_Suffix, // Custom table, sorted by increasing exponent
+	suffix_count:  c.size_t,
+}
+
+/// Output style for format_duration (mirrors NUM_DURATION_COMPACT / NUM_DURATION_ISO8601)
+Duration_Style :: enum u8 {
+	Compact = 0, // Like time.duration_to_string: "1h2m3.5s", "12.345ms", "850ns"
+	ISO8601 = 1, // "PT1H2M3.5S", "P1DT2H"
+}
+
+/// Largest unit format_duration counts in (mirrors NUM_DURATION_HOURS etc.)
+Duration_Unit :: enum u8 {
+	Hours   = 0, // "26h3m4s"
+	Days    = 1, // "1d2h3m4s" (24-hour days)
+	Minutes = 2, // "1563m4s"
+	Seconds = 3, // "93784s"
+}
+
+/// Options for format_duration (mirrors NumDurationOptions)
+///
+/// Set precision to -1 to keep every fraction digit; the helpers default to that.
+Duration_Options :: struct {
+	style:        Duration_Style,
+	largest_unit: Duration_Unit,
+	precision:    i8, // Maximum fraction digits (0..=9), or -1 for all; trailing zeros are dropped
+}
+
+/// Options for the timestamp formatters (mirrors NumTimestampOptions)
+///
+/// The zero value prints whole seconds in UTC: "2026-10-16T12:34:56Z".
+Timestamp_Options :: struct {
+	fraction_digits: i8, // 0..=9 digits (truncated), or -1 for as many as needed
+	offset_minutes:  i16, // Offset from UTC (-1439..=1439); the time is shown in that zone
+	numeric_utc:     bool, // Write "+00:00" instead of "Z" for a zero offset
+}
+
+/// Sign style for format_scaled_i64 (mirrors NUM_SIGN_*)
+Sign_Style :: enum u8 {
+	Minus = 0, // "-1.50", "1.50"
+	Plus  = 1, // "-1.50", "+1.50"
+	Space = 2, // "-1.50", " 1.50"
+}
+
+/// Options for format_scaled_i64 and parse_scaled_i64 (mirrors NumScaledOptions)
+///
+/// The zero value prints "1234.5": no grouping, no plus sign, trailing zeros dropped.
+Scaled_Options :: struct {
+	min_fraction_digits: u8, // Fraction digits always printed, zero-padded (may exceed the scale)
+	sign:                Sign_Style,
+	grouping:            ^Grouping, // Separators and grouping, or nil for "1234.56"
+}
+
+/// What currency_format shows next to the amount (mirrors NUM_CURRENCY_SYMBOL / _CODE)
+Currency_Display :: enum u8 {
+	Symbol = 0, // "$", "€", "CHF"
+	Code   = 1, // "USD"
+}
+
+/// Where the symbol or code goes (mirrors NUM_CURRENCY_PREFIX / _SUFFIX)
+Currency_Placement :: enum u8 {
+	Prefix = 0, // "$1.00"
+	Suffix = 1, // "1,00 €"
+}
+
+/// Space between amount and symbol or code (mirrors NUM_CURRENCY_SPACE_*)
+Currency_Space :: enum u8 {
+	Auto   = 0, // Except after prefix symbols not ending in a letter: "$1.00", "CHF 1.00", "1.00 €"
+	Never  = 1, // "USD1.00"
+	Always = 2, // "$ 1.00"
+}
+
+/// How negative amounts are marked (mirrors NUM_CURRENCY_NEGATIVE_*)
+Currency_Negative :: enum u8 {
+	Minus      = 0, // "-$1.00"
+	Accounting = 1, // "($1.00)"
+}
+
+/// Configuration for currency_new (mirrors NumCurrencyConfig)
+///
+/// Build one with make_currency, which creates the handle directly.
+Currency_Config :: struct {
+	code:         [^]u8, // ISO 4217 code such as "USD"
+	code_len:     c.size_t,
+	symbol:       [^]u8, // Replaces the table's symbol (nil for the table's)
+	symbol_len:   c.size_t,
+	display:      Currency_Display,
+	placement:    Currency_Placement,
+	space:        Currency_Space,
+	negative:     Currency_Negative,
+	minor_digits: i8, // 0..=SCALED_MAX_SCALE, or -1 for the table's
+	grouping:     ^Grouping, // nil for "1,234,567.89"
+}
+
+/// Currency display profile handle (opaque, mirrors NumCurrency)
+///
+/// Create with make_currency and release with destroy_currency.
+Currency :: struct {}
+
+/// Format and width of one table column (mirrors NumTableColumn)
+Table_Column :: struct {
+	spec:  ^Spec, // Compiled spec, or nil for shortest floats / plain decimal integers
+	width: u32, // Minimum width in characters (at most ITOA_MAX_WIDTH); cells are right-aligned with spaces
+}
+
 /* FFI Function Declarations */
 
 when ODIN_OS == .Windows {
@@ -68,7 +399,7 @@ foreign num_format {
 	///     if len > 0 {
 	///         str := string(buf[:len])
 	///     }
-	format_f64 :: proc(value: f64, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	format_f64 :: proc(value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format f32 floating point to UTF-8 string
 	///
@@ -82,7 +413,7 @@ foreign num_format {
 	///
 	/// Returns:
 	///     Number of bytes written, or 0 if buffer too small/invalid
-	format_f32 :: proc(value: f32, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	format_f32 :: proc(value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format finite f64 (optimized, no NaN/inf checks)
 	///
@@ -99,7 +430,7 @@ foreign num_format {
 	///
 	/// Returns:
 	///     Number of bytes written, or 0 if buffer too small/invalid
-	format_finite_f64 :: proc(value: f64, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	format_finite_f64 :: proc(value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format finite f32 (optimized, no NaN/inf checks)
 	///
@@ -116,7 +447,166 @@ foreign num_format {
 	///
 	/// Returns:
 	///     Number of bytes written, or 0 if buffer too small/invalid
-	format_finite_f32 :: proc(value: f32, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	format_finite_f32 :: proc(value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 with exactly `precision` digits after the decimal point
+	///
+	/// Rounds half-to-even from the exact binary value, so 0.125 with
+	/// precision 2 is "0.12" and 0.375 is "0.38".
+	/// Handles special cases: NaN → "NaN", +∞ → "inf", -∞ → "-inf"
+	///
+	/// Arguments:
+	///     value     - The f64 value to format
+	///     precision - Digits after the decimal point (0..=MAX_FIXED_PRECISION)
+	///     buf       - Output buffer (must be valid and writable)
+	///     buf_len   - Size of output buffer in bytes
+	///
+	/// Returns:
+	///     Number of bytes written, or 0 if buffer too small/invalid
+	///
+	/// Output can be much longer than BUFFER_SIZE (1e308 has 309 integer
+	/// digits), size buffers with fixed_buffer_size_f64(precision).
+	format_fixed_f64 :: proc(value: f64, precision: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 with exactly `precision` digits after the decimal point
+	///
+	/// Size buffers with fixed_buffer_size_f32(precision).
+	format_fixed_f32 :: proc(value: f32, precision: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Buffer size that fits any f64 formatted with format_fixed_f64
+	fixed_buffer_size_f64 :: proc(precision: c.uint) -> c.size_t ---
+
+	/// Buffer size that fits any f32 formatted with format_fixed_f32
+	fixed_buffer_size_f32 :: proc(precision: c.uint) -> c.size_t ---
+
+	/// Format f64 in scientific notation
+	///
+	/// Always emits exponent notation, unlike format_f64 which picks its own
+	/// switch point. The exponent style is controlled by options:
+	///     DEFAULT_SCI_OPTIONS                                  → "1.2345e-7"
+	///     {precision = -1, uppercase = true,
+	///      exp_plus_sign = true, min_exp_digits = 2}            → "1.2345E-07"
+	///     {precision = 2, min_exp_digits = 1}                  → "1.23e-7"
+	/// Handles special cases: NaN → "NaN", +∞ → "inf", -∞ → "-inf"
+	///
+	/// Arguments:
+	///     value   - The f64 value to format
+	///     options - Exponent style and precision (nil = DEFAULT_SCI_OPTIONS)
+	///     buf     - Output buffer (must be valid and writable)
+	///     buf_len - Size of output buffer in bytes
+	///
+	/// Returns:
+	///     Number of bytes written, or 0 if buffer too small/invalid
+	format_sci_f64 :: proc(value: f64, options: ^Sci_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 in scientific notation
+	format_sci_f32 :: proc(value: f32, options: ^Sci_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Buffer size that fits any f64 or f32 formatted with the given options
+	sci_buffer_size :: proc(options: ^Sci_Options) -> c.size_t ---
+
+	/*
+	snprintf-style variants
+
+	Accept any buffer large enough for the actual text (no 24-byte minimum)
+	and always return the length the output requires. The output was written
+	only if the result is <= buf_len; otherwise retry with a buffer of exactly
+	that size. Pass buf = nil, buf_len = 0 to measure without writing.
+	*/
+
+	/// Format f64, returning the required length (written if <= buf_len)
+	///
+	/// Example:
+	///     needed := num_format.format_f64_fit(value, nil, 0)
+	///     buf := make([]u8, needed)
+	///     num_format.format_f64_fit(value, raw_data(buf), needed)
+	format_f64_fit :: proc(value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32, returning the required length (written if <= buf_len)
+	format_f32_fit :: proc(value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format finite f64, returning the required length (written if <= buf_len)
+	format_finite_f64_fit :: proc(value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format finite f32, returning the required length (written if <= buf_len)
+	format_finite_f32_fit :: proc(value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Fixed notation, returning the required length (0 = invalid precision)
+	format_fixed_f64_fit :: proc(value: f64, precision: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Fixed notation, returning the required length (0 = invalid precision)
+	format_fixed_f32_fit :: proc(value: f32, precision: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Scientific notation, returning the required length (0 = invalid precision)
+	format_sci_f64_fit :: proc(value: f64, options: ^Sci_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Scientific notation, returning the required length (0 = invalid precision)
+	format_sci_f32_fit :: proc(value: f32, options: ^Sci_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 with N significant digits like printf "%.Ng"
+	///
+	/// Arguments:
+	///     value  - The f64 value to format
+	///     digits - Significant digits (0 is treated as 1)
+	///     flags  - transmute(u32)Sig_Flags
+	///     buf    - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if digits > MAX_FIXED_PRECISION or flags are invalid
+	format_sig_f64 :: proc(value: f64, digits: c.uint, flags: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 with N significant digits like printf "%.Ng"
+	format_sig_f32 :: proc(value: f32, digits: c.uint, flags: c.uint, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 like format_f64 with the spellings of a Float_Policy
+	///
+	/// Arguments:
+	///     value   - The f64 value to format
+	///     policy  - Non-finite spellings and negative zero handling, or nil for format_f64's
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if the preset is unknown
+	format_policy_f64 :: proc(value: f64, policy: ^Float_Policy, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 like format_f32 with the spellings of a Float_Policy
+	format_policy_f32 :: proc(value: f32, policy: ^Float_Policy, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 as an RFC 8259 JSON number
+	///
+	/// Arguments:
+	///     value   - The f64 value to format
+	///     options - JSON options, or nil to keep ".0" and fail on NaN/infinity
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if value is non-finite and
+	///     options has no non_finite token
+	format_json_f64 :: proc(value: f64, options: ^Json_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 as an RFC 8259 JSON number
+	format_json_f32 :: proc(value: f32, options: ^Json_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format an array of f64 values separated by a delimiter
+	///
+	/// Arguments:
+	///     values        - Values to format (may be nil if count is 0)
+	///     count         - Number of values
+	///     delimiter     - Bytes written between elements (may be nil)
+	///     delimiter_len - Length of delimiter
+	///     buf           - Output buffer
+	///     buf_len       - Size of the buffer
+	///     written_count - Receives the number of whole elements written (may be nil)
+	///
+	/// Returns:
+	///     Bytes written; stops after the last whole element that fits
+	format_f64_array :: proc(values: [^]f64, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of f32 values separated by a delimiter
+	format_f32_array :: proc(values: [^]f32, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
 }
 
 /* itoa FFI declarations */
@@ -132,150 +622,2859 @@ foreign num_format {
 	///
 	/// Returns:
 	///     Number of bytes written, or 0 if buffer too small/invalid
-	itoa_i64 :: proc(value: i64, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	itoa_i64 :: proc(value: i64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format u64 integer to UTF-8 string
-	itoa_u64 :: proc(value: u64, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	itoa_u64 :: proc(value: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format i32 integer to UTF-8 string
-	itoa_i32 :: proc(value: i32, buf: [^]u8, buf_len: c.uint) -> c.uint ---
+	itoa_i32 :: proc(value: i32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
 	/// Format u32 integer to UTF-8 string
-	itoa_u32 :: proc(value: u32, buf: [^]u8, buf_len: c.uint) -> c.uint ---
-}
+	itoa_u32 :: proc(value: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/* Helper Procedures */
+	/// Format i64, returning the required length (written if <= buf_len)
+	///
+	/// Unlike itoa_i64 the buffer only needs to hold the actual digits.
+	itoa_i64_fit :: proc(value: i64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format f64 and return as Odin string
-///
-/// Allocator: Uses provided allocator (default: context.allocator)
-/// Returns: formatted string (must be deleted by caller)
-///
-/// Example:
-///     str := num_format.format_f64_to_string(3.14159) or_else "error"
-///     defer delete(str)
-///     fmt.println(str)
-format_f64_to_string :: proc(value: f64, allocator := context.allocator) -> (string, bool) {
-	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
-	defer delete(buf)
+	/// Format u64, returning the required length (written if <= buf_len)
+	itoa_u64_fit :: proc(value: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	len := format_f64(value, raw_data(buf), BUFFER_SIZE)
-	if len == 0 {
-		return "", false
-	}
+	/// Format i32, returning the required length (written if <= buf_len)
+	itoa_i32_fit :: proc(value: i32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	result := make([dynamic]u8, len, allocator)
-	copy(result[:], buf[:len])
+	/// Format u32, returning the required length (written if <= buf_len)
+	itoa_u32_fit :: proc(value: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(result[:]), true
-}
+	/// Format i128 passed as two halves, value = (i128(hi) << 64) | i128(lo)
+	///
+	/// 128-bit integers have no portable C ABI; prefer format_i128_buffer,
+	/// which splits the value for you.
+	itoa_i128 :: proc(hi: i64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format f32 and return as Odin string
-///
-/// Allocator: Uses provided allocator (default: context.allocator)
-/// Returns: formatted string (must be deleted by caller)
-format_f32_to_string :: proc(value: f32, allocator := context.allocator) -> (string, bool) {
-	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
-	defer delete(buf)
+	/// Format u128 passed as two halves, value = (u128(hi) << 64) | u128(lo)
+	itoa_u128 :: proc(hi: u64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	len := format_f32(value, raw_data(buf), BUFFER_SIZE)
-	if len == 0 {
-		return "", false
-	}
+	/// Format i16 integer to UTF-8 string (buf_len must be >= 40)
+	itoa_i16 :: proc(value: i16, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	result := make([dynamic]u8, len, allocator)
-	copy(result[:], buf[:len])
+	/// Format u16 integer to UTF-8 string (buf_len must be >= 40)
+	itoa_u16 :: proc(value: u16, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(result[:]), true
-}
+	/// Format i8 integer to UTF-8 string (buf_len must be >= 40)
+	itoa_i8 :: proc(value: i8, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format finite f64 and return as Odin string (optimized)
-///
-/// Only call after verifying value is finite.
-/// Allocator: Uses provided allocator (default: context.allocator)
-/// Returns: formatted string (must be deleted by caller)
-format_finite_f64_to_string :: proc(value: f64, allocator := context.allocator) -> (string, bool) {
-	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
-	defer delete(buf)
+	/// Format u8 integer to UTF-8 string (buf_len must be >= 40)
+	itoa_u8 :: proc(value: u8, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	len := format_finite_f64(value, raw_data(buf), BUFFER_SIZE)
-	if len == 0 {
-		return "", false
-	}
+	/// Format i128 halves, returning the required length (written if <= buf_len)
+	itoa_i128_fit :: proc(hi: i64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	result := make([dynamic]u8, len, allocator)
-	copy(result[:], buf[:len])
+	/// Format u128 halves, returning the required length (written if <= buf_len)
+	itoa_u128_fit :: proc(hi: u64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(result[:]), true
-}
+	/// Format i16, returning the required length (written if <= buf_len)
+	itoa_i16_fit :: proc(value: i16, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format finite f32 and return as Odin string (optimized)
-///
-/// Only call after verifying value is finite.
-/// Allocator: Uses provided allocator (default: context.allocator)
-/// Returns: formatted string (must be deleted by caller)
-format_finite_f32_to_string :: proc(value: f32, allocator := context.allocator) -> (string, bool) {
-	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
-	defer delete(buf)
+	/// Format u16, returning the required length (written if <= buf_len)
+	itoa_u16_fit :: proc(value: u16, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	len := format_finite_f32(value, raw_data(buf), BUFFER_SIZE)
-	if len == 0 {
-		return "", false
-	}
+	/// Format i8, returning the required length (written if <= buf_len)
+	itoa_i8_fit :: proc(value: i8, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	result := make([dynamic]u8, len, allocator)
-	copy(result[:], buf[:len])
+	/// Format u8, returning the required length (written if <= buf_len)
+	itoa_u8_fit :: proc(value: u8, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(result[:]), true
-}
+	/// Format i64 in any radix 2..=36, returning the required length
+	///
+	/// Arguments:
+	///     value   - The i64 value to format
+	///     radix   - Base between 2 and 36
+	///     flags   - transmute(u32)Radix_Flags
+	///     buf     - Output buffer (may be nil when buf_len is 0)
+	///     buf_len - Size of output buffer in bytes
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if radix or flags are invalid
+	itoa_radix_i64 :: proc(value: i64, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format f64 into provided buffer
-///
-/// Buffer: Caller must provide valid buffer
-/// Returns: (string, success)
-///
-/// Example:
-///     buf := make([dynamic]u8, num_format.BUFFER_SIZE)
-///     defer delete(buf)
-///     str, ok := num_format.format_f64_buffer(3.14, buf[:])
-///     if ok {
-///         fmt.println(str)
-///     }
-format_f64_buffer :: proc(value: f64, buffer: []u8) -> (string, bool) {
-	len := format_f64(value, raw_data(buffer), c.uint(len(buffer)))
-	if len == 0 {
-		return "", false
-	}
+	/// Format u64 in any radix 2..=36, returning the required length
+	itoa_radix_u64 :: proc(value: u64, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(buffer[:len]), true
-}
+	/// Format i32 in any radix 2..=36, returning the required length
+	itoa_radix_i32 :: proc(value: i32, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format f32 into provided buffer
-format_f32_buffer :: proc(value: f32, buffer: []u8) -> (string, bool) {
-	len := format_f32(value, raw_data(buffer), c.uint(len(buffer)))
-	if len == 0 {
-		return "", false
-	}
+	/// Format u32 in any radix 2..=36, returning the required length
+	itoa_radix_u32 :: proc(value: u32, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-	return string(buffer[:len]), true
-}
+	/// Format i16 in any radix 2..=36, returning the required length
+	itoa_radix_i16 :: proc(value: i16, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
 
-/// Format finite f64 into provided buffer (optimized)
-format_finite_f64_buffer :: proc(value: f64, buffer: []u8) -> (string, bool) {
-	len := format_finite_f64(value, raw_data(buffer), c.uint(len(buffer)))
+	/// Format u16 in any radix 2..=36, returning the required length
+	itoa_radix_u16 :: proc(value: u16, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i8 in any radix 2..=36, returning the required length
+	itoa_radix_i8 :: proc(value: i8, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u8 in any radix 2..=36, returning the required length
+	itoa_radix_u8 :: proc(value: u8, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 halves in any radix 2..=36, returning the required length
+	itoa_radix_i128 :: proc(hi: i64, lo: u64, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 halves in any radix 2..=36, returning the required length
+	itoa_radix_u128 :: proc(hi: u64, lo: u64, radix: u32, flags: u32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i64 padded to a minimum width, returning the required length
+	///
+	/// Arguments:
+	///     value   - The i64 value to format
+	///     options - Width, fill, alignment and sign, or nil for none
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if align or fill are invalid
+	itoa_pad_i64 :: proc(value: i64, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 padded to a minimum width, returning the required length
+	itoa_pad_u64 :: proc(value: u64, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i32 padded to a minimum width, returning the required length
+	itoa_pad_i32 :: proc(value: i32, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u32 padded to a minimum width, returning the required length
+	itoa_pad_u32 :: proc(value: u32, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i16 padded to a minimum width, returning the required length
+	itoa_pad_i16 :: proc(value: i16, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u16 padded to a minimum width, returning the required length
+	itoa_pad_u16 :: proc(value: u16, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i8 padded to a minimum width, returning the required length
+	itoa_pad_i8 :: proc(value: i8, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u8 padded to a minimum width, returning the required length
+	itoa_pad_u8 :: proc(value: u8, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 (passed as hi/lo halves) padded to a minimum width
+	itoa_pad_i128 :: proc(hi: i64, lo: u64, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 (passed as hi/lo halves) padded to a minimum width
+	itoa_pad_u128 :: proc(hi: u64, lo: u64, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format an array of i64 integers separated by a delimiter
+	///
+	/// Same arguments and result as format_f64_array.
+	itoa_i64_array :: proc(values: [^]i64, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of u64 integers separated by a delimiter
+	itoa_u64_array :: proc(values: [^]u64, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of i32 integers separated by a delimiter
+	itoa_i32_array :: proc(values: [^]i32, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of u32 integers separated by a delimiter
+	itoa_u32_array :: proc(values: [^]u32, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of i16 integers separated by a delimiter
+	itoa_i16_array :: proc(values: [^]i16, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of u16 integers separated by a delimiter
+	itoa_u16_array :: proc(values: [^]u16, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of i8 integers separated by a delimiter
+	itoa_i8_array :: proc(values: [^]i8, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of u8 integers separated by a delimiter
+	itoa_u8_array :: proc(values: [^]u8, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of i128 integers (values points to 2 * count u64 words in i128 memory layout)
+	itoa_i128_array :: proc(values: [^]u64, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Format an array of u128 integers (values points to 2 * count u64 words in u128 memory layout)
+	itoa_u128_array :: proc(values: [^]u64, count: c.size_t, delimiter: [^]u8, delimiter_len: c.size_t, buf: [^]u8, buf_len: c.size_t, written_count: ^c.size_t) -> c.size_t ---
+
+	/// Parse the number at the start of buf as f64, correctly rounded
+	///
+	/// Arguments:
+	///     buf          - Input bytes (no whitespace is skipped)
+	///     buf_len      - Number of input bytes
+	///     out_value    - Receives the value (may be nil)
+	///     out_consumed - Receives the number of bytes parsed (may be nil)
+	///
+	/// Returns:
+	///     Parse_Error as i32; accepts everything format_f64 emits
+	parse_f64 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^f64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse the number at the start of buf as f32, correctly rounded
+	parse_f32 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^f32, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as i64
+	///
+	/// Arguments:
+	///     buf          - Input bytes (no whitespace is skipped)
+	///     buf_len      - Number of input bytes
+	///     out_value    - Receives the value, saturated on overflow (may be nil)
+	///     out_consumed - Receives the number of bytes parsed (may be nil)
+	///
+	/// Returns:
+	///     Parse_Error as i32
+	atoi_i64 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^i64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as u64
+	atoi_u64 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^u64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as i32
+	atoi_i32 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^i32, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as u32
+	atoi_u32 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^u32, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as i16
+	atoi_i16 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^i16, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as u16
+	atoi_u16 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^u16, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as i8
+	atoi_i8 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^i8, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` at the start of buf as u8
+	atoi_u8 :: proc(buf: [^]u8, buf_len: c.size_t, out_value: ^u8, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` as i128, value = (i128(out_hi) << 64) | i128(out_lo)
+	atoi_i128 :: proc(buf: [^]u8, buf_len: c.size_t, out_hi: ^i64, out_lo: ^u64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse `[+-]?digits` as u128, value = (u128(out_hi) << 64) | u128(out_lo)
+	atoi_u128 :: proc(buf: [^]u8, buf_len: c.size_t, out_hi: ^u64, out_lo: ^u64, out_consumed: ^c.size_t) -> i32 ---
+}
+
+/* Generic num_ FFI declarations */
+
+@(default_calling_convention = "c", link_prefix = "num_")
+foreign num_format {
+	/// Format i64 with digit grouping
+	///
+	/// Arguments:
+	///     value    - The i64 value to format
+	///     grouping - Separators and pattern (nil = "1,234,567")
+	///     buf      - Output buffer (may be nil when buf_len is 0)
+	///     buf_len  - Size of output buffer in bytes
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len)
+	format_grouped_i64 :: proc(value: i64, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 with digit grouping
+	format_grouped_u64 :: proc(value: u64, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 halves with digit grouping
+	format_grouped_i128 :: proc(hi: i64, lo: u64, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 halves with digit grouping
+	format_grouped_u128 :: proc(hi: u64, lo: u64, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 positionally with digit grouping
+	///
+	/// precision < 0 prints the shortest round-trip digits with at least one
+	/// fraction digit, otherwise exactly that many fraction digits.
+	/// Returns 0 if precision > MAX_FIXED_PRECISION.
+	format_grouped_f64 :: proc(value: f64, precision: i32, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 positionally with digit grouping
+	format_grouped_f32 :: proc(value: f32, precision: i32, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Compile a Python-style format spec such as ">+12,.3e"
+	///
+	/// Arguments:
+	///     spec     - Spec text `[[fill]align][sign][#][0][width][grouping][.precision][type]`
+	///     spec_len - Length of spec in bytes
+	///
+	/// Returns:
+	///     Handle to release with spec_free, or nil if the spec is malformed
+	spec_new :: proc(spec: [^]u8, spec_len: c.size_t) -> ^Spec ---
+
+	/// Release a handle returned by spec_new (nil is ignored)
+	spec_free :: proc(spec: ^Spec) ---
+
+	/// Format i64 with a compiled spec (nil = empty spec), returning the required length
+	///
+	/// Returns 0 if the spec does not apply to integers (e.g. a precision with 'd')
+	spec_format_i64 :: proc(spec: ^Spec, value: i64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 with a compiled spec, returning the required length
+	spec_format_u64 :: proc(spec: ^Spec, value: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 halves with a compiled spec, returning the required length
+	spec_format_i128 :: proc(spec: ^Spec, hi: i64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 halves with a compiled spec, returning the required length
+	spec_format_u128 :: proc(spec: ^Spec, hi: u64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 with a compiled spec, returning the required length
+	///
+	/// Returns 0 if the spec does not apply to floats (e.g. 'x')
+	spec_format_f64 :: proc(spec: ^Spec, value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 with a compiled spec, returning the required length
+	spec_format_f32 :: proc(spec: ^Spec, value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i64 with a spec string parsed on every call, returning the required length
+	///
+	/// Returns 0 if the spec is malformed or does not apply to integers
+	format_spec_i64 :: proc(value: i64, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 with a spec string, returning the required length
+	format_spec_u64 :: proc(value: u64, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 halves with a spec string, returning the required length
+	format_spec_i128 :: proc(hi: i64, lo: u64, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 halves with a spec string, returning the required length
+	format_spec_u128 :: proc(hi: u64, lo: u64, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 with a spec string, returning the required length
+	format_spec_f64 :: proc(value: f64, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 with a spec string, returning the required length
+	format_spec_f32 :: proc(value: f32, spec: [^]u8, spec_len: c.size_t, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Create a formatting profile; the config strings are copied
+	///
+	/// Arguments:
+	///     config - Profile configuration, or nil for format_f64's output
+	///
+	/// Returns:
+	///     Handle to release with formatter_free, or nil if notation or precision are invalid
+	formatter_new :: proc(config: ^Formatter_Config) -> ^Formatter ---
+
+	/// Release a handle returned by formatter_new (nil is ignored)
+	formatter_free :: proc(formatter: ^Formatter) ---
+
+	/// Format f64 with a profile (nil = defaults), returning the required length
+	formatter_f64 :: proc(formatter: ^Formatter, value: f64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f32 with a profile (nil = defaults), returning the required length
+	formatter_f32 :: proc(formatter: ^Formatter, value: f32, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i64 with a profile's grouping, returning the required length
+	formatter_i64 :: proc(formatter: ^Formatter, value: i64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 with a profile's grouping, returning the required length
+	formatter_u64 :: proc(formatter: ^Formatter, value: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i128 halves with a profile's grouping, returning the required length
+	formatter_i128 :: proc(formatter: ^Formatter, hi: i64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u128 halves with a profile's grouping, returning the required length
+	formatter_u128 :: proc(formatter: ^Formatter, hi: u64, lo: u64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format a row-major f64 matrix as right-aligned text rows
+	///
+	/// Each cell is formatted with its column's spec and right-aligned to the column
+	/// width; columns are separated by one space and every row ends with a newline.
+	///
+	/// Arguments:
+	///     values  - rows * cols values, row by row
+	///     rows    - Number of rows
+	///     cols    - Number of columns (and entries in columns)
+	///     columns - Spec and width of each column
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if cols is 0, a column is wider than
+	///     ITOA_MAX_WIDTH or a spec does not apply to floats
+	format_table_f64 :: proc(values: [^]f64, rows: c.size_t, cols: c.size_t, columns: [^]Table_Column, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format a row-major i64 matrix as right-aligned text rows
+	format_table_i64 :: proc(values: [^]i64, rows: c.size_t, cols: c.size_t, columns: [^]Table_Column, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format a byte count as a human-readable size such as "1.5 GiB"
+	///
+	/// Arguments:
+	///     value   - Number of bytes
+	///     options - Base, unit spelling, precision and spacing, or nil for the zero value
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if the options are invalid
+	format_bytes :: proc(value: u64, options: ^Byte_Size_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format f64 in compact notation such as "1.2K" or "12.3 µs"
+	///
+	/// Rounds to the significant digits first, so 999950 becomes "1M", never "1000K".
+	///
+	/// Arguments:
+	///     value   - The f64 value to format (NaN/inf print like format_f64)
+	///     options - Suffix table, digits, separator and unit, or nil for the zero value
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if the table is invalid
+	format_compact_f64 :: proc(value: f64, options: ^Compact_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format i64 in compact notation such as "-45M"
+	format_compact_i64 :: proc(value: i64, options: ^Compact_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format u64 in compact notation such as "3.1B"
+	format_compact_u64 :: proc(value: u64, options: ^Compact_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format nanoseconds as a duration such as "1h2m3.5s" or "PT1H2M3.5S"
+	///
+	/// Fractions are rounded before units are chosen, so 59.99s at precision 1 is "1m0s".
+	///
+	/// Arguments:
+	///     nanoseconds - Duration in nanoseconds (time.Duration)
+	///     options     - Style, largest unit and precision, or nil for compact/hours/all digits
+	///     buf         - Output buffer, or nil to measure
+	///     buf_len     - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if the options are invalid
+	format_duration :: proc(nanoseconds: i64, options: ^Duration_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format Unix seconds and nanoseconds as an RFC 3339 timestamp
+	///
+	/// Arguments:
+	///     seconds - Unix time in seconds
+	///     nanos   - Nanoseconds within the second (< 1e9)
+	///     options - Fraction digits and UTC offset, or nil for the zero value
+	///     buf     - Output buffer (should be >= TIMESTAMP_BUFFER_SIZE)
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Number of bytes written, or 0 if the buffer is too small, the arguments
+	///     are out of range or the local year is outside 0000-9999
+	format_timestamp :: proc(seconds: i64, nanos: u32, options: ^Timestamp_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Format Unix time in nanoseconds (time.Time) as an RFC 3339 timestamp
+	format_timestamp_ns :: proc(nanoseconds: i64, options: ^Timestamp_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Parse `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` at the start of buf
+	///
+	/// Arguments:
+	///     buf                - Input bytes (no whitespace is skipped)
+	///     buf_len            - Number of input bytes
+	///     out_seconds        - Receives the Unix time in seconds, UTC (may be nil)
+	///     out_nanos          - Receives the nanoseconds within the second (may be nil)
+	///     out_offset_minutes - Receives the offset the text was written in (may be nil)
+	///     out_consumed       - Receives the number of bytes parsed (may be nil)
+	///
+	/// Returns:
+	///     Parse_Error as i32 (.None, .Empty or .Invalid); leap seconds are rejected
+	parse_timestamp :: proc(buf: [^]u8, buf_len: c.size_t, out_seconds: ^i64, out_nanos: ^u32, out_offset_minutes: ^i16, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Parse an RFC 3339 timestamp at the start of buf as Unix nanoseconds (saturated on overflow)
+	parse_timestamp_ns :: proc(buf: [^]u8, buf_len: c.size_t, out_nanoseconds: ^i64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Format an i64 holding `value * 10^scale` as a decimal such as "1,234.56"
+	///
+	/// Arguments:
+	///     value   - Amount in minor units (cents at scale 2)
+	///     scale   - Digits after the decimal point (0..=SCALED_MAX_SCALE)
+	///     options - Minimum fraction digits, sign style and grouping, or nil for the zero value
+	///     buf     - Output buffer, or nil to measure
+	///     buf_len - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if scale or the sign style is invalid
+	format_scaled_i64 :: proc(value: i64, scale: u8, options: ^Scaled_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Parse the decimal at the start of buf as an i64 holding `value * 10^scale`
+	///
+	/// Arguments:
+	///     buf          - Input bytes (no whitespace is skipped)
+	///     buf_len      - Number of input bytes
+	///     scale        - Digits after the decimal point (0..=SCALED_MAX_SCALE)
+	///     options      - Separators from options.grouping; group separators are skipped (may be nil)
+	///     out_value    - Receives the value, saturated on overflow (may be nil)
+	///     out_consumed - Receives the number of bytes parsed (may be nil)
+	///
+	/// Returns:
+	///     Parse_Error as i32; .Excess_Precision if non-zero digits follow the scale
+	parse_scaled_i64 :: proc(buf: [^]u8, buf_len: c.size_t, scale: u8, options: ^Scaled_Options, out_value: ^i64, out_consumed: ^c.size_t) -> i32 ---
+
+	/// Create a currency display profile
+	///
+	/// Arguments:
+	///     config - Code, display style and grouping; the strings are copied
+	///
+	/// Returns:
+	///     Handle to release with currency_free, or nil if the code is empty or unknown
+	///     without minor_digits, or a style is invalid
+	currency_new :: proc(config: ^Currency_Config) -> ^Currency ---
+
+	/// Release a handle returned by currency_new (nil is ignored)
+	currency_free :: proc(currency: ^Currency) ---
+
+	/// Format an amount in minor units (cents for USD) with a currency's profile
+	///
+	/// Arguments:
+	///     currency    - Handle from currency_new
+	///     minor_units - Amount in minor units; every minor digit is printed
+	///     buf         - Output buffer, or nil to measure
+	///     buf_len     - Size of the buffer
+	///
+	/// Returns:
+	///     Required length (written if <= buf_len), or 0 if currency is nil
+	currency_format :: proc(currency: ^Currency, minor_units: i64, buf: [^]u8, buf_len: c.size_t) -> c.size_t ---
+
+	/// Minor-unit digits of a currency in the built-in table (2 for USD, 0 for JPY, 3 for BHD), or -1
+	currency_minor_digits :: proc(code: [^]u8, code_len: c.size_t) -> i32 ---
+}
+
+/* Helper Procedures */
+
+/// Format f64 and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format_f64_to_string(3.14159) or_else "error"
+///     defer delete(str)
+///     fmt.println(str)
+format_f64_to_string :: proc(value: f64, allocator := context.allocator) -> (string, bool) {
+	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
+	defer delete(buf)
+
+	len := format_f64(value, raw_data(buf), BUFFER_SIZE)
 	if len == 0 {
 		return "", false
 	}
 
-	return string(buffer[:len]), true
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
 }
 
-/// Format finite f32 into provided buffer (optimized)
-format_finite_f32_buffer :: proc(value: f32, buffer: []u8) -> (string, bool) {
-	len := format_finite_f32(value, raw_data(buffer), c.uint(len(buffer)))
+/// Format f32 and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_f32_to_string :: proc(value: f32, allocator := context.allocator) -> (string, bool) {
+	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
+	defer delete(buf)
+
+	len := format_f32(value, raw_data(buf), BUFFER_SIZE)
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format finite f64 and return as Odin string (optimized)
+///
+/// Only call after verifying value is finite.
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_finite_f64_to_string :: proc(value: f64, allocator := context.allocator) -> (string, bool) {
+	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
+	defer delete(buf)
+
+	len := format_finite_f64(value, raw_data(buf), BUFFER_SIZE)
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format finite f32 and return as Odin string (optimized)
+///
+/// Only call after verifying value is finite.
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_finite_f32_to_string :: proc(value: f32, allocator := context.allocator) -> (string, bool) {
+	buf := make([dynamic]u8, BUFFER_SIZE, allocator)
+	defer delete(buf)
+
+	len := format_finite_f32(value, raw_data(buf), BUFFER_SIZE)
 	if len == 0 {
 		return "", false
 	}
 
-	return string(buffer[:len]), true
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format f64 into provided buffer
+///
+/// Buffer: Caller must provide valid buffer; it only needs to hold the
+///         formatted text (e.g. 3 bytes for "1.0"), not BUFFER_SIZE
+/// Returns: (string, success)
+///
+/// Example:
+///     buf := make([dynamic]u8, num_format.BUFFER_SIZE)
+///     defer delete(buf)
+///     str, ok := num_format.format_f64_buffer(3.14, buf[:])
+///     if ok {
+///         fmt.println(str)
+///     }
+format_f64_buffer :: proc(value: f64, buffer: []u8) -> (string, bool) {
+	required := format_f64_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f32 into provided buffer
+format_f32_buffer :: proc(value: f32, buffer: []u8) -> (string, bool) {
+	required := format_f32_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format finite f64 into provided buffer (optimized)
+format_finite_f64_buffer :: proc(value: f64, buffer: []u8) -> (string, bool) {
+	required := format_finite_f64_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format finite f32 into provided buffer (optimized)
+format_finite_f32_buffer :: proc(value: f32, buffer: []u8) -> (string, bool) {
+	required := format_finite_f32_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f64 with `precision` fractional digits and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller); false if precision is
+///          outside 0..=MAX_FIXED_PRECISION
+///
+/// Example:
+///     str := num_format.format_fixed_f64_to_string(2.0 / 3.0, 4) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "0.6667"
+format_fixed_f64_to_string :: proc(value: f64, precision: int, allocator := context.allocator) -> (string, bool) {
+	if precision < 0 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	buf_size := int(fixed_buffer_size_f64(c.uint(precision)))
+	buf := make([dynamic]u8, buf_size, allocator)
+	defer delete(buf)
+
+	len := format_fixed_f64(value, c.uint(precision), raw_data(buf), c.size_t(buf_size))
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format f32 with `precision` fractional digits and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_fixed_f32_to_string :: proc(value: f32, precision: int, allocator := context.allocator) -> (string, bool) {
+	if precision < 0 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	buf_size := int(fixed_buffer_size_f32(c.uint(precision)))
+	buf := make([dynamic]u8, buf_size, allocator)
+	defer delete(buf)
+
+	len := format_fixed_f32(value, c.uint(precision), raw_data(buf), c.size_t(buf_size))
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format f64 with `precision` fractional digits into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the text;
+///         fixed_buffer_size_f64(precision) bytes always suffice
+/// Returns: (string, success)
+format_fixed_f64_buffer :: proc(value: f64, precision: int, buffer: []u8) -> (string, bool) {
+	if precision < 0 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_fixed_f64_fit(value, c.uint(precision), raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f32 with `precision` fractional digits into provided buffer
+format_fixed_f32_buffer :: proc(value: f32, precision: int, buffer: []u8) -> (string, bool) {
+	if precision < 0 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_fixed_f32_fit(value, c.uint(precision), raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f64 in scientific notation and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     opts := num_format.Sci_Options{precision = -1, uppercase = true, exp_plus_sign = true, min_exp_digits = 2}
+///     str := num_format.format_sci_f64_to_string(1.2345e-7, opts) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "1.2345E-07"
+format_sci_f64_to_string :: proc(
+	value: f64,
+	options := DEFAULT_SCI_OPTIONS,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	options := options
+	buf_size := int(sci_buffer_size(&options))
+	buf := make([dynamic]u8, buf_size, allocator)
+	defer delete(buf)
+
+	len := format_sci_f64(value, &options, raw_data(buf), c.size_t(buf_size))
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format f32 in scientific notation and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_sci_f32_to_string :: proc(
+	value: f32,
+	options := DEFAULT_SCI_OPTIONS,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	options := options
+	buf_size := int(sci_buffer_size(&options))
+	buf := make([dynamic]u8, buf_size, allocator)
+	defer delete(buf)
+
+	len := format_sci_f32(value, &options, raw_data(buf), c.size_t(buf_size))
+	if len == 0 {
+		return "", false
+	}
+
+	result := make([dynamic]u8, len, allocator)
+	copy(result[:], buf[:len])
+
+	return string(result[:]), true
+}
+
+/// Format f64 in scientific notation into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the text;
+///         sci_buffer_size(&options) bytes always suffice
+/// Returns: (string, success)
+format_sci_f64_buffer :: proc(value: f64, buffer: []u8, options := DEFAULT_SCI_OPTIONS) -> (string, bool) {
+	options := options
+	required := format_sci_f64_fit(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f32 in scientific notation into provided buffer
+format_sci_f32_buffer :: proc(value: f32, buffer: []u8, options := DEFAULT_SCI_OPTIONS) -> (string, bool) {
+	options := options
+	required := format_sci_f32_fit(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format i64 into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the digits
+///         (20 bytes cover every i64)
+/// Returns: (string, success)
+format_i64_buffer :: proc(value: i64, buffer: []u8) -> (string, bool) {
+	required := itoa_i64_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format u64 into provided buffer
+format_u64_buffer :: proc(value: u64, buffer: []u8) -> (string, bool) {
+	required := itoa_u64_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format i32 into provided buffer
+format_i32_buffer :: proc(value: i32, buffer: []u8) -> (string, bool) {
+	required := itoa_i32_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format u32 into provided buffer
+format_u32_buffer :: proc(value: u32, buffer: []u8) -> (string, bool) {
+	required := itoa_u32_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format i128 into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the digits
+///         (40 bytes cover every i128)
+/// Returns: (string, success)
+format_i128_buffer :: proc(value: i128, buffer: []u8) -> (string, bool) {
+	required := itoa_i128_fit(i64(value >> 64), u64(value), raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format u128 into provided buffer
+format_u128_buffer :: proc(value: u128, buffer: []u8) -> (string, bool) {
+	required := itoa_u128_fit(u64(value >> 64), u64(value), raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format i16 into provided buffer
+format_i16_buffer :: proc(value: i16, buffer: []u8) -> (string, bool) {
+	required := itoa_i16_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format u16 into provided buffer
+format_u16_buffer :: proc(value: u16, buffer: []u8) -> (string, bool) {
+	required := itoa_u16_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format i8 into provided buffer
+format_i8_buffer :: proc(value: i8, buffer: []u8) -> (string, bool) {
+	required := itoa_i8_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format u8 into provided buffer
+format_u8_buffer :: proc(value: u8, buffer: []u8) -> (string, bool) {
+	required := itoa_u8_fit(value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format int into provided buffer (formatted as i64)
+format_int_buffer :: proc(value: int, buffer: []u8) -> (string, bool) {
+	return format_i64_buffer(i64(value), buffer)
+}
+
+/// Format uint into provided buffer (formatted as u64)
+format_uint_buffer :: proc(value: uint, buffer: []u8) -> (string, bool) {
+	return format_u64_buffer(u64(value), buffer)
+}
+
+/// Format i128 and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format_i128_to_string(-170141183460469231731687303715884105728) or_else "error"
+///     defer delete(str)
+///     fmt.println(str)
+format_i128_to_string :: proc(value: i128, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_i128_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format u128 and return as Odin string (must be deleted by caller)
+format_u128_to_string :: proc(value: u128, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_u128_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format i64 and return as Odin string (must be deleted by caller)
+format_i64_to_string :: proc(value: i64, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_i64_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format u64 and return as Odin string (must be deleted by caller)
+format_u64_to_string :: proc(value: u64, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_u64_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format i32 and return as Odin string (must be deleted by caller)
+format_i32_to_string :: proc(value: i32, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_i32_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format u32 and return as Odin string (must be deleted by caller)
+format_u32_to_string :: proc(value: u32, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_u32_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format i16 and return as Odin string (must be deleted by caller)
+format_i16_to_string :: proc(value: i16, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_i16_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format u16 and return as Odin string (must be deleted by caller)
+format_u16_to_string :: proc(value: u16, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_u16_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format i8 and return as Odin string (must be deleted by caller)
+format_i8_to_string :: proc(value: i8, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_i8_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format u8 and return as Odin string (must be deleted by caller)
+format_u8_to_string :: proc(value: u8, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_u8_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format int and return as Odin string (must be deleted by caller)
+format_int_to_string :: proc(value: int, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_int_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format uint and return as Odin string (must be deleted by caller)
+format_uint_to_string :: proc(value: uint, allocator := context.allocator) -> (string, bool) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	str, ok := format_uint_buffer(value, buf[:])
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Format any integer width and return as Odin string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.itoa(u128(1) << 100) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "1267650600228229401496703205376"
+itoa :: proc {
+	format_i8_to_string,
+	format_u8_to_string,
+	format_i16_to_string,
+	format_u16_to_string,
+	format_i32_to_string,
+	format_u32_to_string,
+	format_i64_to_string,
+	format_u64_to_string,
+	format_i128_to_string,
+	format_u128_to_string,
+	format_int_to_string,
+	format_uint_to_string,
+}
+
+/// Format any integer width into provided buffer
+///
+/// Example:
+///     buf: [num_format.ITOA_BUFFER_SIZE]u8
+///     str, ok := num_format.itoa_buffer(i8(-128), buf[:])
+itoa_buffer :: proc {
+	format_i8_buffer,
+	format_u8_buffer,
+	format_i16_buffer,
+	format_u16_buffer,
+	format_i32_buffer,
+	format_u32_buffer,
+	format_i64_buffer,
+	format_u64_buffer,
+	format_i128_buffer,
+	format_u128_buffer,
+	format_int_buffer,
+	format_uint_buffer,
+}
+
+/// Format any integer in the given radix (2..=36) into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the digits;
+///         ITOA_RADIX_BUFFER_SIZE bytes always suffice
+/// Returns: (string, success); fails for an invalid radix
+///
+/// Example:
+///     buf: [num_format.ITOA_RADIX_BUFFER_SIZE]u8
+///     str, ok := num_format.format_radix_buffer(u32(0xdeadbeef), 16, buf[:], {.Prefix})
+///     fmt.println(str) // "0xdeadbeef"
+format_radix_buffer :: proc(
+	value: $T,
+	radix: int,
+	buffer: []u8,
+	flags := Radix_Flags{},
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	buf, buf_len := raw_data(buffer), c.size_t(len(buffer))
+	bits := transmute(u32)flags
+	required: c.size_t
+	when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			required = itoa_radix_u128(u64(value >> 64), u64(value), u32(radix), bits, buf, buf_len)
+		} else {
+			required = itoa_radix_i128(i64(value >> 64), u64(value), u32(radix), bits, buf, buf_len)
+		}
+	} else when size_of(T) == 8 {
+		when intrinsics.type_is_unsigned(T) {
+			required = itoa_radix_u64(u64(value), u32(radix), bits, buf, buf_len)
+		} else {
+			required = itoa_radix_i64(i64(value), u32(radix), bits, buf, buf_len)
+		}
+	} else when size_of(T) == 4 {
+		when intrinsics.type_is_unsigned(T) {
+			required = itoa_radix_u32(u32(value), u32(radix), bits, buf, buf_len)
+		} else {
+			required = itoa_radix_i32(i32(value), u32(radix), bits, buf, buf_len)
+		}
+	} else when size_of(T) == 2 {
+		when intrinsics.type_is_unsigned(T) {
+			required = itoa_radix_u16(u16(value), u32(radix), bits, buf, buf_len)
+		} else {
+			required = itoa_radix_i16(i16(value), u32(radix), bits, buf, buf_len)
+		}
+	} else {
+		when intrinsics.type_is_unsigned(T) {
+			required = itoa_radix_u8(u8(value), u32(radix), bits, buf, buf_len)
+		} else {
+			required = itoa_radix_i8(i8(value), u32(radix), bits, buf, buf_len)
+		}
+	}
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer in the given radix (2..=36) and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format_radix_to_string(i8(-1), 2, {.Twos_Complement}) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "11111111"
+format_radix_to_string :: proc(
+	value: $T,
+	radix: int,
+	flags := Radix_Flags{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	buf: [ITOA_RADIX_BUFFER_SIZE]u8
+	str, ok := format_radix_buffer(value, radix, buf[:], flags)
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Parse the number at the start of `str` as f64
+///
+/// Returns: (value, bytes consumed, error); trailing text is left unparsed
+///
+/// Example:
+///     value, consumed, err := num_format.parse_f64_prefix("12.5px")
+///     // 12.5, 4, .None
+parse_f64_prefix :: proc(str: string) -> (value: f64, consumed: int, err: Parse_Error) {
+	n: c.size_t
+	code := parse_f64(raw_data(str), c.size_t(len(str)), &value, &n)
+	return value, int(n), Parse_Error(code)
+}
+
+/// Parse the number at the start of `str` as f32
+parse_f32_prefix :: proc(str: string) -> (value: f32, consumed: int, err: Parse_Error) {
+	n: c.size_t
+	code := parse_f32(raw_data(str), c.size_t(len(str)), &value, &n)
+	return value, int(n), Parse_Error(code)
+}
+
+/// Parse all of `str` as f64
+///
+/// Returns: (value, error); .Invalid if anything follows the number
+///
+/// Example:
+///     value, err := num_format.parse_f64_string("-2.5e-7")
+///     if err == .None {
+///         fmt.println(value)
+///     }
+parse_f64_string :: proc(str: string) -> (f64, Parse_Error) {
+	value, consumed, err := parse_f64_prefix(str)
+	if err == .None && consumed != len(str) {
+		return 0, .Invalid
+	}
+
+	return value, err
+}
+
+/// Parse all of `str` as f32
+parse_f32_string :: proc(str: string) -> (f32, Parse_Error) {
+	value, consumed, err := parse_f32_prefix(str)
+	if err == .None && consumed != len(str) {
+		return 0, .Invalid
+	}
+
+	return value, err
+}
+
+/// Parse the integer at the start of `str` as any integer type T
+///
+/// Returns: (value, bytes consumed, error); the value saturates on overflow
+///
+/// Example:
+///     value, consumed, err := num_format.parse_int_prefix("404 Not Found", u16)
+///     // 404, 3, .None
+parse_int_prefix :: proc(
+	str: string,
+	$T: typeid,
+) -> (
+	value: T,
+	consumed: int,
+	err: Parse_Error,
+) where intrinsics.type_is_integer(T) {
+	buf, buf_len := raw_data(str), c.size_t(len(str))
+	n: c.size_t
+	code: i32
+	when size_of(T) == 16 {
+		lo: u64
+		when intrinsics.type_is_unsigned(T) {
+			hi: u64
+			code = atoi_u128(buf, buf_len, &hi, &lo, &n)
+		} else {
+			hi: i64
+			code = atoi_i128(buf, buf_len, &hi, &lo, &n)
+		}
+		value = T(hi) << 64 | T(lo)
+	} else when size_of(T) == 8 {
+		when intrinsics.type_is_unsigned(T) {
+			v: u64
+			code = atoi_u64(buf, buf_len, &v, &n)
+		} else {
+			v: i64
+			code = atoi_i64(buf, buf_len, &v, &n)
+		}
+		value = T(v)
+	} else when size_of(T) == 4 {
+		when intrinsics.type_is_unsigned(T) {
+			v: u32
+			code = atoi_u32(buf, buf_len, &v, &n)
+		} else {
+			v: i32
+			code = atoi_i32(buf, buf_len, &v, &n)
+		}
+		value = T(v)
+	} else when size_of(T) == 2 {
+		when intrinsics.type_is_unsigned(T) {
+			v: u16
+			code = atoi_u16(buf, buf_len, &v, &n)
+		} else {
+			v: i16
+			code = atoi_i16(buf, buf_len, &v, &n)
+		}
+		value = T(v)
+	} else {
+		when intrinsics.type_is_unsigned(T) {
+			v: u8
+			code = atoi_u8(buf, buf_len, &v, &n)
+		} else {
+			v: i8
+			code = atoi_i8(buf, buf_len, &v, &n)
+		}
+		value = T(v)
+	}
+
+	return value, int(n), Parse_Error(code)
+}
+
+/// Parse all of `str` as any integer type T
+///
+/// Returns: (value, error); .Invalid if anything follows the digits
+///
+/// Example:
+///     count, err := num_format.parse_int_string("-42", i32)
+parse_int_string :: proc(str: string, $T: typeid) -> (T, Parse_Error) where intrinsics.type_is_integer(T) {
+	value, consumed, err := parse_int_prefix(str, T)
+	if err == .None && consumed != len(str) {
+		return 0, .Invalid
+	}
+
+	return value, err
+}
+
+/// Build a Grouping from Odin strings
+///
+/// The strings must outlive the Grouping (string literals always do).
+/// Returns: (grouping, success); false if primary or secondary is outside 0..=255
+///
+/// Example:
+///     indian, _ := num_format.make_grouping(",", ".", 3, 2)   // 12,34,567.89
+///     french, _ := num_format.make_grouping("\u202F", ",")   // 1 234 567,89
+make_grouping :: proc(group_sep := ",", decimal_sep := ".", primary := 3, secondary := 0) -> (Grouping, bool) {
+	if primary < 0 || primary > 255 || secondary < 0 || secondary > 255 {
+		return {}, false
+	}
+
+	grouping := Grouping {
+		group_sep       = raw_data(group_sep),
+		group_sep_len   = c.size_t(len(group_sep)),
+		decimal_sep     = raw_data(decimal_sep),
+		decimal_sep_len = c.size_t(len(decimal_sep)),
+		primary         = u8(primary),
+		secondary       = u8(secondary),
+	}
+
+	return grouping, true
+}
+
+@(private)
+grouped_int_fit :: proc(value: $T, grouping: ^Grouping, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return format_grouped_u128(u64(value >> 64), u64(value), grouping, buf, buf_len)
+		} else {
+			return format_grouped_i128(i64(value >> 64), u64(value), grouping, buf, buf_len)
+		}
+	} else when intrinsics.type_is_unsigned(T) {
+		return format_grouped_u64(u64(value), grouping, buf, buf_len)
+	} else {
+		return format_grouped_i64(i64(value), grouping, buf, buf_len)
+	}
+}
+
+/// Format any integer with digit grouping into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the text
+/// Returns: (string, success)
+///
+/// Example:
+///     buf: [64]u8
+///     str, ok := num_format.format_grouped_buffer(12345678, buf[:]) // "12,345,678"
+format_grouped_buffer :: proc(
+	value: $T,
+	buffer: []u8,
+	grouping: ^Grouping = nil,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	required := grouped_int_fit(value, grouping, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer with digit grouping and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_grouped_to_string :: proc(
+	value: $T,
+	grouping: ^Grouping = nil,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	required := grouped_int_fit(value, grouping, nil, 0)
+	buf := make([]u8, required, allocator)
+	grouped_int_fit(value, grouping, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format f64 with digit grouping into provided buffer
+///
+/// precision -1 prints the shortest round-trip digits, at least one after the separator
+/// Returns: (string, success); false if precision is outside -1..=MAX_FIXED_PRECISION
+format_grouped_f64_buffer :: proc(
+	value: f64,
+	buffer: []u8,
+	precision := -1,
+	grouping: ^Grouping = nil,
+) -> (
+	string,
+	bool,
+) {
+	if precision < -1 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_grouped_f64(value, i32(precision), grouping, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f32 with digit grouping into provided buffer
+format_grouped_f32_buffer :: proc(
+	value: f32,
+	buffer: []u8,
+	precision := -1,
+	grouping: ^Grouping = nil,
+) -> (
+	string,
+	bool,
+) {
+	if precision < -1 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_grouped_f32(value, i32(precision), grouping, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f64 with digit grouping and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     french, _ := num_format.make_grouping("\u202F", ",")
+///     str := num_format.format_grouped_f64_to_string(1234567.891, 2, &french) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "1 234 567,89"
+format_grouped_f64_to_string :: proc(
+	value: f64,
+	precision := -1,
+	grouping: ^Grouping = nil,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	if precision < -1 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_grouped_f64(value, i32(precision), grouping, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_grouped_f64(value, i32(precision), grouping, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format f32 with digit grouping and return as Odin string
+format_grouped_f32_to_string :: proc(
+	value: f32,
+	precision := -1,
+	grouping: ^Grouping = nil,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	if precision < -1 || precision > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_grouped_f32(value, i32(precision), grouping, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_grouped_f32(value, i32(precision), grouping, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format f64 with N significant digits (printf "%g" style) into provided buffer
+///
+/// Picks fixed or exponent notation by magnitude: "3.142", "0.0001235", "1.235e+20"
+/// Returns: (string, success); false if digits is outside 0..=MAX_FIXED_PRECISION
+format_sig_f64_buffer :: proc(value: f64, digits: int, buffer: []u8, flags := Sig_Flags{}) -> (string, bool) {
+	if digits < 0 || digits > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_sig_f64(value, c.uint(digits), transmute(c.uint)flags, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f32 with N significant digits (printf "%g" style) into provided buffer
+format_sig_f32_buffer :: proc(value: f32, digits: int, buffer: []u8, flags := Sig_Flags{}) -> (string, bool) {
+	if digits < 0 || digits > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_sig_f32(value, c.uint(digits), transmute(c.uint)flags, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format f64 with N significant digits and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format_sig_f64_to_string(2.5, 4, {.Keep_Trailing_Zeros}) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "2.500"
+format_sig_f64_to_string :: proc(
+	value: f64,
+	digits: int,
+	flags := Sig_Flags{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	if digits < 0 || digits > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_sig_f64(value, c.uint(digits), transmute(c.uint)flags, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_sig_f64(value, c.uint(digits), transmute(c.uint)flags, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format f32 with N significant digits and return as Odin string
+format_sig_f32_to_string :: proc(
+	value: f32,
+	digits: int,
+	flags := Sig_Flags{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	if digits < 0 || digits > MAX_FIXED_PRECISION {
+		return "", false
+	}
+
+	required := format_sig_f32(value, c.uint(digits), transmute(c.uint)flags, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_sig_f32(value, c.uint(digits), transmute(c.uint)flags, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Build a Float_Policy; empty strings keep the preset's spelling
+///
+/// The policy borrows the strings, which must outlive its use.
+///
+/// Example:
+///     js := num_format.make_float_policy(.JS, normalize_negative_zero = true)
+///     str, ok := num_format.format_policy_buffer(math.inf_f64(-1), buf[:], js) // "-Infinity"
+make_float_policy :: proc(
+	preset := Non_Finite.Zmij,
+	normalize_negative_zero := false,
+	nan := "",
+	inf := "",
+	neg_inf := "",
+) -> Float_Policy {
+	policy := Float_Policy {
+		preset                  = preset,
+		normalize_negative_zero = normalize_negative_zero,
+	}
+	if len(nan) > 0 {
+		policy.nan, policy.nan_len = raw_data(nan), c.size_t(len(nan))
+	}
+	if len(inf) > 0 {
+		policy.inf, policy.inf_len = raw_data(inf), c.size_t(len(inf))
+	}
+	if len(neg_inf) > 0 {
+		policy.neg_inf, policy.neg_inf_len = raw_data(neg_inf), c.size_t(len(neg_inf))
+	}
+	return policy
+}
+
+@(private)
+policy_float_fit :: proc(value: $T, policy: ^Float_Policy, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when size_of(T) == 4 {
+		return format_policy_f32(f32(value), policy, buf, buf_len)
+	} else {
+		return format_policy_f64(f64(value), policy, buf, buf_len)
+	}
+}
+
+/// Format a float into provided buffer with the spellings of a Float_Policy
+format_policy_buffer :: proc(
+	value: $T,
+	buffer: []u8,
+	policy: Float_Policy,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_float(T) {
+	policy := policy
+	required := policy_float_fit(value, &policy, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a float to an allocated string with the spellings of a Float_Policy
+format_policy_to_string :: proc(
+	value: $T,
+	policy: Float_Policy,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_float(T) {
+	policy := policy
+	required := policy_float_fit(value, &policy, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	policy_float_fit(value, &policy, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Build Json_Options; an empty non_finite makes NaN and infinities fail
+///
+/// The options borrow the string, which must outlive their use.
+///
+/// Example:
+///     opts := num_format.make_json_options(integers_without_fraction = true, non_finite = "null")
+///     str, ok := num_format.format_json_buffer(42.0, buf[:], opts) // "42"
+make_json_options :: proc(integers_without_fraction := false, non_finite := "") -> Json_Options {
+	options := Json_Options {
+		integers_without_fraction = integers_without_fraction,
+	}
+	if len(non_finite) > 0 {
+		options.non_finite, options.non_finite_len = raw_data(non_finite), c.size_t(len(non_finite))
+	}
+	return options
+}
+
+@(private)
+json_float_fit :: proc(value: $T, options: ^Json_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when size_of(T) == 4 {
+		return format_json_f32(f32(value), options, buf, buf_len)
+	} else {
+		return format_json_f64(f64(value), options, buf, buf_len)
+	}
+}
+
+/// Format a float as a JSON number into provided buffer
+///
+/// Returns false for NaN and infinities unless options has a non_finite token.
+format_json_buffer :: proc(
+	value: $T,
+	buffer: []u8,
+	options := Json_Options{},
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_float(T) {
+	options := options
+	required := json_float_fit(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a float as a JSON number to an allocated string
+format_json_to_string :: proc(
+	value: $T,
+	options := Json_Options{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_float(T) {
+	options := options
+	required := json_float_fit(value, &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	json_float_fit(value, &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+@(private)
+array_fit :: proc(values: []$T, delimiter: string, buf: [^]u8, buf_len: c.size_t, written: ^c.size_t) -> c.size_t {
+	data, count := raw_data(values), c.size_t(len(values))
+	delim, delim_len := raw_data(delimiter), c.size_t(len(delimiter))
+	when intrinsics.type_is_float(T) {
+		when size_of(T) == 4 {
+			return format_f32_array(cast([^]f32)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return format_f64_array(cast([^]f64)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	} else when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u128_array(cast([^]u64)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return itoa_i128_array(cast([^]u64)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	} else when size_of(T) == 8 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u64_array(cast([^]u64)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return itoa_i64_array(cast([^]i64)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	} else when size_of(T) == 4 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u32_array(cast([^]u32)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return itoa_i32_array(cast([^]i32)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	} else when size_of(T) == 2 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u16_array(cast([^]u16)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return itoa_i16_array(cast([^]i16)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	} else {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u8_array(cast([^]u8)data, count, delim, delim_len, buf, buf_len, written)
+		} else {
+			return itoa_i8_array(cast([^]i8)data, count, delim, delim_len, buf, buf_len, written)
+		}
+	}
+}
+
+/// Format a slice of numbers separated by a delimiter into provided buffer
+///
+/// Writes as many whole elements as fit in one FFI call. To resume after a
+/// partial run, flush the text and call again with values[written:].
+///
+/// Example:
+///     text, written := num_format.format_array_buffer(samples[:], buf[:], ",")
+///     os.write_string(fd, text)
+///     samples = samples[written:]
+format_array_buffer :: proc(
+	values: []$T,
+	buffer: []u8,
+	delimiter := ",",
+) -> (
+	text: string,
+	written: int,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	count: c.size_t
+	bytes := array_fit(values, delimiter, raw_data(buffer), c.size_t(len(buffer)), &count)
+	return string(buffer[:bytes]), int(count)
+}
+
+/// Format a slice of numbers separated by a delimiter to an allocated string
+format_array_to_string :: proc(
+	values: []$T,
+	delimiter := ",",
+	allocator := context.allocator,
+) -> string where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	capacity := max(len(values) * (ITOA_BUFFER_SIZE + len(delimiter)), 1)
+	buf := make([]u8, capacity, allocator)
+	count: c.size_t
+	bytes := array_fit(values, delimiter, raw_data(buf), c.size_t(capacity), &count)
+	assert(int(count) == len(values))
+
+	return string(buf[:bytes])
+}
+
+@(private)
+padded_int_fit :: proc(value: $T, options: ^Pad_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_pad_u128(u64(value >> 64), u64(value), options, buf, buf_len)
+		} else {
+			return itoa_pad_i128(i64(value >> 64), u64(value), options, buf, buf_len)
+		}
+	} else when size_of(T) == 8 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_pad_u64(u64(value), options, buf, buf_len)
+		} else {
+			return itoa_pad_i64(i64(value), options, buf, buf_len)
+		}
+	} else when size_of(T) == 4 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_pad_u32(u32(value), options, buf, buf_len)
+		} else {
+			return itoa_pad_i32(i32(value), options, buf, buf_len)
+		}
+	} else when size_of(T) == 2 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_pad_u16(u16(value), options, buf, buf_len)
+		} else {
+			return itoa_pad_i16(i16(value), options, buf, buf_len)
+		}
+	} else {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_pad_u8(u8(value), options, buf, buf_len)
+		} else {
+			return itoa_pad_i8(i8(value), options, buf, buf_len)
+		}
+	}
+}
+
+/// Format any integer padded to a minimum width into provided buffer
+///
+/// Buffer: Caller must provide valid buffer large enough for the padded text
+/// Returns: (string, success)
+///
+/// Example:
+///     buf: [32]u8
+///     str, ok := num_format.format_padded_buffer(-42, buf[:], {width = 5, align = .Zero_Pad}) // "-0042"
+format_padded_buffer :: proc(
+	value: $T,
+	buffer: []u8,
+	options: Pad_Options,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	options := options
+	required := padded_int_fit(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer padded to a minimum width and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format_padded_to_string(42, {width = 6, fill = '.', align = .Left}) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "42...."
+format_padded_to_string :: proc(
+	value: $T,
+	options: Pad_Options,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) {
+	options := options
+	required := padded_int_fit(value, &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	padded_int_fit(value, &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Compile a format spec once for use in hot loops
+///
+/// Returns: handle (release with destroy_spec), or nil if the spec is malformed
+///
+/// Example:
+///     spec := num_format.compile_spec(">10.2f")
+///     defer num_format.destroy_spec(spec)
+///     str, ok := num_format.format_compiled_buffer(3.14159, spec, buf[:]) // "      3.14"
+compile_spec :: proc(spec: string) -> ^Spec {
+	return spec_new(raw_data(spec), c.size_t(len(spec)))
+}
+
+/// Release a handle returned by compile_spec
+destroy_spec :: proc(spec: ^Spec) {
+	spec_free(spec)
+}
+
+@(private)
+spec_string_fit :: proc(value: $T, spec: string, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	spec_ptr, spec_len := raw_data(spec), c.size_t(len(spec))
+	when intrinsics.type_is_float(T) {
+		when size_of(T) == 4 {
+			return format_spec_f32(f32(value), spec_ptr, spec_len, buf, buf_len)
+		} else {
+			return format_spec_f64(f64(value), spec_ptr, spec_len, buf, buf_len)
+		}
+	} else when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return format_spec_u128(u64(value >> 64), u64(value), spec_ptr, spec_len, buf, buf_len)
+		} else {
+			return format_spec_i128(i64(value >> 64), u64(value), spec_ptr, spec_len, buf, buf_len)
+		}
+	} else when intrinsics.type_is_unsigned(T) {
+		return format_spec_u64(u64(value), spec_ptr, spec_len, buf, buf_len)
+	} else {
+		return format_spec_i64(i64(value), spec_ptr, spec_len, buf, buf_len)
+	}
+}
+
+@(private)
+spec_compiled_fit :: proc(value: $T, spec: ^Spec, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when intrinsics.type_is_float(T) {
+		when size_of(T) == 4 {
+			return spec_format_f32(spec, f32(value), buf, buf_len)
+		} else {
+			return spec_format_f64(spec, f64(value), buf, buf_len)
+		}
+	} else when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return spec_format_u128(spec, u64(value >> 64), u64(value), buf, buf_len)
+		} else {
+			return spec_format_i128(spec, i64(value >> 64), u64(value), buf, buf_len)
+		}
+	} else when intrinsics.type_is_unsigned(T) {
+		return spec_format_u64(spec, u64(value), buf, buf_len)
+	} else {
+		return spec_format_i64(spec, i64(value), buf, buf_len)
+	}
+}
+
+/// Format any integer or float with a Python-style spec into provided buffer
+///
+/// Spec: `[[fill]align][sign][#][0][width][grouping][.precision][type]`
+/// Returns: (string, success); fails if the spec is malformed, does not apply
+/// to T, or the buffer is too small
+///
+/// Example:
+///     buf: [64]u8
+///     str, ok := num_format.format_buffer(255, "#010x", buf[:]) // "0x000000ff"
+format_buffer :: proc(
+	value: $T,
+	spec: string,
+	buffer: []u8,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := spec_string_fit(value, spec, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer or float with a Python-style spec and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     str := num_format.format(1234.5678, ">+12,.3e") or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "  +1.235e+03"
+format :: proc(
+	value: $T,
+	spec: string,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := spec_string_fit(value, spec, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	spec_string_fit(value, spec, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format any integer or float with a compiled spec into provided buffer
+///
+/// Returns: (string, success)
+format_compiled_buffer :: proc(
+	value: $T,
+	spec: ^Spec,
+	buffer: []u8,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := spec_compiled_fit(value, spec, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer or float with a compiled spec and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+format_compiled :: proc(
+	value: $T,
+	spec: ^Spec,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := spec_compiled_fit(value, spec, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	spec_compiled_fit(value, spec, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+@(private)
+table_fit :: proc(values: []$T, columns: []Table_Column, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	cols := len(columns)
+	if cols == 0 || len(values) % cols != 0 {
+		return 0
+	}
+	rows := c.size_t(len(values) / cols)
+	when T == f64 {
+		return format_table_f64(raw_data(values), rows, c.size_t(cols), raw_data(columns), buf, buf_len)
+	} else {
+		return format_table_i64(raw_data(values), rows, c.size_t(cols), raw_data(columns), buf, buf_len)
+	}
+}
+
+/// Format a row-major matrix as aligned text rows into provided buffer
+///
+/// len(values) must be a multiple of len(columns).
+///
+/// Example:
+///     fixed := num_format.compile_spec(".3f")
+///     defer num_format.destroy_spec(fixed)
+///     columns := []num_format.Table_Column{{nil, 8}, {fixed, 10}}
+///     str, ok := num_format.format_table_buffer(timings[:], columns, buf[:])
+format_table_buffer :: proc(
+	values: []$T,
+	columns: []Table_Column,
+	buffer: []u8,
+) -> (
+	string,
+	bool,
+) where T == f64 || T == i64 {
+	required := table_fit(values, columns, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a row-major matrix as aligned text rows to an allocated string
+format_table :: proc(
+	values: []$T,
+	columns: []Table_Column,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where T == f64 || T == i64 {
+	required := table_fit(values, columns, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	table_fit(values, columns, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Create a formatting profile from named options
+///
+/// Strings, including the policy's, are copied, so temporaries are fine. An
+/// empty separator prints nothing. Defaults reproduce format_f64.
+/// Returns: handle (release with destroy_formatter), or nil if primary, secondary or
+///          min_exp_digits is outside 0..=255, precision > MAX_FIXED_PRECISION or the
+///          policy preset is unknown
+///
+/// Example:
+///     german := num_format.make_formatter(decimal_sep = ",", group_sep = ".", primary = 3, notation = .Fixed, precision = 2)
+///     defer num_format.destroy_formatter(german)
+///     str, ok := num_format.format_with_buffer(german, 1234567.891, buf[:]) // "1.234.567,89"
+make_formatter :: proc(
+	decimal_sep := ".",
+	group_sep := ",",
+	primary := 0,
+	secondary := 0,
+	policy := Float_Policy{},
+	notation := Notation.Shortest,
+	precision := -1,
+	exp_uppercase := false,
+	exp_plus_sign := true,
+	min_exp_digits := 1,
+) -> ^Formatter {
+	if primary < 0 || primary > 255 || secondary < 0 || secondary > 255 {
+		return nil
+	}
+	if min_exp_digits < 0 || min_exp_digits > 255 || precision > MAX_FIXED_PRECISION {
+		return nil
+	}
+
+	policy := policy
+	config := Formatter_Config {
+		decimal_sep     = raw_data(decimal_sep),
+		decimal_sep_len = c.size_t(len(decimal_sep)),
+		group_sep       = raw_data(group_sep),
+		group_sep_len   = c.size_t(len(group_sep)),
+		primary         = u8(primary),
+		secondary       = u8(secondary),
+		policy          = &policy,
+		notation        = notation,
+		precision       = i32(max(precision, -1)),
+		exp_uppercase   = exp_uppercase,
+		exp_plus_sign   = exp_plus_sign,
+		min_exp_digits  = u8(min_exp_digits),
+	}
+	return formatter_new(&config)
+}
+
+/// Release a handle returned by make_formatter
+destroy_formatter :: proc(formatter: ^Formatter) {
+	formatter_free(formatter)
+}
+
+@(private)
+formatter_fit :: proc(formatter: ^Formatter, value: $T, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when intrinsics.type_is_float(T) {
+		when size_of(T) == 4 {
+			return formatter_f32(formatter, f32(value), buf, buf_len)
+		} else {
+			return formatter_f64(formatter, f64(value), buf, buf_len)
+		}
+	} else when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return formatter_u128(formatter, u64(value >> 64), u64(value), buf, buf_len)
+		} else {
+			return formatter_i128(formatter, i64(value >> 64), u64(value), buf, buf_len)
+		}
+	} else when intrinsics.type_is_unsigned(T) {
+		return formatter_u64(formatter, u64(value), buf, buf_len)
+	} else {
+		return formatter_i64(formatter, i64(value), buf, buf_len)
+	}
+}
+
+/// Format any integer or float with a formatting profile into provided buffer
+///
+/// Returns: (string, success)
+format_with_buffer :: proc(
+	formatter: ^Formatter,
+	value: $T,
+	buffer: []u8,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := formatter_fit(formatter, value, raw_data(buffer), c.size_t(len(buffer)))
+	if int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format any integer or float with a formatting profile and return as Odin string
+///
+/// Allocator: Uses provided allocator (default: context.allocator)
+/// Returns: formatted string (must be deleted by caller)
+///
+/// Example:
+///     report := num_format.make_formatter(notation = .Scientific, precision = 3, min_exp_digits = 2)
+///     defer num_format.destroy_formatter(report)
+///     str := num_format.format_with(report, 1234.5678) or_else "error"
+///     defer delete(str)
+///     fmt.println(str) // "1.235e+03"
+format_with :: proc(
+	formatter: ^Formatter,
+	value: $T,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	required := formatter_fit(formatter, value, nil, 0)
+	buf := make([]u8, required, allocator)
+	formatter_fit(formatter, value, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format a byte count as a human-readable size into provided buffer
+///
+/// Example:
+///     buf: [32]u8
+///     str, ok := num_format.format_bytes_buffer(3 << 29, buf[:], {precision = 1}) // "1.5 GiB"
+format_bytes_buffer :: proc(value: u64, buffer: []u8, options := Byte_Size_Options{}) -> (string, bool) {
+	options := options
+	required := format_bytes(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a byte count as a human-readable size and return as Odin string
+format_bytes_to_string :: proc(
+	value: u64,
+	options := Byte_Size_Options{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	options := options
+	required := format_bytes(value, &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_bytes(value, &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Build Compact_Options from Odin strings
+///
+/// The options borrow the strings and suffix slice, which must outlive their use.
+/// Returns: (options, success); false if digits is outside 1..=255
+///
+/// Example:
+///     seconds, _ := num_format.make_compact_options(.SI, separator = " ", unit = "s")
+///     str, ok := num_format.format_compact_buffer(12.3e-6, buf[:], seconds) // "12.3 µs"
+make_compact_options :: proc(
+	table := Compact_Table.Short,
+	digits := 3,
+	separator := "",
+	unit := "",
+	suffixes: []Compact_Suffix = nil,
+) -> (
+	Compact_Options,
+	bool,
+) {
+	if digits < 1 || digits > 255 {
+		return {}, false
+	}
+	options := Compact_Options {
+		table         = table,
+		digits        = u8(digits),
+		separator     = raw_data(separator),
+		separator_len = c.size_t(len(separator)),
+		unit          = raw_data(unit),
+		unit_len      = c.size_t(len(unit)),
+		suffixes      = raw_data(suffixes),
+		suffix_count  = c.size_t(len(suffixes)),
+	}
+
+	return options, true
+}
+
+/// Build a custom table entry; the entry borrows the suffix string
+compact_suffix :: proc(exponent: int, suffix: string) -> Compact_Suffix {
+	return Compact_Suffix{i32(exponent), raw_data(suffix), c.size_t(len(suffix))}
+}
+
+@(private)
+compact_fit :: proc(value: $T, options: ^Compact_Options, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when intrinsics.type_is_float(T) {
+		return format_compact_f64(f64(value), options, buf, buf_len)
+	} else when intrinsics.type_is_unsigned(T) {
+		return format_compact_u64(u64(value), options, buf, buf_len)
+	} else {
+		return format_compact_i64(i64(value), options, buf, buf_len)
+	}
+}
+
+/// Format a number in compact notation into provided buffer
+format_compact_buffer :: proc(
+	value: $T,
+	buffer: []u8,
+	options := Compact_Options{},
+) -> (
+	string,
+	bool,
+) where (intrinsics.type_is_integer(T) && size_of(T) <= 8) || intrinsics.type_is_float(T) {
+	options := options
+	required := compact_fit(value, &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a number in compact notation and return as Odin string
+format_compact_to_string :: proc(
+	value: $T,
+	options := Compact_Options{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) where (intrinsics.type_is_integer(T) && size_of(T) <= 8) || intrinsics.type_is_float(T) {
+	options := options
+	required := compact_fit(value, &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	compact_fit(value, &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format a time.Duration into provided buffer
+///
+/// Example:
+///     buf: [32]u8
+///     str, ok := num_format.format_duration_buffer(3723500 * time.Millisecond, buf[:]) // "1h2m3.5s"
+format_duration_buffer :: proc(
+	d: time.Duration,
+	buffer: []u8,
+	options := Duration_Options{precision = -1},
+) -> (
+	string,
+	bool,
+) {
+	options := options
+	required := format_duration(i64(d), &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format a time.Duration and return as Odin string
+format_duration_to_string :: proc(
+	d: time.Duration,
+	options := Duration_Options{precision = -1},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	options := options
+	required := format_duration(i64(d), &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_duration(i64(d), &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Format a time.Time as an RFC 3339 timestamp into provided buffer
+///
+/// Example:
+///     buf: [num_format.TIMESTAMP_BUFFER_SIZE]u8
+///     str, ok := num_format.format_time_buffer(time.now(), buf[:], {fraction_digits = 3})
+///     // "2026-10-16T12:34:56.123Z"
+format_time_buffer :: proc(t: time.Time, buffer: []u8, options := Timestamp_Options{}) -> (string, bool) {
+	options := options
+	n := format_timestamp_ns(time.time_to_unix_nano(t), &options, raw_data(buffer), c.size_t(len(buffer)))
+	if n == 0 {
+		return "", false
+	}
+
+	return string(buffer[:n]), true
+}
+
+/// Format a time.Time as an RFC 3339 timestamp and return as Odin string
+format_time_to_string :: proc(
+	t: time.Time,
+	options := Timestamp_Options{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	buf: [TIMESTAMP_BUFFER_SIZE]u8
+	str, ok := format_time_buffer(t, buf[:], options)
+	if !ok {
+		return "", false
+	}
+
+	return clone_to_string(str, allocator), true
+}
+
+/// Parse the RFC 3339 timestamp at the start of `str`
+///
+/// Returns: (time, offset in minutes the text was written in, bytes consumed, error);
+/// .Overflow / .Neg_Overflow if the time is outside what time.Time holds (1677-2262)
+///
+/// Example:
+///     t, offset, consumed, err := num_format.parse_time_prefix("2026-10-16T18:04:56+05:30 GET /")
+///     // 12:34:56 UTC, 330, 25, .None
+parse_time_prefix :: proc(str: string) -> (t: time.Time, offset_minutes: int, consumed: int, err: Parse_Error) {
+	seconds: i64
+	nanos: u32
+	offset: i16
+	n: c.size_t
+	code := parse_timestamp(raw_data(str), c.size_t(len(str)), &seconds, &nanos, &offset, &n)
+	if code != 0 {
+		return {}, 0, 0, Parse_Error(code)
+	}
+
+	scaled, mul_overflow := intrinsics.overflow_mul(seconds, i64(time.Second))
+	total, add_overflow := intrinsics.overflow_add(scaled, i64(nanos))
+	if mul_overflow || add_overflow {
+		return {}, int(offset), int(n), .Neg_Overflow if seconds < 0 else .Overflow
+	}
+
+	return time.unix(0, total), int(offset), int(n), .None
+}
+
+/// Parse all of `str` as an RFC 3339 timestamp
+///
+/// Returns: (time, offset in minutes, error); .Invalid if anything follows the timestamp
+parse_time_string :: proc(str: string) -> (time.Time, int, Parse_Error) {
+	t, offset, consumed, err := parse_time_prefix(str)
+	if err == .None && consumed != len(str) {
+		return {}, 0, .Invalid
+	}
+
+	return t, offset, err
+}
+
+/// Format an amount in minor units as a decimal into provided buffer
+///
+/// Example:
+///     buf: [32]u8
+///     commas, _ := num_format.make_grouping()
+///     str, ok := num_format.format_scaled_buffer(123456789, 2, buf[:], {grouping = &commas}) // "1,234,567.89"
+format_scaled_buffer :: proc(value: i64, scale: int, buffer: []u8, options := Scaled_Options{}) -> (string, bool) {
+	if scale < 0 || scale > SCALED_MAX_SCALE {
+		return "", false
+	}
+	options := options
+	required := format_scaled_i64(value, u8(scale), &options, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format an amount in minor units as a decimal and return as Odin string
+format_scaled_to_string :: proc(
+	value: i64,
+	scale: int,
+	options := Scaled_Options{},
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	if scale < 0 || scale > SCALED_MAX_SCALE {
+		return "", false
+	}
+	options := options
+	required := format_scaled_i64(value, u8(scale), &options, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	format_scaled_i64(value, u8(scale), &options, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Parse the decimal at the start of `str` as an amount in minor units
+///
+/// Returns: (value, bytes consumed, error); trailing text is left unparsed
+///
+/// Example:
+///     cents, consumed, err := num_format.parse_scaled_prefix("12.5 USD", 2)
+///     // 1250, 4, .None
+parse_scaled_prefix :: proc(
+	str: string,
+	scale: int,
+	options := Scaled_Options{},
+) -> (
+	value: i64,
+	consumed: int,
+	err: Parse_Error,
+) {
+	if scale < 0 || scale > SCALED_MAX_SCALE {
+		return 0, 0, .Invalid
+	}
+	options := options
+	n: c.size_t
+	code := parse_scaled_i64(raw_data(str), c.size_t(len(str)), u8(scale), &options, &value, &n)
+	return value, int(n), Parse_Error(code)
+}
+
+/// Parse all of `str` as an amount in minor units
+///
+/// Returns: (value, error); .Invalid if anything follows the number
+parse_scaled_string :: proc(str: string, scale: int, options := Scaled_Options{}) -> (i64, Parse_Error) {
+	value, consumed, err := parse_scaled_prefix(str, scale, options)
+	if err == .None && consumed != len(str) {
+		return 0, .Invalid
+	}
+
+	return value, err
+}
+
+/// Create a currency display profile from Odin strings
+///
+/// The strings are copied, so they need not outlive the call.
+/// Returns nil if the code is unknown and minor_digits is -1, minor_digits is outside
+/// -1..=SCALED_MAX_SCALE, or a style is invalid.
+///
+/// Example:
+///     usd := num_format.make_currency("USD", negative = .Accounting)
+///     defer num_format.destroy_currency(usd)
+///     str, ok := num_format.format_currency_buffer(usd, -123456, buf[:]) // "($1,234.56)"
+make_currency :: proc(
+	code: string,
+	display := Currency_Display.Symbol,
+	placement := Currency_Placement.Prefix,
+	space := Currency_Space.Auto,
+	negative := Currency_Negative.Minus,
+	symbol := "",
+	minor_digits := -1,
+	grouping: ^Grouping = nil,
+) -> ^Currency {
+	if minor_digits < -1 || minor_digits > SCALED_MAX_SCALE {
+		return nil
+	}
+	config := Currency_Config {
+		code         = raw_data(code),
+		code_len     = c.size_t(len(code)),
+		symbol       = raw_data(symbol) if len(symbol) > 0 else nil,
+		symbol_len   = c.size_t(len(symbol)),
+		display      = display,
+		placement    = placement,
+		space        = space,
+		negative     = negative,
+		minor_digits = i8(minor_digits),
+		grouping     = grouping,
+	}
+	return currency_new(&config)
+}
+
+/// Release a handle returned by make_currency
+destroy_currency :: proc(currency: ^Currency) {
+	currency_free(currency)
+}
+
+/// Format an amount in minor units with a currency into provided buffer
+format_currency_buffer :: proc(currency: ^Currency, minor_units: i64, buffer: []u8) -> (string, bool) {
+	required := currency_format(currency, minor_units, raw_data(buffer), c.size_t(len(buffer)))
+	if required == 0 || int(required) > len(buffer) {
+		return "", false
+	}
+
+	return string(buffer[:required]), true
+}
+
+/// Format an amount in minor units with a currency and return as Odin string
+format_currency :: proc(
+	currency: ^Currency,
+	minor_units: i64,
+	allocator := context.allocator,
+) -> (
+	string,
+	bool,
+) {
+	required := currency_format(currency, minor_units, nil, 0)
+	if required == 0 {
+		return "", false
+	}
+	buf := make([]u8, required, allocator)
+	currency_format(currency, minor_units, raw_data(buf), required)
+
+	return string(buf), true
+}
+
+/// Minor-unit digits of a currency in the built-in table, or -1 if unknown
+///
+/// Use it as the scale for format_scaled_* and parse_scaled_*.
+currency_digits :: proc(code: string) -> int {
+	return int(currency_minor_digits(raw_data(code), c.size_t(len(code))))
+}
+
+@(private)
+shortest_fit :: proc(value: $T, buf: [^]u8, buf_len: c.size_t) -> c.size_t {
+	when intrinsics.type_is_float(T) {
+		when size_of(T) == 4 {
+			return format_f32_fit(f32(value), buf, buf_len)
+		} else {
+			return format_f64_fit(f64(value), buf, buf_len)
+		}
+	} else when size_of(T) == 16 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u128_fit(u64(value >> 64), u64(value), buf, buf_len)
+		} else {
+			return itoa_i128_fit(i64(value >> 64), u64(value), buf, buf_len)
+		}
+	} else when size_of(T) == 8 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u64_fit(u64(value), buf, buf_len)
+		} else {
+			return itoa_i64_fit(i64(value), buf, buf_len)
+		}
+	} else when size_of(T) == 4 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u32_fit(u32(value), buf, buf_len)
+		} else {
+			return itoa_i32_fit(i32(value), buf, buf_len)
+		}
+	} else when size_of(T) == 2 {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u16_fit(u16(value), buf, buf_len)
+		} else {
+			return itoa_i16_fit(i16(value), buf, buf_len)
+		}
+	} else {
+		when intrinsics.type_is_unsigned(T) {
+			return itoa_u8_fit(u8(value), buf, buf_len)
+		} else {
+			return itoa_i8_fit(i8(value), buf, buf_len)
+		}
+	}
+}
+
+/// Append any integer or float to a strings.Builder without allocating a temporary
+///
+/// Floats use the shortest round-trip form of format_f64, integers plain decimal.
+/// The digits are formatted on the stack, then appended with strings.write_bytes,
+/// so a fixed builder from strings.builder_from_bytes is never overrun.
+/// Returns: number of bytes appended (fewer if the builder could not grow)
+///
+/// Example:
+///     b := strings.builder_make()
+///     num_format.write_builder(&b, 3.25)
+///     strings.write_byte(&b, ' ')
+///     num_format.write_builder(&b, u128(1) << 100)
+write_builder :: proc(
+	b: ^strings.Builder,
+	value: $T,
+) -> int where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	written := shortest_fit(value, raw_data(buf[:]), ITOA_BUFFER_SIZE)
+	return strings.write_bytes(b, buf[:written])
+}
+
+/// Write any integer or float to an io.Writer (or io.Stream) from a stack buffer
+///
+/// Floats use the shortest round-trip form of format_f64, integers plain decimal.
+/// Returns: bytes written and the writer's error
+///
+/// Example:
+///     num_format.write_number(os.stream_from_handle(os.stdout), -42)
+write_number :: proc(
+	w: io.Writer,
+	value: $T,
+) -> (
+	n: int,
+	err: io.Error,
+) where intrinsics.type_is_integer(T) || intrinsics.type_is_float(T) {
+	buf: [ITOA_BUFFER_SIZE]u8
+	written := shortest_fit(value, raw_data(buf[:]), ITOA_BUFFER_SIZE)
+	return io.write(w, buf[:written])
+}
+
+@(private)
+fmt_formatters: map[typeid]fmt.User_Formatter
+
+/// Install core:fmt user formatters for Fast_F64, Fast_F32, Fast_I64 and Fast_U64
+///
+/// Pass the map already given to fmt.set_user_formatters to add to it; with nil
+/// a package-level map is created and installed. Call once at startup, before
+/// any printing threads start (core:fmt's registry is not synchronized).
+///
+/// Verbs: %v (shortest floats, decimal integers), %f %F %e %E %g %G for floats,
+/// %d %x %X %o %b for integers. Width, precision and the `-`, `+`, ` `, `0`
+/// and `#` flags are honoured. Other verbs fall back to core:fmt.
+///
+/// Example:
+///     num_format.register_fmt_formatters()
+///     fmt.printf("[%10.3f]\n", num_format.Fast_F64(3.14159)) // "[     3.142]"
+register_fmt_formatters :: proc(formatters: ^map[typeid]fmt.User_Formatter = nil) {
+	formatters := formatters
+	if formatters == nil {
+		formatters = &fmt_formatters
+		fmt.set_user_formatters(formatters)
+	}
+	for id in ([]typeid{Fast_F64, Fast_F32, Fast_I64, Fast_U64}) {
+		formatters^[id] = fmt_user_formatter
+	}
+}
+
+/// Build the Python-style spec matching a core:fmt verb and its flags
+@(private)
+fmt_verb_spec :: proc(fi: ^fmt.Info, verb: rune, is_float: bool, buf: []u8) -> (string, bool) {
+	type: u8
+	switch verb {
+	case 'v':
+		if is_float && fi.prec_set {
+			type = 'f'
+		}
+	case 'f', 'F', 'e', 'E', 'g', 'G':
+		if !is_float {
+			return "", false
+		}
+		type = u8(verb)
+	case 'd', 'x', 'X', 'o', 'b':
+		if is_float {
+			return "", false
+		}
+		type = u8(verb)
+	case:
+		return "", false
+	}
+
+	b := strings.builder_from_bytes(buf)
+	if fi.minus {
+		strings.write_byte(&b, '<')
+	}
+	if fi.plus {
+		strings.write_byte(&b, '+')
+	} else if fi.space {
+		strings.write_byte(&b, ' ')
+	}
+	if fi.hash {
+		strings.write_byte(&b, '#')
+	}
+	if fi.zero && !fi.minus {
+		strings.write_byte(&b, '0')
+	}
+	if fi.width_set {
+		strings.write_int(&b, fi.width)
+	}
+	if fi.prec_set {
+		strings.write_byte(&b, '.')
+		strings.write_int(&b, fi.prec)
+	}
+	if type != 0 {
+		strings.write_byte(&b, type)
+	}
+	return strings.to_string(b), true
+}
+
+@(private)
+fmt_write :: proc(fi: ^fmt.Info, value: $T, verb: rune) -> bool {
+	spec_buf: [32]u8
+	spec, ok := fmt_verb_spec(fi, verb, intrinsics.type_is_float(T), spec_buf[:])
+	if !ok {
+		return false
+	}
+
+	buf: [256]u8
+	required := spec_string_fit(value, spec, raw_data(buf[:]), len(buf))
+	if required == 0 {
+		return false
+	}
+	if int(required) <= len(buf) {
+		io.write(fi.writer, buf[:required], &fi.n)
+		return true
+	}
+
+	// Long fixed-notation output (large precision or magnitude)
+	text := make([]u8, required, context.temp_allocator)
+	spec_string_fit(value, spec, raw_data(text), required)
+	io.write(fi.writer, text, &fi.n)
+	return true
+}
+
+@(private)
+fmt_user_formatter :: proc(fi: ^fmt.Info, arg: any, verb: rune) -> bool {
+	switch value in arg {
+	case Fast_F64:
+		return fmt_write(fi, f64(value), verb)
+	case Fast_F32:
+		return fmt_write(fi, f32(value), verb)
+	case Fast_I64:
+		return fmt_write(fi, i64(value), verb)
+	case Fast_U64:
+		return fmt_write(fi, u64(value), verb)
+	}
+	return false
+}
+
+@(private)
+clone_to_string :: proc(str: string, allocator := context.allocator) -> string {
+	result := make([dynamic]u8, len(str), allocator)
+	copy(result[:], str)
+
+	return string(result[:])
 }
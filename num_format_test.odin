@@ -10,7 +10,9 @@ import num_format "."
 import "core:fmt"
 import "core:math"
 import "core:strconv"
+import "core:strings"
 import "core:testing"
+import "core:time"
 
 @(test)
 test_num_format_f64_simple :: proc(t: ^testing.T) {
@@ -226,6 +228,129 @@ test_num_format_f64_boundary_values :: proc(t: ^testing.T) {
 	testing.expect(t, max_ok, "Expected MAX formatting")
 }
 
+@(test)
+test_num_format_fixed_f64 :: proc(t: ^testing.T) {
+	str, ok := num_format.format_fixed_f64_to_string(2.0 / 3.0, 4)
+	defer delete(str)
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "0.6667", "Expected four fractional digits")
+}
+
+@(test)
+test_num_format_fixed_f64_round_half_even :: proc(t: ^testing.T) {
+	buf: [64]u8
+
+	str, ok := num_format.format_fixed_f64_buffer(0.125, 2, buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "0.12", "Expected tie to round to even")
+
+	str, ok = num_format.format_fixed_f64_buffer(2.5, 0, buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "2", "Expected tie to round to even")
+}
+
+@(test)
+test_num_format_fixed_f64_large_value :: proc(t: ^testing.T) {
+	str, ok := num_format.format_fixed_f64_to_string(1e308, 2)
+	defer delete(str)
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, len(str) == 312, "Expected 309 integer digits and 2 fractional digits")
+	testing.expect(t, len(str) > num_format.BUFFER_SIZE, "Expected output larger than BUFFER_SIZE")
+
+	small_buf: [num_format.BUFFER_SIZE]u8
+	_, small_ok := num_format.format_fixed_f64_buffer(1e308, 2, small_buf[:])
+	testing.expect(t, !small_ok, "Expected error for small buffer")
+}
+
+@(test)
+test_num_format_fixed_invalid_precision :: proc(t: ^testing.T) {
+	_, ok := num_format.format_fixed_f64_to_string(1.5, -1)
+	testing.expect(t, !ok, "Expected error for negative precision")
+
+	_, ok = num_format.format_fixed_f32_to_string(1.5, num_format.MAX_FIXED_PRECISION + 1)
+	testing.expect(t, !ok, "Expected error for too large precision")
+
+	buf: [64]u8
+	_, ok = num_format.format_fixed_f64_buffer(1.5, -1, buf[:])
+	testing.expect(t, !ok, "Expected error for negative precision")
+}
+
+@(test)
+test_num_format_fixed_f32 :: proc(t: ^testing.T) {
+	str, ok := num_format.format_fixed_f32_to_string(f32(-1234.5), 3)
+	defer delete(str)
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "-1234.500", "Expected correct output")
+}
+
+@(test)
+test_num_format_sci_f64_default :: proc(t: ^testing.T) {
+	str, ok := num_format.format_sci_f64_to_string(1.2345e-7)
+	defer delete(str)
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "1.2345e-7", "Expected shortest scientific output")
+}
+
+@(test)
+test_num_format_sci_f64_exponent_style :: proc(t: ^testing.T) {
+	buf: [32]u8
+	opts := num_format.Sci_Options {
+		precision      = -1,
+		uppercase      = true,
+		exp_plus_sign  = true,
+		min_exp_digits = 2,
+	}
+
+	str, ok := num_format.format_sci_f64_buffer(1.2345e-7, buf[:], opts)
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "1.2345E-07", "Expected upper-case padded exponent")
+
+	str, ok = num_format.format_sci_f64_buffer(42.0, buf[:], opts)
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "4.2E+01", "Expected forced exponent sign")
+}
+
+@(test)
+test_num_format_sci_f64_precision :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_sci_f64_buffer(1234.5678, buf[:], num_format.Sci_Options{precision = 2})
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "1.23e3", "Expected two mantissa fraction digits")
+}
+
+@(test)
+test_num_format_f64_fit_tight_buffer :: proc(t: ^testing.T) {
+	buf: [3]u8
+	required := num_format.format_f64_fit(1.0, raw_data(buf[:]), len(buf))
+
+	testing.expect(t, required == 3, "Expected exact required length")
+	testing.expect(t, string(buf[:required]) == "1.0", "Expected output in 3-byte buffer")
+}
+
+@(test)
+test_num_format_f64_fit_measure :: proc(t: ^testing.T) {
+	required := num_format.format_f64_fit(1234.5678, nil, 0)
+	testing.expect(t, required == 9, "Expected required length for null buffer")
+
+	small_buf: [4]u8
+	required = num_format.format_f64_fit(1234.5678, raw_data(small_buf[:]), len(small_buf))
+	testing.expect(t, required == 9, "Expected required length for small buffer")
+}
+
+@(test)
+test_num_format_f64_buffer_exact_size :: proc(t: ^testing.T) {
+	buf: [4]u8
+	str, ok := num_format.format_f64_buffer(-2.5, buf[:])
+
+	testing.expect(t, ok, "Expected buffer smaller than BUFFER_SIZE to work")
+	testing.expect(t, str == "-2.5", "Expected correct output")
+}
+
 /* itoa function tests */
 
 @(test)
@@ -371,3 +496,658 @@ test_itoa_u32_max :: proc(t: ^testing.T) {
 	testing.expect(t, len > 0, "Expected successful formatting")
 	testing.expect(t, string(buf[:len]) == "4294967295", "Expected u32::MAX")
 }
+
+@(test)
+test_itoa_fit_tight_buffer :: proc(t: ^testing.T) {
+	buf: [1]u8
+	required := num_format.itoa_i64_fit(i64(0), raw_data(buf[:]), len(buf))
+
+	testing.expect(t, required == 1, "Expected exact required length")
+	testing.expect(t, string(buf[:required]) == "0", "Expected output in 1-byte buffer")
+}
+
+@(test)
+test_itoa_buffer_helpers :: proc(t: ^testing.T) {
+	buf: [20]u8
+
+	str, ok := num_format.format_i64_buffer(-9223372036854775808, buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "-9223372036854775808", "Expected i64::MIN")
+
+	str, ok = num_format.format_u64_buffer(18446744073709551615, buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "18446744073709551615", "Expected u64::MAX")
+
+	small_buf: [2]u8
+	_, ok = num_format.format_u32_buffer(12345, small_buf[:])
+	testing.expect(t, !ok, "Expected error for small buffer")
+}
+
+@(test)
+test_itoa_128_bit :: proc(t: ^testing.T) {
+	buf: [num_format.ITOA_BUFFER_SIZE]u8
+
+	str, ok := num_format.format_i128_buffer(min(i128), buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "-170141183460469231731687303715884105728", "Expected i128::MIN")
+
+	str, ok = num_format.format_u128_buffer(max(u128), buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "340282366920938463463374607431768211455", "Expected u128::MAX")
+
+	str, ok = num_format.format_u128_buffer(u128(1) << 64, buf[:])
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, str == "18446744073709551616", "Expected carry into the high half")
+}
+
+@(test)
+test_itoa_small_widths :: proc(t: ^testing.T) {
+	buf: [num_format.ITOA_BUFFER_SIZE]u8
+
+	n := num_format.itoa_i8(i8(-128), raw_data(buf[:]), len(buf))
+	testing.expect(t, string(buf[:n]) == "-128", "Expected i8::MIN")
+
+	n = num_format.itoa_u16(u16(65535), raw_data(buf[:]), len(buf))
+	testing.expect(t, string(buf[:n]) == "65535", "Expected u16::MAX")
+
+	small_buf: [3]u8
+	_, ok := num_format.format_i16_buffer(-32768, small_buf[:])
+	testing.expect(t, !ok, "Expected error for small buffer")
+}
+
+@(test)
+test_itoa_proc_group :: proc(t: ^testing.T) {
+	str, ok := num_format.itoa(u8(255))
+	testing.expect(t, ok && str == "255", "Expected u8 overload")
+	delete(str)
+
+	str, ok = num_format.itoa(i16(-32768))
+	testing.expect(t, ok && str == "-32768", "Expected i16 overload")
+	delete(str)
+
+	str, ok = num_format.itoa(int(-42))
+	testing.expect(t, ok && str == "-42", "Expected int overload")
+	delete(str)
+
+	str, ok = num_format.itoa(max(u128))
+	testing.expect(t, ok && str == "340282366920938463463374607431768211455", "Expected u128 overload")
+	delete(str)
+}
+
+@(test)
+test_radix_formatting :: proc(t: ^testing.T) {
+	buf: [num_format.ITOA_RADIX_BUFFER_SIZE]u8
+
+	str, ok := num_format.format_radix_buffer(u32(0xdeadbeef), 16, buf[:], {.Prefix})
+	testing.expect(t, ok && str == "0xdeadbeef", "Expected prefixed hex")
+
+	str, ok = num_format.format_radix_buffer(u64(255), 16, buf[:], {.Uppercase})
+	testing.expect(t, ok && str == "FF", "Expected upper-case hex")
+
+	str, ok = num_format.format_radix_buffer(i8(-1), 2, buf[:], {.Twos_Complement})
+	testing.expect(t, ok && str == "11111111", "Expected 8-bit two's complement")
+
+	str, ok = num_format.format_radix_buffer(i32(-255), 16, buf[:], {.Prefix})
+	testing.expect(t, ok && str == "-0xff", "Expected signed hex")
+
+	str, ok = num_format.format_radix_buffer(u128(35), 36, buf[:])
+	testing.expect(t, ok && str == "z", "Expected base-36 digit")
+
+	_, ok = num_format.format_radix_buffer(u8(1), 37, buf[:])
+	testing.expect(t, !ok, "Expected error for invalid radix")
+
+	small_buf: [2]u8
+	_, ok = num_format.format_radix_buffer(u8(255), 2, small_buf[:])
+	testing.expect(t, !ok, "Expected error for small buffer")
+}
+
+@(test)
+test_radix_to_string :: proc(t: ^testing.T) {
+	str, ok := num_format.format_radix_to_string(min(i128), 2, {.Prefix})
+	defer delete(str)
+
+	testing.expect(t, ok, "Expected successful formatting")
+	testing.expect(t, len(str) == num_format.ITOA_RADIX_BUFFER_SIZE, "Expected worst-case length")
+}
+
+@(test)
+test_parse_f64_string :: proc(t: ^testing.T) {
+	value, err := num_format.parse_f64_string("-2.5e-7")
+	testing.expect(t, err == .None && value == -2.5e-7, "Expected exact parse")
+
+	_, err = num_format.parse_f64_string("12.5px")
+	testing.expect(t, err == .Invalid, "Expected error for trailing text")
+
+	_, err = num_format.parse_f64_string("")
+	testing.expect(t, err == .Empty, "Expected error for empty input")
+
+	value, err = num_format.parse_f64_string("1e999")
+	testing.expect(t, err == .Overflow && math.is_inf(value, 1), "Expected overflow to +inf")
+
+	value, err = num_format.parse_f64_string("1e-999")
+	testing.expect(t, err == .Underflow && value == 0, "Expected underflow to 0")
+}
+
+@(test)
+test_parse_f64_prefix :: proc(t: ^testing.T) {
+	value, consumed, err := num_format.parse_f64_prefix("12.5px")
+	testing.expect(t, err == .None, "Expected successful parse")
+	testing.expect(t, value == 12.5 && consumed == 4, "Expected the numeric prefix")
+}
+
+@(test)
+test_parse_roundtrips_format :: proc(t: ^testing.T) {
+	values := []f64{0.1, 1.0 / 3.0, 5e-324, 1.7976931348623157e308, -123456.789, 1e21}
+	buf: [num_format.BUFFER_SIZE]u8
+
+	for value in values {
+		str, ok := num_format.format_f64_buffer(value, buf[:])
+		testing.expect(t, ok, "Expected successful formatting")
+
+		parsed, err := num_format.parse_f64_string(str)
+		testing.expect(t, err == .None, "Expected successful parse")
+		testing.expect(t, transmute(u64)parsed == transmute(u64)value, "Expected identical bits")
+	}
+
+	parsed32, err := num_format.parse_f32_string("0.1")
+	testing.expect(t, err == .None && parsed32 == f32(0.1), "Expected f32 parse")
+}
+
+@(test)
+test_parse_int_string :: proc(t: ^testing.T) {
+	v64, err := num_format.parse_int_string("-9223372036854775808", i64)
+	testing.expect(t, err == .None && v64 == min(i64), "Expected i64::MIN")
+
+	v8: u8
+	v8, err = num_format.parse_int_string("256", u8)
+	testing.expect(t, err == .Overflow && v8 == 255, "Expected saturated overflow")
+
+	v16: i16
+	v16, err = num_format.parse_int_string("-40000", i16)
+	testing.expect(t, err == .Neg_Overflow && v16 == min(i16), "Expected saturated negative overflow")
+
+	_, err = num_format.parse_int_string("12ab", int)
+	testing.expect(t, err == .Invalid, "Expected error for trailing text")
+
+	_, err = num_format.parse_int_string("", u32)
+	testing.expect(t, err == .Empty, "Expected error for empty input")
+}
+
+@(test)
+test_parse_int_128_bit :: proc(t: ^testing.T) {
+	value, err := num_format.parse_int_string("-170141183460469231731687303715884105728", i128)
+	testing.expect(t, err == .None && value == min(i128), "Expected i128::MIN")
+
+	big, big_err := num_format.parse_int_string("340282366920938463463374607431768211455", u128)
+	testing.expect(t, big_err == .None && big == max(u128), "Expected u128::MAX")
+}
+
+@(test)
+test_parse_int_prefix :: proc(t: ^testing.T) {
+	value, consumed, err := num_format.parse_int_prefix("404 Not Found", u16)
+	testing.expect(t, err == .None, "Expected successful parse")
+	testing.expect(t, value == 404 && consumed == 3, "Expected the numeric prefix")
+}
+
+@(test)
+test_grouped_integers :: proc(t: ^testing.T) {
+	buf: [64]u8
+
+	str, ok := num_format.format_grouped_buffer(12345678, buf[:])
+	testing.expect(t, ok && str == "12,345,678", "Expected default grouping")
+
+	indian, indian_ok := num_format.make_grouping(",", ".", 3, 2)
+	testing.expect(t, indian_ok, "Expected valid Indian grouping")
+	str, ok = num_format.format_grouped_buffer(i64(-123456789), buf[:], &indian)
+	testing.expect(t, ok && str == "-12,34,56,789", "Expected Indian grouping")
+
+	str, ok = num_format.format_grouped_buffer(max(u128), buf[:])
+	testing.expect(t, ok && str == "340,282,366,920,938,463,463,374,607,431,768,211,455", "Expected u128::MAX")
+
+	small_buf: [4]u8
+	_, ok = num_format.format_grouped_buffer(1000000, small_buf[:])
+	testing.expect(t, !ok, "Expected error for small buffer")
+
+	_, indian_ok = num_format.make_grouping(primary = 256)
+	testing.expect(t, !indian_ok, "Expected error for group size above 255")
+}
+
+@(test)
+test_grouped_floats :: proc(t: ^testing.T) {
+	french, _ := num_format.make_grouping("\u202F", ",")
+	str, ok := num_format.format_grouped_f64_to_string(1234567.891, 2, &french)
+	defer delete(str)
+	testing.expect(t, ok && str == "1\u202F234\u202F567,89", "Expected narrow no-break space grouping")
+
+	buf: [64]u8
+	shortest, shortest_ok := num_format.format_grouped_f64_buffer(1e20, buf[:])
+	testing.expect(t, shortest_ok && shortest == "100,000,000,000,000,000,000.0", "Expected positional output")
+
+	_, shortest_ok = num_format.format_grouped_f64_buffer(1.5, buf[:], 1 << 31)
+	testing.expect(t, !shortest_ok, "Expected error for precision out of range")
+}
+
+@(test)
+test_sig_formatting :: proc(t: ^testing.T) {
+	buf: [64]u8
+
+	str, ok := num_format.format_sig_f64_buffer(3.14159265, 4, buf[:])
+	testing.expect(t, ok && str == "3.142", "Expected fixed notation")
+
+	str, ok = num_format.format_sig_f64_buffer(0.000123456, 4, buf[:])
+	testing.expect(t, ok && str == "0.0001235", "Expected fixed notation down to 1e-4")
+
+	str, ok = num_format.format_sig_f64_buffer(1.23456e20, 4, buf[:], {.Uppercase})
+	testing.expect(t, ok && str == "1.235E+20", "Expected exponent notation")
+
+	str, ok = num_format.format_sig_f32_buffer(100, 3, buf[:], {.Keep_Trailing_Zeros})
+	testing.expect(t, ok && str == "100.", "Expected the decimal point to be kept")
+
+	kept, kept_ok := num_format.format_sig_f64_to_string(2.5, 4, {.Keep_Trailing_Zeros})
+	defer delete(kept)
+	testing.expect(t, kept_ok && kept == "2.500", "Expected trailing zeros")
+
+	_, ok = num_format.format_sig_f64_buffer(1, num_format.MAX_FIXED_PRECISION + 1, buf[:])
+	testing.expect(t, !ok, "Expected error for too many digits")
+
+	_, ok = num_format.format_sig_f64_buffer(1, (1 << 32) + 3, buf[:])
+	testing.expect(t, !ok, "Expected error instead of truncating digits")
+}
+
+@(test)
+test_padded_integers :: proc(t: ^testing.T) {
+	buf: [64]u8
+
+	str, ok := num_format.format_padded_buffer(-42, buf[:], {width = 5, align = .Zero_Pad})
+	testing.expect(t, ok && str == "-0042", "Expected sign-aware zero padding")
+
+	str, ok = num_format.format_padded_buffer(u8(7), buf[:], {width = 4, plus_sign = true})
+	testing.expect(t, ok && str == "  +7", "Expected right alignment with a forced sign")
+
+	str, ok = num_format.format_padded_buffer(i128(-1), buf[:], {width = 5, fill = '·', align = .Center})
+	testing.expect(t, ok && str == "·-1··", "Expected centered multi-byte fill")
+
+	left, left_ok := num_format.format_padded_to_string(42, {width = 6, fill = '.', align = .Left})
+	defer delete(left)
+	testing.expect(t, left_ok && left == "42....", "Expected left alignment")
+
+	small_buf: [4]u8
+	_, ok = num_format.format_padded_buffer(1, small_buf[:], {width = 8})
+	testing.expect(t, !ok, "Expected error for small buffer")
+}
+
+@(test)
+test_format_spec :: proc(t: ^testing.T) {
+	buf: [64]u8
+
+	str, ok := num_format.format_buffer(255, "#010x", buf[:])
+	testing.expect(t, ok && str == "0x000000ff", "Expected prefixed zero-padded hex")
+
+	str, ok = num_format.format_buffer(1234567.891, ",.2f", buf[:])
+	testing.expect(t, ok && str == "1,234,567.89", "Expected grouped fixed notation")
+
+	str, ok = num_format.format_buffer(f32(0.25), ".1%", buf[:])
+	testing.expect(t, ok && str == "25.0%", "Expected percent")
+
+	str, ok = num_format.format_buffer(max(u128), "_x", buf[:])
+	testing.expect(t, ok && str == "ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", "Expected grouped u128 hex")
+
+	sci, sci_ok := num_format.format(1234.5678, ">+12,.3e")
+	defer delete(sci)
+	testing.expect(t, sci_ok && sci == "  +1.235e+03", "Expected padded scientific notation")
+
+	_, ok = num_format.format_buffer(1.5, "x", buf[:])
+	testing.expect(t, !ok, "Expected error for an integer type on a float")
+
+	_, ok = num_format.format_buffer(1, "q", buf[:])
+	testing.expect(t, !ok, "Expected error for a malformed spec")
+}
+
+@(test)
+test_compiled_spec :: proc(t: ^testing.T) {
+	spec := num_format.compile_spec(">8.2f")
+	defer num_format.destroy_spec(spec)
+	testing.expect(t, spec != nil, "Expected spec to compile")
+
+	buf: [32]u8
+	str, ok := num_format.format_compiled_buffer(-12.345, spec, buf[:])
+	testing.expect(t, ok && str == "  -12.35", "Expected padded fixed notation")
+
+	str, ok = num_format.format_compiled_buffer(i32(3), spec, buf[:])
+	testing.expect(t, ok && str == "    3.00", "Expected integer converted to float")
+
+	testing.expect(t, num_format.compile_spec("5.") == nil, "Expected nil for a malformed spec")
+}
+
+@(test)
+test_formatter_profiles :: proc(t: ^testing.T) {
+	german := num_format.make_formatter(decimal_sep = ",", group_sep = ".", primary = 3, notation = .Fixed, precision = 2)
+	defer num_format.destroy_formatter(german)
+	testing.expect(t, german != nil, "Expected formatter to be created")
+
+	buf: [64]u8
+	str, ok := num_format.format_with_buffer(german, 1234567.891, buf[:])
+	testing.expect(t, ok && str == "1.234.567,89", "Expected German separators")
+
+	str, ok = num_format.format_with_buffer(german, -9876543, buf[:])
+	testing.expect(t, ok && str == "-9.876.543", "Expected grouped integer")
+
+	report := num_format.make_formatter(
+		policy = num_format.make_float_policy(nan = "n/a", normalize_negative_zero = true),
+		notation = .Scientific,
+		precision = 3,
+		min_exp_digits = 2,
+	)
+	defer num_format.destroy_formatter(report)
+
+	sci, sci_ok := num_format.format_with(report, 1234.5678)
+	defer delete(sci)
+	testing.expect(t, sci_ok && sci == "1.235e+03", "Expected scientific profile")
+
+	str, ok = num_format.format_with_buffer(report, f32(0h7FC00000), buf[:])
+	testing.expect(t, ok && str == "n/a", "Expected custom NaN spelling")
+
+	str, ok = num_format.format_with_buffer(report, -0.0, buf[:])
+	testing.expect(t, ok && str == "0.000e+00", "Expected normalized negative zero")
+
+	testing.expect(t, num_format.make_formatter(precision = 2000) == nil, "Expected nil for too large precision")
+	testing.expect(t, num_format.make_formatter(primary = 256) == nil, "Expected nil for too large group size")
+	testing.expect(t, num_format.make_formatter(min_exp_digits = -1) == nil, "Expected nil for negative exponent digits")
+
+	plain := num_format.make_formatter(group_sep = "", primary = 3)
+	defer num_format.destroy_formatter(plain)
+	str, ok = num_format.format_with_buffer(plain, 1234567, buf[:])
+	testing.expect(t, ok && str == "1234567", "Expected empty group separator to stay empty")
+}
+
+@(test)
+test_float_policy :: proc(t: ^testing.T) {
+	buf: [32]u8
+	js := num_format.make_float_policy(.JS)
+	str, ok := num_format.format_policy_buffer(math.inf_f64(-1), buf[:], js)
+	testing.expect(t, ok && str == "-Infinity", "Expected JavaScript infinity")
+
+	str, ok = num_format.format_policy_buffer(f32(2.5), buf[:], js)
+	testing.expect(t, ok && str == "2.5", "Expected finite values unchanged")
+
+	yaml := num_format.make_float_policy(.YAML, normalize_negative_zero = true)
+	str, ok = num_format.format_policy_buffer(math.nan_f64(), buf[:], yaml)
+	testing.expect(t, ok && str == ".nan", "Expected YAML NaN")
+
+	str, ok = num_format.format_policy_buffer(-0.0, buf[:], yaml)
+	testing.expect(t, ok && str == "0.0", "Expected normalized negative zero")
+
+	custom := num_format.make_float_policy(.JSON, inf = "1e999")
+	json, json_ok := num_format.format_policy_to_string(math.inf_f64(1), custom)
+	defer delete(json)
+	testing.expect(t, json_ok && json == "1e999", "Expected custom infinity spelling")
+
+	str, ok = num_format.format_policy_buffer(math.nan_f32(), buf[:], custom)
+	testing.expect(t, ok && str == "null", "Expected JSON null for NaN")
+}
+
+@(test)
+test_json_numbers :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_json_buffer(42.0, buf[:])
+	testing.expect(t, ok && str == "42.0", "Expected zmij output by default")
+
+	_, ok = num_format.format_json_buffer(math.nan_f64(), buf[:])
+	testing.expect(t, !ok, "Expected failure for NaN without a substitute")
+
+	opts := num_format.make_json_options(integers_without_fraction = true, non_finite = "null")
+	str, ok = num_format.format_json_buffer(-42.0, buf[:], opts)
+	testing.expect(t, ok && str == "-42", "Expected integral value without fraction")
+
+	str, ok = num_format.format_json_buffer(f32(0.25), buf[:], opts)
+	testing.expect(t, ok && str == "0.25", "Expected fraction kept")
+
+	json, json_ok := num_format.format_json_to_string(math.inf_f64(-1), opts)
+	defer delete(json)
+	testing.expect(t, json_ok && json == "null", "Expected null for infinity")
+}
+
+@(test)
+test_format_array :: proc(t: ^testing.T) {
+	buf: [16]u8
+	values := []f64{1.5, 22.25, 333.125}
+	text, written := num_format.format_array_buffer(values, buf[:])
+	testing.expect(t, text == "1.5,22.25" && written == 2, "Expected the whole elements that fit")
+
+	text, written = num_format.format_array_buffer(values[written:], buf[:])
+	testing.expect(t, text == "333.125" && written == 1, "Expected resume from the next element")
+
+	ints := []i16{-32768, 0, 7}
+	joined := num_format.format_array_to_string(ints, "; ")
+	defer delete(joined)
+	testing.expect(t, joined == "-32768; 0; 7", "Expected delimited integers")
+
+	wide := []u128{max(u128), 1}
+	wide_text := num_format.format_array_to_string(wide)
+	defer delete(wide_text)
+	testing.expect(t, wide_text == "340282366920938463463374607431768211455,1", "Expected 128-bit integers")
+}
+
+@(test)
+test_format_table :: proc(t: ^testing.T) {
+	fixed := num_format.compile_spec(".2f")
+	defer num_format.destroy_spec(fixed)
+
+	columns := []num_format.Table_Column{{nil, 6}, {fixed, 9}}
+	table, ok := num_format.format_table([]f64{1.5, 1234.567, -0.125, 3}, columns)
+	defer delete(table)
+	testing.expect(t, ok && table == "   1.5   1234.57\n-0.125      3.00\n", "Expected aligned rows")
+
+	buf: [64]u8
+	str, str_ok := num_format.format_table_buffer([]i64{1, 22, 333}, []num_format.Table_Column{{nil, 4}}, buf[:])
+	testing.expect(t, str_ok && str == "   1\n  22\n 333\n", "Expected a single right-aligned column")
+
+	_, str_ok = num_format.format_table_buffer([]i64{1, 2, 3}, columns, buf[:])
+	testing.expect(t, !str_ok, "Expected failure for a partial row")
+}
+
+@(test)
+test_write_builder_and_writer :: proc(t: ^testing.T) {
+	b := strings.builder_make()
+	defer strings.builder_destroy(&b)
+
+	n := num_format.write_builder(&b, 3.25)
+	testing.expect(t, n == 4, "Expected bytes appended")
+	strings.write_byte(&b, ' ')
+	num_format.write_builder(&b, i8(-128))
+	strings.write_byte(&b, ' ')
+	num_format.write_builder(&b, u128(1) << 100)
+	strings.write_byte(&b, ' ')
+	num_format.write_builder(&b, f32(0.1))
+	testing.expect(
+		t,
+		strings.to_string(b) == "3.25 -128 1267650600228229401496703205376 0.1",
+		"Expected numbers appended in place",
+	)
+
+	strings.builder_reset(&b)
+	w := strings.to_writer(&b)
+	written, err := num_format.write_number(w, -42)
+	testing.expect(t, written == 3 && err == nil, "Expected write through io.Writer")
+	num_format.write_number(w, math.inf_f64(1))
+	testing.expect(t, strings.to_string(b) == "-42inf", "Expected writer output")
+}
+
+@(test)
+test_fmt_formatters :: proc(t: ^testing.T) {
+	formatters: map[typeid]fmt.User_Formatter
+	defer delete(formatters)
+	fmt.set_user_formatters(&formatters)
+	defer fmt.set_user_formatters(nil)
+	num_format.register_fmt_formatters(&formatters)
+
+	buf: [128]u8
+	str := fmt.bprintf(buf[:], "%v|%10.3f|%-8.2e|", num_format.Fast_F64(0.1), num_format.Fast_F64(3.14159), num_format.Fast_F32(1234.5))
+	testing.expect(t, str == "0.1|     3.142|1.23e+03|", "Expected floats through num_format")
+
+	str = fmt.bprintf(buf[:], "%d|%+06d|%#x|%v", num_format.Fast_I64(-42), num_format.Fast_I64(42), num_format.Fast_U64(255), num_format.Fast_U64(7))
+	testing.expect(t, str == "-42|+00042|0xff|7", "Expected integers through num_format")
+}
+
+@(test)
+test_format_bytes :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_bytes_buffer(3 << 29, buf[:], {precision = 1})
+	testing.expect(t, ok && str == "1.5 GiB", "Expected IEC size")
+
+	str, ok = num_format.format_bytes_buffer(1024 * 1024 - 1, buf[:], {precision = 1})
+	testing.expect(t, ok && str == "1.0 MiB", "Expected rounding into the next unit")
+
+	str, ok = num_format.format_bytes_buffer(12_345_678, buf[:], {base = .SI, precision = 2, no_space = true})
+	testing.expect(t, ok && str == "12.35MB", "Expected SI size without space")
+
+	long, long_ok := num_format.format_bytes_to_string(1, {units = .Long})
+	defer delete(long)
+	testing.expect(t, long_ok && long == "1 byte", "Expected singular byte")
+
+	_, ok = num_format.format_bytes_buffer(1, buf[:], {precision = 19})
+	testing.expect(t, !ok, "Expected failure for too large precision")
+}
+
+@(test)
+test_format_compact :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_compact_buffer(1234, buf[:])
+	testing.expect(t, ok && str == "1.23K", "Expected short suffix")
+
+	str, ok = num_format.format_compact_buffer(u64(999_950), buf[:])
+	testing.expect(t, ok && str == "1M", "Expected rounding into the next suffix")
+
+	hertz, hertz_ok := num_format.make_compact_options(.SI, digits = 2, separator = " ", unit = "Hz")
+	testing.expect(t, hertz_ok, "Expected valid compact options")
+	str, ok = num_format.format_compact_buffer(4700.0, buf[:], hertz)
+	testing.expect(t, ok && str == "4.7 kHz", "Expected SI prefix with unit")
+
+	indian := []num_format.Compact_Suffix{num_format.compact_suffix(5, "L"), num_format.compact_suffix(7, "Cr")}
+	lakhs, _ := num_format.make_compact_options(.Custom, suffixes = indian)
+	lakh, lakh_ok := num_format.format_compact_to_string(i64(250_000), lakhs)
+	defer delete(lakh)
+	testing.expect(t, lakh_ok && lakh == "2.5L", "Expected custom suffix")
+
+	str, ok = num_format.format_compact_buffer(math.nan_f64(), buf[:])
+	testing.expect(t, ok && str == "NaN", "Expected zmij NaN spelling")
+
+	_, hertz_ok = num_format.make_compact_options(digits = 256)
+	testing.expect(t, !hertz_ok, "Expected error for digits above 255")
+	_, hertz_ok = num_format.make_compact_options(digits = -1)
+	testing.expect(t, !hertz_ok, "Expected error for negative digits")
+}
+
+@(test)
+test_format_duration :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_duration_buffer(3723500 * time.Millisecond, buf[:])
+	testing.expect(t, ok && str == "1h2m3.5s", "Expected Go-style duration")
+
+	str, ok = num_format.format_duration_buffer(12_345 * time.Microsecond, buf[:])
+	testing.expect(t, ok && str == "12.345ms", "Expected sub-second unit")
+
+	str, ok = num_format.format_duration_buffer(59_999 * time.Millisecond, buf[:], {precision = 1})
+	testing.expect(t, ok && str == "1m0s", "Expected rounding into the next unit")
+
+	iso, iso_ok := num_format.format_duration_to_string(26 * time.Hour, {style = .ISO8601, largest_unit = .Days, precision = -1})
+	defer delete(iso)
+	testing.expect(t, iso_ok && iso == "P1DT2H", "Expected ISO 8601 duration")
+
+	_, ok = num_format.format_duration_buffer(time.Second, buf[:], {precision = 10})
+	testing.expect(t, !ok, "Expected failure for too large precision")
+}
+
+@(test)
+test_format_time :: proc(t: ^testing.T) {
+	buf: [num_format.TIMESTAMP_BUFFER_SIZE]u8
+	moment := time.unix(1_792_154_096, 123_456_789)
+	str, ok := num_format.format_time_buffer(moment, buf[:])
+	testing.expect(t, ok && str == "2026-10-16T12:34:56Z", "Expected whole seconds in UTC")
+
+	str, ok = num_format.format_time_buffer(moment, buf[:], {fraction_digits = 9})
+	testing.expect(t, ok && str == "2026-10-16T12:34:56.123456789Z", "Expected nanoseconds")
+
+	local, local_ok := num_format.format_time_to_string(moment, {fraction_digits = 3, offset_minutes = 330})
+	defer delete(local)
+	testing.expect(t, local_ok && local == "2026-10-16T18:04:56.123+05:30", "Expected UTC offset")
+
+	_, ok = num_format.format_time_buffer(moment, buf[:19])
+	testing.expect(t, !ok, "Expected failure for too small buffer")
+}
+
+@(test)
+test_parse_time :: proc(t: ^testing.T) {
+	moment, offset, consumed, err := num_format.parse_time_prefix("2026-10-16T18:04:56.5+05:30 GET /")
+	testing.expect(t, err == .None && consumed == 27, "Expected timestamp prefix")
+	testing.expect(t, time.time_to_unix_nano(moment) == 1_792_154_096_500_000_000 && offset == 330, "Expected UTC time and offset")
+
+	_, _, err = num_format.parse_time_string("2026-02-29T00:00:00Z")
+	testing.expect(t, err == .Invalid, "Expected invalid date")
+
+	_, _, err = num_format.parse_time_string("2300-01-01T00:00:00Z")
+	testing.expect(t, err == .Overflow, "Expected overflow past time.Time range")
+}
+
+@(test)
+test_format_scaled :: proc(t: ^testing.T) {
+	buf: [32]u8
+	str, ok := num_format.format_scaled_buffer(123456, 2, buf[:])
+	testing.expect(t, ok && str == "1234.56", "Expected decimal point at scale")
+
+	str, ok = num_format.format_scaled_buffer(120000, 4, buf[:], {min_fraction_digits = 2})
+	testing.expect(t, ok && str == "12.00", "Expected minimum fraction digits")
+
+	commas, _ := num_format.make_grouping()
+	grouped, grouped_ok := num_format.format_scaled_to_string(-123456789, 2, {grouping = &commas})
+	defer delete(grouped)
+	testing.expect(t, grouped_ok && grouped == "-1,234,567.89", "Expected grouped amount")
+
+	_, ok = num_format.format_scaled_buffer(1, num_format.SCALED_MAX_SCALE + 1, buf[:])
+	testing.expect(t, !ok, "Expected failure for too large scale")
+}
+
+@(test)
+test_parse_scaled :: proc(t: ^testing.T) {
+	cents, consumed, err := num_format.parse_scaled_prefix("12.5 USD", 2)
+	testing.expect(t, err == .None && cents == 1250 && consumed == 4, "Expected scaled prefix")
+
+	commas, _ := num_format.make_grouping()
+	value: i64
+	value, err = num_format.parse_scaled_string("1,234.5", 2, {grouping = &commas})
+	testing.expect(t, err == .None && value == 123450, "Expected grouped input")
+
+	value, err = num_format.parse_scaled_string("1.239", 2)
+	testing.expect(t, err == .Excess_Precision && value == 123, "Expected excess precision")
+
+	_, err = num_format.parse_scaled_string("92233720368547758.08", 2)
+	testing.expect(t, err == .Overflow, "Expected overflow")
+}
+
+@(test)
+test_format_currency :: proc(t: ^testing.T) {
+	buf: [32]u8
+	usd := num_format.make_currency("USD", negative = .Accounting)
+	defer num_format.destroy_currency(usd)
+	str, ok := num_format.format_currency_buffer(usd, 123456, buf[:])
+	testing.expect(t, ok && str == "$1,234.56", "Expected prefix symbol")
+
+	str, ok = num_format.format_currency_buffer(usd, -123456, buf[:])
+	testing.expect(t, ok && str == "($1,234.56)", "Expected accounting negative")
+
+	european, _ := num_format.make_grouping(".", ",")
+	eur := num_format.make_currency("EUR", placement = .Suffix, grouping = &european)
+	defer num_format.destroy_currency(eur)
+	euros, euros_ok := num_format.format_currency(eur, -123456)
+	defer delete(euros)
+	testing.expect(t, euros_ok && euros == "-1.234,56 €", "Expected suffix symbol")
+
+	jpy := num_format.make_currency("JPY", display = .Code)
+	defer num_format.destroy_currency(jpy)
+	str, ok = num_format.format_currency_buffer(jpy, 1235, buf[:])
+	testing.expect(t, ok && str == "JPY 1,235", "Expected ISO code without minor digits")
+
+	testing.expect(t, num_format.currency_digits("BHD") == 3, "Expected three minor digits")
+	testing.expect(t, num_format.make_currency("XXX") == nil, "Expected nil for unknown code")
+	testing.expect(t, num_format.make_currency("USD", minor_digits = 255) == nil, "Expected nil instead of the table's digits")
+}
@@ -0,0 +1,2444 @@
+/*
+ * num_format C FFI Interface
+ *
+ * Fast number <-> string conversion (zmij + itoa) for C and Odin.
+ *
+ * GENERATED from rust-ffi/src by `NUM_FORMAT_BLESS=1 cargo test header`.
+ * Do not edit by hand.
+ *
+ * All lengths and return values use size_t, matching Rust's usize.
+ * Output is UTF-8 without a null terminator.
+ */
+
+#ifndef NUM_FORMAT_FFI_H
+#define NUM_FORMAT_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// ============================================================================
+// Zmij C FFI Wrappers - Fast floating point to string conversion
+// ============================================================================
+// All functions write UTF-8 formatted output to the provided buffer
+// and return the number of bytes written (0 if buffer too small)
+
+// ============================================================================
+// itoa C FFI Wrappers - Fast integer to string conversion
+// ============================================================================
+// All functions write UTF-8 formatted output to the provided buffer
+// and return the number of bytes written (0 if buffer too small)
+
+/** Buffer size the zmij_format_* functions require (size of zmij::Buffer) */
+#define ZMIJ_BUFFER_SIZE 24
+
+/** Buffer size the rust_itoa_* functions require (i128::MAX_STR_LEN) */
+#define ITOA_BUFFER_SIZE 40
+
+/**
+ * Format f64 floating point to string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 24 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t zmij_format_f64(double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 floating point to string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 24 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t zmij_format_f32(float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 assuming it is finite (no NaN/inf checks)
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - value must be a finite floating point number (not NaN or infinity)
+ * - buf_len should be >= 24 for guaranteed success
+ *
+ * # Undefined Behavior
+ * Calling with non-finite values produces unspecified output
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t zmij_format_finite_f64(double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 assuming it is finite (no NaN/inf checks)
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - value must be a finite floating point number (not NaN or infinity)
+ * - buf_len should be >= 24 for guaranteed success
+ *
+ * # Undefined Behavior
+ * Calling with non-finite values produces unspecified output
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t zmij_format_finite_f32(float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i64 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_i64(int64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_u64(uint64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i32 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_i32(int32_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u32 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_u32(uint32_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128 integer, passed as `(hi << 64) | lo`, to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_i128(int64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128 integer, passed as `(hi << 64) | lo`, to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_u128(uint64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i16 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_i16(int16_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u16 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_u16(uint16_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i8 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_i8(int8_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u8 integer to UTF-8 string
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= 40 for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small
+ */
+size_t rust_itoa_u8(uint8_t value, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// snprintf-style C FFI Wrappers - Return the required length
+// ============================================================================
+// The `_fit` variants accept any buffer that is large enough for the actual
+// text instead of the 24/40 byte scratch size. They always return the length
+// the output requires: if it is <= buf_len the text was written, otherwise
+// the buffer contents are unspecified and the caller can retry with exactly
+// that many bytes. buf may be null when buf_len is 0 to only measure.
+
+/**
+ * Format f64 floating point to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t zmij_format_f64_fit(double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 floating point to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t zmij_format_f32_fit(float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format finite f64 (no NaN/inf checks), returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - value must be a finite floating point number (not NaN or infinity)
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t zmij_format_finite_f64_fit(double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format finite f32 (no NaN/inf checks), returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - value must be a finite floating point number (not NaN or infinity)
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t zmij_format_finite_f32_fit(float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i64 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_i64_fit(int64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_u64_fit(uint64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i32 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_i32_fit(int32_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u32 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_u32_fit(uint32_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128 integer, passed as `(hi << 64) | lo`, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_i128_fit(int64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128 integer, passed as `(hi << 64) | lo`, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_u128_fit(uint64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i16 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_i16_fit(int16_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u16 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_u16_fit(uint16_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i8 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_i8_fit(int8_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u8 integer to string, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t rust_itoa_u8_fit(uint8_t value, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Batch C FFI Wrappers - Delimited runs of numbers in one call
+// ============================================================================
+// Format `count` values separated by a delimiter into one buffer, saving an
+// FFI crossing per element for CSV and JSON exporters. Output stops cleanly
+// after the last whole element that fits; the number of elements written is
+// stored in `written_count` so the caller can flush the buffer and resume
+// from `values + written_count` (writing the delimiter between the runs).
+// Functions return the number of bytes written, never a partial element.
+//
+// 128-bit variants take `2 * count` u64 words holding the values in native
+// memory layout, which is how an Odin `[]i128` or C `__int128[]` is stored.
+
+/**
+ * Format an array of f64 values separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t zmij_format_f64_array(const double *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of f32 values separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t zmij_format_f32_array(const float *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of i64 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_i64_array(const int64_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of u64 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_u64_array(const uint64_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of i32 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_i32_array(const int32_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of u32 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_u32_array(const uint32_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of i16 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_i16_array(const int16_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of u16 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_u16_array(const uint16_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of i8 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_i8_array(const int8_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of u8 integers separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of count elements (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_u8_array(const uint8_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of i128 integers, stored as native-layout u64 pairs, separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of 2 * count u64 words (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_i128_array(const uint64_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+/**
+ * Format an array of u128 integers, stored as native-layout u64 pairs, separated by a delimiter
+ *
+ * # Safety
+ * - values must be valid for reads of 2 * count u64 words (may be null if count is 0)
+ * - delimiter must be null or valid for reads of delimiter_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - written_count must be null or valid for writes
+ *
+ * # Returns
+ * Bytes written; the number of whole elements written is stored in written_count
+ */
+size_t rust_itoa_u128_array(const uint64_t *values, size_t count, const uint8_t *delimiter, size_t delimiter_len, uint8_t *buf, size_t buf_len, size_t *written_count);
+
+// ============================================================================
+// Byte-size C FFI Wrappers - `1.5 GiB`, `12.3 MB`, `512 bytes`
+// ============================================================================
+// Scale a u64 byte count to the largest IEC (1024-based) or SI (1000-based)
+// unit it reaches and print it with a fixed number of fraction digits. The
+// division is exact integer arithmetic rounded half-to-even, and a value that
+// rounds up to the next unit moves there (`1.0 MiB`, never `1024.0 KiB`).
+// Plain byte counts have no fraction. Functions return the length the output
+// requires and write it only if it is <= buf_len (snprintf-style); 0 means
+// the options were invalid.
+
+/** 1024-based units: KiB, MiB, GiB, ... */
+#define NUM_BYTES_IEC 0
+
+/** 1000-based units: kB, MB, GB, ... */
+#define NUM_BYTES_SI 1
+
+/** Unit symbols: `B`, `KiB`, `kB` */
+#define NUM_BYTES_SHORT 0
+
+/** Unit names: `bytes`, `kibibytes`, `kilobytes` */
+#define NUM_BYTES_LONG 1
+
+/** Largest number of fraction digits num_format_bytes accepts */
+#define NUM_BYTES_MAX_PRECISION 18
+
+/**
+ * Options for num_format_bytes
+ *
+ * A null pointer behaves like a zeroed struct: IEC symbols, no fraction
+ * digits, a space before the unit.
+ */
+typedef struct NumByteSizeOptions {
+    /** NUM_BYTES_IEC or NUM_BYTES_SI */
+    uint8_t base;
+    /** NUM_BYTES_SHORT or NUM_BYTES_LONG */
+    uint8_t units;
+    /** Fraction digits for scaled values (0..=NUM_BYTES_MAX_PRECISION) */
+    uint8_t precision;
+    /** Omit the space between number and unit (`1.5GiB`) */
+    bool no_space;
+} NumByteSizeOptions;
+
+/**
+ * Format a byte count as a human-readable size such as `1.5 GiB`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumByteSizeOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if base or units is unknown or precision exceeds NUM_BYTES_MAX_PRECISION
+ */
+size_t num_format_bytes(uint64_t value, const NumByteSizeOptions *options, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Compact C FFI Wrappers - Abbreviated numbers such as `1.2K`, `45M`, `12.3 µs`
+// ============================================================================
+// Round to N significant digits, then pick the suffix with the largest power
+// of ten not above the rounded value, so 999,950 at 3 digits becomes `1M`
+// rather than `1000K`. Trailing zeros are dropped. Tables: short English
+// (K, M, B, T), SI prefixes (q ... µ m k M ... Q) or a caller supplied list
+// of (exponent, suffix) pairs. An optional separator and unit follow the
+// number (`12.3 µs`, `4.7 kHz`). NaN and infinities use the zmij spellings,
+// and -0.0 prints as `0` like 0.0.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style); 0 means the options were invalid.
+
+/** K, M, B, T (thousand, million, billion, trillion) */
+#define NUM_COMPACT_SHORT 0
+
+/** SI prefixes from q (10^-30) to Q (10^30), `µ` for micro */
+#define NUM_COMPACT_SI 1
+
+/** The suffixes / suffix_count table of NumCompactOptions */
+#define NUM_COMPACT_CUSTOM 2
+
+/** One entry of a custom suffix table */
+typedef struct NumCompactSuffix {
+    /** Power of ten the suffix stands for (3 for `K`) */
+    int32_t exponent;
+    /** Suffix text (UTF-8) */
+    const uint8_t *suffix;
+    /** Length of suffix in bytes */
+    size_t suffix_len;
+} NumCompactSuffix;
+
+/**
+ * Options for the compact formatters
+ *
+ * A null pointer behaves like a zeroed struct: short English suffixes,
+ * 3 significant digits, no separator or unit.
+ */
+typedef struct NumCompactOptions {
+    /** One of the NUM_COMPACT_* tables */
+    uint8_t table;
+    /** Significant digits, 0 for 3 */
+    uint8_t digits;
+    /** Written between the number and a non-empty suffix or unit (UTF-8, may be null) */
+    const uint8_t *separator;
+    /** Length of separator in bytes */
+    size_t separator_len;
+    /** Written after the suffix, such as `s` or `Hz` (UTF-8, may be null) */
+    const uint8_t *unit;
+    /** Length of unit in bytes */
+    size_t unit_len;
+    /** Entries for NUM_COMPACT_CUSTOM, sorted by strictly increasing exponent */
+    const NumCompactSuffix *suffixes;
+    /** Number of entries in suffixes */
+    size_t suffix_count;
+} NumCompactOptions;
+
+/**
+ * Format f64 in compact notation such as `1.2K` or `12.3 µs`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumCompactOptions whose
+ *   strings and suffix table are null or valid for their lengths
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the table is unknown, or custom and empty or not sorted
+ */
+size_t num_format_compact_f64(double value, const NumCompactOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i64 in compact notation such as `-45M`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumCompactOptions whose
+ *   strings and suffix table are null or valid for their lengths
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the table is unknown, or custom and empty or not sorted
+ */
+size_t num_format_compact_i64(int64_t value, const NumCompactOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 in compact notation such as `3.1B`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumCompactOptions whose
+ *   strings and suffix table are null or valid for their lengths
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the table is unknown, or custom and empty or not sorted
+ */
+size_t num_format_compact_u64(uint64_t value, const NumCompactOptions *options, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Currency handle C FFI Wrappers - `$1,234.56`, `(1.234,56 €)`, `JPY 1,235`
+// ============================================================================
+// A NumCurrency combines an ISO 4217 currency from a small built-in table
+// (symbol and minor-unit digits) with a display style: symbol or code, before
+// or after the amount, a space rule, and `-` or accounting parentheses for
+// negatives. Amounts are i64 minor units printed through the scaled integer
+// formatter, so every minor digit is shown (`$5.00`, `¥1,235`, `BD 1.250`).
+// The configuration strings are copied into the handle. Functions return the
+// length the output requires and write it only if it is <= buf_len
+// (snprintf-style).
+
+/** Show the currency symbol (`$`, `€`, `CHF`) */
+#define NUM_CURRENCY_SYMBOL 0
+
+/** Show the ISO 4217 code (`USD`) */
+#define NUM_CURRENCY_CODE 1
+
+/** Symbol or code before the amount: `$1.00` */
+#define NUM_CURRENCY_PREFIX 0
+
+/** Symbol or code after the amount: `1,00 €` */
+#define NUM_CURRENCY_SUFFIX 1
+
+/**
+ * Space between amount and label, except after prefix symbols that do not
+ * end in a letter: `$1.00`, `CHF 1.00`, `USD 1.00`, `1.00 €`
+ */
+#define NUM_CURRENCY_SPACE_AUTO 0
+
+/** Never a space: `USD1.00`, `1.00€` */
+#define NUM_CURRENCY_SPACE_NEVER 1
+
+/** Always a space: `$ 1.00` */
+#define NUM_CURRENCY_SPACE_ALWAYS 2
+
+/** Minus before everything: `-$1.00`, `-1,00 €` */
+#define NUM_CURRENCY_NEGATIVE_MINUS 0
+
+/** Accounting parentheses around everything: `($1.00)`, `(1,00 €)` */
+#define NUM_CURRENCY_NEGATIVE_ACCOUNTING 1
+
+/**
+ * Configuration for num_currency_new
+ *
+ * The code is required. It selects the symbol and minor digits from the
+ * built-in table; a code outside the table needs minor_digits >= 0 and uses
+ * the code as its symbol unless one is given.
+ */
+typedef struct NumCurrencyConfig {
+    /** ISO 4217 code such as `USD` (UTF-8) */
+    const uint8_t *code;
+    /** Length of code in bytes */
+    size_t code_len;
+    /** Symbol replacing the table's (UTF-8, null for the table's) */
+    const uint8_t *symbol;
+    /** Length of symbol in bytes */
+    size_t symbol_len;
+    /** NUM_CURRENCY_SYMBOL or NUM_CURRENCY_CODE */
+    uint8_t display;
+    /** NUM_CURRENCY_PREFIX or NUM_CURRENCY_SUFFIX */
+    uint8_t placement;
+    /** One of the NUM_CURRENCY_SPACE_* constants */
+    uint8_t space;
+    /** NUM_CURRENCY_NEGATIVE_MINUS or NUM_CURRENCY_NEGATIVE_ACCOUNTING */
+    uint8_t negative;
+    /** Digits of the minor unit (0..=NUM_SCALED_MAX_SCALE), or -1 for the table's */
+    int8_t minor_digits;
+    /** Separators and grouping, or null for `1,234,567.89` */
+    const struct NumGrouping *grouping;
+} NumCurrencyConfig;
+
+/**
+ * A currency display profile created by num_currency_new
+ *
+ * Release with num_currency_free. A currency is immutable, so it may be
+ * shared between threads.
+ */
+typedef struct NumCurrency NumCurrency;
+
+/**
+ * Create a currency display profile
+ *
+ * # Safety
+ * - config must be null or point to a valid NumCurrencyConfig whose strings
+ *   and grouping are null or valid
+ *
+ * # Returns
+ * A handle to release with num_currency_free, or null if config is null, the
+ * code is empty or unknown without minor_digits, or a style is invalid
+ */
+NumCurrency *num_currency_new(const NumCurrencyConfig *config);
+
+/**
+ * Release a currency returned by num_currency_new (null is ignored)
+ *
+ * # Safety
+ * - currency must be null or a pointer returned by num_currency_new, not yet freed
+ */
+void num_currency_free(NumCurrency *currency);
+
+/**
+ * Format an amount in minor units (cents for USD) with a currency's profile
+ *
+ * # Safety
+ * - currency must be null or a live pointer returned by num_currency_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if currency is null
+ */
+size_t num_currency_format(const NumCurrency *currency, int64_t minor_units, uint8_t *buf, size_t buf_len);
+
+/**
+ * Minor-unit digits of a currency in the built-in table
+ *
+ * # Safety
+ * - code must be null or valid for reads of code_len bytes
+ *
+ * # Returns
+ * 2 for USD, 0 for JPY, 3 for BHD, ...; -1 if the code is not in the table
+ */
+int32_t num_currency_minor_digits(const uint8_t *code, size_t code_len);
+
+// ============================================================================
+// Duration C FFI Wrappers - `1h2m3.5s`, `12.345ms`, `PT1H2M3.5S`
+// ============================================================================
+// Format i64 nanoseconds (Odin's time.Duration) in Go's compact style or as an
+// ISO 8601 duration. Compact output below one second uses the largest of ns,
+// µs and ms that fits (`850ns`, `12.345ms`); from one second up it counts
+// down from the largest unit to seconds (`1h0m5s`). ISO output omits zero
+// components (`PT1H5S`). Fractions drop trailing zeros and are rounded
+// half-to-even to at most `precision` digits before the units are chosen, so
+// 59.999s at precision 1 prints `1m0s`. Functions return the length the
+// output requires and write it only if it is <= buf_len (snprintf-style); 0
+// means the options were invalid.
+
+/** Go's `time.Duration` style: `1h2m3.5s`, `12.345ms`, `850ns` */
+#define NUM_DURATION_COMPACT 0
+
+/** ISO 8601: `PT1H2M3.5S`, `P1DT2H` */
+#define NUM_DURATION_ISO8601 1
+
+/** Largest unit: hours (`26h3m`) */
+#define NUM_DURATION_HOURS 0
+
+/** Largest unit: days of 24 hours (`1d2h3m`) */
+#define NUM_DURATION_DAYS 1
+
+/** Largest unit: minutes (`1563m`) */
+#define NUM_DURATION_MINUTES 2
+
+/** Largest unit: seconds (`93784s`) */
+#define NUM_DURATION_SECONDS 3
+
+/**
+ * Options for num_format_duration
+ *
+ * A null pointer selects compact style, hours and all fraction digits.
+ */
+typedef struct NumDurationOptions {
+    /** NUM_DURATION_COMPACT or NUM_DURATION_ISO8601 */
+    uint8_t style;
+    /** One of NUM_DURATION_HOURS, _DAYS, _MINUTES, _SECONDS */
+    uint8_t largest_unit;
+    /** Maximum fraction digits (0..=9), or -1 for all of them */
+    int8_t precision;
+} NumDurationOptions;
+
+/**
+ * Format nanoseconds as a duration such as `1h2m3.5s` or `PT1H2M3.5S`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumDurationOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if style or largest_unit is unknown or precision is outside -1..=9
+ */
+size_t num_format_duration(int64_t nanoseconds, const NumDurationOptions *options, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Fixed-notation C FFI Wrappers - Exactly N digits after the decimal point
+// ============================================================================
+// Rounds half-to-even from the exact binary value, so the output matches
+// `format!("{:.N}", value)`. NaN and infinities use the same spellings as
+// zmij_format_f64. Functions return the number of bytes written, or 0 if the
+// buffer was too small; use zmij_fixed_buffer_size_* to size buffers.
+
+/** Largest supported precision (every digit of the smallest f64 subnormal) */
+#define ZMIJ_MAX_FIXED_PRECISION 1074
+
+/**
+ * Format f64 with exactly `precision` digits after the decimal point
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= zmij_fixed_buffer_size_f64(precision) for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small or
+ * precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_fixed_f64(double value, uint32_t precision, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with exactly `precision` digits after the decimal point
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= zmij_fixed_buffer_size_f32(precision) for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small or
+ * precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_fixed_f32(float value, uint32_t precision, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 with `precision` fractional digits, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_fixed_f64_fit(double value, uint32_t precision, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with `precision` fractional digits, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_fixed_f32_fit(float value, uint32_t precision, uint8_t *buf, size_t buf_len);
+
+/**
+ * Buffer size that fits any f64 formatted with zmij_format_fixed_f64
+ *
+ * # Returns
+ * Sign + 309 integer digits + decimal point + precision
+ */
+size_t zmij_fixed_buffer_size_f64(uint32_t precision);
+
+/**
+ * Buffer size that fits any f32 formatted with zmij_format_fixed_f32
+ *
+ * # Returns
+ * Sign + 39 integer digits + decimal point + precision
+ */
+size_t zmij_fixed_buffer_size_f32(uint32_t precision);
+
+// ============================================================================
+// Formatter handle C FFI Wrappers - Persistent formatting profiles
+// ============================================================================
+// A NumFormatter holds a decimal separator, digit grouping, a float policy
+// (NaN/infinity spellings, negative zero), notation, precision and exponent
+// style, so each subsystem can keep its own profile instead of passing every
+// option on every call. The configuration strings, including the policy's,
+// are copied, so they need not outlive the call to num_formatter_new.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style).
+
+/** Shortest round-trip digits, positional or exponent like zmij_format_f64 */
+#define NUM_NOTATION_SHORTEST 0
+
+/** Exactly `precision` digits after the decimal point (shortest if < 0) */
+#define NUM_NOTATION_FIXED 1
+
+/** Always an exponent, `precision` mantissa digits (shortest if < 0) */
+#define NUM_NOTATION_SCIENTIFIC 2
+
+/** `precision` significant digits like printf `%g` (6 if < 0) */
+#define NUM_NOTATION_GENERAL 3
+
+/**
+ * Configuration for num_formatter_new
+ *
+ * A null separator is empty, as in NumGrouping, so fill in both separators.
+ * A null config pointer selects `.`, no grouping, shortest notation and a
+ * signed exponent, matching zmij_format_f64 exactly.
+ */
+typedef struct NumFormatterConfig {
+    /** Separator between integer and fraction digits (UTF-8, null is empty) */
+    const uint8_t *decimal_sep;
+    /** Length of decimal_sep in bytes */
+    size_t decimal_sep_len;
+    /** Separator between digit groups (UTF-8, null is empty) */
+    const uint8_t *group_sep;
+    /** Length of group_sep in bytes */
+    size_t group_sep_len;
+    /** Digits in the group next to the decimal point, 0 disables grouping */
+    uint8_t primary;
+    /** Digits in every further group, 0 repeats primary */
+    uint8_t secondary;
+    /** NaN/infinity spellings and negative zero handling (null for zmij's) */
+    const struct ZmijFloatPolicy *policy;
+    /** One of the NUM_NOTATION_* constants */
+    uint8_t notation;
+    /** Digits for the notation, or < 0 for its default */
+    int32_t precision;
+    /** Use `E` instead of `e` as the exponent marker */
+    bool exp_uppercase;
+    /** Always print the exponent sign (`e+7` instead of `e7`) */
+    bool exp_plus_sign;
+    /** Zero-pad the exponent to at least this many digits */
+    uint8_t min_exp_digits;
+} NumFormatterConfig;
+
+/**
+ * A formatting profile created by num_formatter_new
+ *
+ * Release with num_formatter_free. A formatter is immutable, so it may be
+ * shared between threads.
+ */
+typedef struct NumFormatter NumFormatter;
+
+/**
+ * Create a formatter from a configuration (null = all defaults)
+ *
+ * # Safety
+ * - config must be null or point to a valid NumFormatterConfig whose
+ *   strings are null or valid for their lengths
+ *
+ * # Returns
+ * A handle to release with num_formatter_free, or null if notation or the
+ * policy preset is unknown or precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+NumFormatter *num_formatter_new(const NumFormatterConfig *config);
+
+/**
+ * Release a formatter returned by num_formatter_new (null is ignored)
+ *
+ * # Safety
+ * - formatter must be null or a pointer returned by num_formatter_new, not yet freed
+ */
+void num_formatter_free(NumFormatter *formatter);
+
+/**
+ * Format f64 with a formatter's profile (null = defaults)
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_f64(const NumFormatter *formatter, double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with a formatter's profile (null = defaults)
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_f32(const NumFormatter *formatter, float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i64 with a formatter's grouping (null = defaults)
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_i64(const NumFormatter *formatter, int64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 with a formatter's grouping (null = defaults)
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_u64(const NumFormatter *formatter, uint64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128, passed as `(hi << 64) | lo`, with a formatter's grouping
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_i128(const NumFormatter *formatter, int64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128, passed as `(hi << 64) | lo`, with a formatter's grouping
+ *
+ * # Safety
+ * - formatter must be null or a live pointer returned by num_formatter_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_formatter_u128(const NumFormatter *formatter, uint64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Grouped C FFI Wrappers - Thousands separators, e.g. `12,345,678.9`
+// ============================================================================
+// Integers and floats with caller supplied group and decimal separators
+// (any UTF-8, e.g. U+202F NARROW NO-BREAK SPACE) and grouping pattern: 3-3-3
+// for `1,234,567`, or 3-2-2 for Indian style `12,34,567`. Floats are always
+// printed positionally, never with an exponent. Functions return the length
+// the output requires and write it only if it is <= buf_len (snprintf-style).
+
+/**
+ * Separators and grouping pattern for the grouped formatters
+ *
+ * A null pointer behaves like `{ ",", ".", 3, 3 }`, e.g. `1,234,567.5`. A
+ * null separator inside the struct is empty, as in NumFormatterConfig.
+ */
+typedef struct NumGrouping {
+    /** Separator between digit groups (UTF-8, null is empty) */
+    const uint8_t *group_sep;
+    /** Length of group_sep in bytes */
+    size_t group_sep_len;
+    /** Separator between the integer and fraction digits (UTF-8, null is empty) */
+    const uint8_t *decimal_sep;
+    /** Length of decimal_sep in bytes */
+    size_t decimal_sep_len;
+    /** Digits in the group next to the decimal point, 0 disables grouping */
+    uint8_t primary;
+    /** Digits in every further group, 0 repeats primary (2 for Indian style) */
+    uint8_t secondary;
+} NumGrouping;
+
+/**
+ * Format i64 integer with digit grouping
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_format_grouped_i64(int64_t value, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 integer with digit grouping
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_format_grouped_u64(uint64_t value, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128 integer, passed as `(hi << 64) | lo`, with digit grouping
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_format_grouped_i128(int64_t hi, uint64_t lo, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128 integer, passed as `(hi << 64) | lo`, with digit grouping
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len
+ */
+size_t num_format_grouped_u128(uint64_t hi, uint64_t lo, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 positionally with digit grouping
+ *
+ * precision < 0 prints the shortest round-trip digits with at least one
+ * fraction digit (`1,234.0`), otherwise exactly `precision` fraction digits
+ * rounded half-to-even.
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t num_format_grouped_f64(double value, int32_t precision, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 positionally with digit grouping
+ *
+ * precision < 0 prints the shortest round-trip digits with at least one
+ * fraction digit (`1,234.0`), otherwise exactly `precision` fraction digits
+ * rounded half-to-even.
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - grouping must be null or point to a valid NumGrouping
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t num_format_grouped_f32(float value, int32_t precision, const NumGrouping *grouping, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// JSON C FFI Wrappers - RFC 8259 number output
+// ============================================================================
+// zmij's shortest output is already valid JSON for finite values (`1.0`,
+// `1e+16`, `-0.0`); NaN and infinities are not. These functions either
+// substitute a configured token such as `null` for them or fail, and can
+// print integral values up to 2^53 without the `.0` for schemas that expect
+// integers. Functions return the length the output requires and write it only
+// if it is <= buf_len (snprintf-style); 0 means the value was non-finite and
+// no substitute was configured.
+
+/**
+ * Options for the JSON formatters
+ *
+ * A null pointer behaves like a zeroed struct: integral values keep `.0`
+ * and non-finite values are an error.
+ */
+typedef struct ZmijJsonOptions {
+    /** Print integral values with magnitude <= 2^53 without `.0` */
+    bool integers_without_fraction;
+    /** Token written for NaN and infinities (UTF-8, null to fail instead) */
+    const uint8_t *non_finite;
+    /** Length of non_finite in bytes */
+    size_t non_finite_len;
+} ZmijJsonOptions;
+
+/**
+ * Format f64 as an RFC 8259 JSON number
+ *
+ * # Safety
+ * - options must be null or point to a valid ZmijJsonOptions whose
+ *   non_finite string is null or valid for non_finite_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if value is NaN or infinite and options has no non_finite token
+ */
+size_t zmij_format_json_f64(double value, const ZmijJsonOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 as an RFC 8259 JSON number
+ *
+ * # Safety
+ * - options must be null or point to a valid ZmijJsonOptions whose
+ *   non_finite string is null or valid for non_finite_len bytes
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if value is NaN or infinite and options has no non_finite token
+ */
+size_t zmij_format_json_f32(float value, const ZmijJsonOptions *options, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Padded itoa C FFI Wrappers - Fixed-width integer columns
+// ============================================================================
+// Integers padded to a minimum width with any fill character, aligned left,
+// right or centered, or zero padded after the sign (`-0042`), with an optional
+// forced `+`. Functions return the length the output requires and write it
+// only if it is <= buf_len (snprintf-style); 0 means the options were invalid.
+
+/** Fill before the number (the default for numbers) */
+#define ITOA_ALIGN_RIGHT 0
+
+/** Fill after the number */
+#define ITOA_ALIGN_LEFT 1
+
+/** Fill on both sides, the extra character going to the right */
+#define ITOA_ALIGN_CENTER 2
+
+/** Pad with `0` between the sign and the digits (`-0042`), ignoring fill */
+#define ITOA_ALIGN_ZERO_PAD 3
+
+/** Largest width the padded formatters accept */
+#define ITOA_MAX_WIDTH 4096
+
+/**
+ * Width, fill and sign options for the padded formatters
+ *
+ * A null pointer or a zeroed struct means no padding and no forced sign.
+ */
+typedef struct ItoaPadOptions {
+    /** Minimum width in characters, counting the sign (at most ITOA_MAX_WIDTH) */
+    uint32_t width;
+    /** Fill character as a Unicode scalar value, 0 for a space */
+    uint32_t fill;
+    /** One of the ITOA_ALIGN_* constants */
+    uint8_t align;
+    /** Print `+` before non-negative values */
+    bool plus_sign;
+} ItoaPadOptions;
+
+/**
+ * Format i64 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_i64(int64_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_u64(uint64_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i32 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_i32(int32_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u32 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_u32(uint32_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i16 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_i16(int16_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u16 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_u16(uint16_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i8 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_i8(int8_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u8 integer padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_u8(uint8_t value, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128 integer, passed as `(hi << 64) | lo`, padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_i128(int64_t hi, uint64_t lo, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128 integer, passed as `(hi << 64) | lo`, padded to a minimum width
+ *
+ * # Safety
+ * - options must be null or point to a valid ItoaPadOptions
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+ * Unicode scalar value
+ */
+size_t rust_itoa_pad_u128(uint64_t hi, uint64_t lo, const ItoaPadOptions *options, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Parsing C FFI Wrappers - The inverse of zmij_format_* and rust_itoa_*
+// ============================================================================
+// Parses the longest prefix of the input that forms a number, like strtod but
+// without skipping whitespace. Floats are rounded correctly (Eisel-Lemire with
+// a big-decimal fallback, as in Rust's `str::parse`) and everything
+// zmij_format_* emits is accepted, including `NaN`, `inf` and `-inf`.
+// Functions return a NUM_PARSE_* code and store the value and bytes consumed
+// through the optional out pointers.
+
+/** The input was parsed into a finite value or an explicit NaN/infinity */
+#define NUM_PARSE_OK 0
+
+/** The input was empty */
+#define NUM_PARSE_EMPTY 1
+
+/** The input does not start with a number; nothing was consumed */
+#define NUM_PARSE_INVALID 2
+
+/**
+ * The number is too large for the type; the value is saturated (±inf for floats,
+ * the integer maximum)
+ */
+#define NUM_PARSE_OVERFLOW 3
+
+/** The number is non-zero but too small for the type; the value is ±0 */
+#define NUM_PARSE_UNDERFLOW 4
+
+/** The integer is below the type's minimum; the value is saturated */
+#define NUM_PARSE_NEG_OVERFLOW 5
+
+/** The number has more fraction digits than the scale holds; the value is truncated */
+#define NUM_PARSE_EXCESS_PRECISION 6
+
+/**
+ * Parse the number at the start of buf as f64, correctly rounded
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * ±inf on OVERFLOW, ±0 on UNDERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_parse_f64(const uint8_t *buf, size_t buf_len, double *out_value, size_t *out_consumed);
+
+/**
+ * Parse the number at the start of buf as f32, correctly rounded
+ *
+ * Rounds the decimal input directly to f32, not via f64.
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * ±inf on OVERFLOW, ±0 on UNDERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_parse_f32(const uint8_t *buf, size_t buf_len, float *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as i64
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * i64::MIN/i64::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_i64(const uint8_t *buf, size_t buf_len, int64_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as u64
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * 0/u64::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_u64(const uint8_t *buf, size_t buf_len, uint64_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as i32
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * i32::MIN/i32::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_i32(const uint8_t *buf, size_t buf_len, int32_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as u32
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * 0/u32::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_u32(const uint8_t *buf, size_t buf_len, uint32_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as i16
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * i16::MIN/i16::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_i16(const uint8_t *buf, size_t buf_len, int16_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as u16
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * 0/u16::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_u16(const uint8_t *buf, size_t buf_len, uint16_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as i8
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * i8::MIN/i8::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_i8(const uint8_t *buf, size_t buf_len, int8_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as u8
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+ * 0/u8::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_u8(const uint8_t *buf, size_t buf_len, uint8_t *out_value, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as i128, returned as two halves
+ *
+ * The value is `(out_hi << 64) | out_lo`, see rust_itoa_i128.
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_hi, out_lo and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. The halves receive the value (0 on EMPTY/INVALID,
+ * i128::MIN/i128::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_i128(const uint8_t *buf, size_t buf_len, int64_t *out_hi, uint64_t *out_lo, size_t *out_consumed);
+
+/**
+ * Parse the integer at the start of buf as u128, returned as two halves
+ *
+ * The value is `(out_hi << 64) | out_lo`, see rust_itoa_u128.
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_hi, out_lo and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. The halves receive the value (0 on EMPTY/INVALID,
+ * 0/u128::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+ */
+int32_t rust_atoi_u128(const uint8_t *buf, size_t buf_len, uint64_t *out_hi, uint64_t *out_lo, size_t *out_consumed);
+
+// ============================================================================
+// Non-finite policy C FFI Wrappers - Spellings of NaN, infinity and -0.0
+// ============================================================================
+// zmij_format_f64 always prints `NaN`, `inf`, `-inf` and keeps `-0.0`. These
+// variants take a ZmijFloatPolicy choosing a preset (C, JavaScript, YAML,
+// JSON), optional custom strings, and whether negative zero prints as `0.0`.
+// Finite values are formatted exactly like zmij_format_f64, and the `_fit`
+// variants go through the same code with the zmij preset. NumFormatter
+// profiles resolve their spellings through a policy as well. Functions return
+// the length the output requires and write it only if it is <= buf_len
+// (snprintf-style); 0 means the preset was unknown.
+
+/** `NaN`, `inf`, `-inf` (zmij_format_f64) */
+#define ZMIJ_NON_FINITE_ZMIJ 0
+
+/** `nan`, `inf`, `-inf` (C printf) */
+#define ZMIJ_NON_FINITE_C 1
+
+/** `NaN`, `Infinity`, `-Infinity` (JavaScript) */
+#define ZMIJ_NON_FINITE_JS 2
+
+/** `.nan`, `.inf`, `-.inf` (YAML) */
+#define ZMIJ_NON_FINITE_YAML 3
+
+/** `null` for every non-finite value (JSON) */
+#define ZMIJ_NON_FINITE_JSON 4
+
+/**
+ * How non-finite values and negative zero are printed
+ *
+ * Non-null strings override the preset's spelling for that value. A null
+ * pointer behaves like a zeroed struct: zmij spellings, `-0.0` kept.
+ */
+typedef struct ZmijFloatPolicy {
+    /** One of the ZMIJ_NON_FINITE_* presets */
+    uint8_t preset;
+    /** Print negative zero as `0.0` */
+    bool normalize_negative_zero;
+    /** Spelling of NaN (UTF-8, null for the preset's) */
+    const uint8_t *nan;
+    /** Length of nan in bytes */
+    size_t nan_len;
+    /** Spelling of positive infinity (UTF-8, null for the preset's) */
+    const uint8_t *inf;
+    /** Length of inf in bytes */
+    size_t inf_len;
+    /** Spelling of negative infinity (UTF-8, null for the preset's) */
+    const uint8_t *neg_inf;
+    /** Length of neg_inf in bytes */
+    size_t neg_inf_len;
+} ZmijFloatPolicy;
+
+/**
+ * Format f64 with the non-finite spellings of a policy (null = zmij's)
+ *
+ * # Safety
+ * - policy must be null or point to a valid ZmijFloatPolicy whose strings
+ *   are null or valid for their lengths
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the preset is unknown
+ */
+size_t zmij_format_policy_f64(double value, const ZmijFloatPolicy *policy, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with the non-finite spellings of a policy (null = zmij's)
+ *
+ * # Safety
+ * - policy must be null or point to a valid ZmijFloatPolicy whose strings
+ *   are null or valid for their lengths
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the preset is unknown
+ */
+size_t zmij_format_policy_f32(float value, const ZmijFloatPolicy *policy, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Radix itoa C FFI Wrappers - Integers in any base from 2 to 36
+// ============================================================================
+// Digits above 9 are letters (`a`-`z`, or `A`-`Z` with ITOA_RADIX_UPPERCASE).
+// Negative values print as `-` and the magnitude, or with
+// ITOA_RADIX_TWOS_COMPLEMENT as the unsigned bit pattern of their own width.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style); 0 means the radix or flags were invalid.
+
+/** Use upper-case letters for digits above 9 */
+#define ITOA_RADIX_UPPERCASE 1
+
+/** Prefix base 16, 8 and 2 with `0x`, `0o` and `0b` (other bases have none) */
+#define ITOA_RADIX_PREFIX 2
+
+/** Print negative values as the two's-complement bit pattern of their width */
+#define ITOA_RADIX_TWOS_COMPLEMENT 4
+
+/** Buffer size that fits any integer in any radix: sign, prefix, 128 digits */
+#define ITOA_RADIX_BUFFER_SIZE 131
+
+/**
+ * Format i64 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_i64(int64_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_u64(uint64_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i32 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_i32(int32_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u32 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_u32(uint32_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i16 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_i16(int16_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u16 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_u16(uint16_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i8 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_i8(int8_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u8 integer in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_u8(uint8_t value, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128 integer, passed as `(hi << 64) | lo`, in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_i128(int64_t hi, uint64_t lo, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128 integer, passed as `(hi << 64) | lo`, in the given radix
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if radix is outside 2..=36 or flags has unknown bits
+ */
+size_t rust_itoa_radix_u128(uint64_t hi, uint64_t lo, uint32_t radix, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Scaled integer C FFI Wrappers - Fixed-point decimals such as `1,234.56`
+// ============================================================================
+// Amounts stored as i64 minor units (cents, satoshis) print with the decimal
+// point `scale` digits from the right: 123456 at scale 2 is `1234.56`. The
+// digits come straight from itoa, so there is no float rounding anywhere.
+// Trailing fraction zeros are dropped down to a minimum count, and the
+// integer digits can be grouped with a NumGrouping. The formatter returns the
+// length the output requires and writes it only if it is <= buf_len
+// (snprintf-style); 0 means the arguments were invalid. The parser is its
+// inverse and reports a NUM_PARSE_* code like rust_atoi_i64.
+
+/** Largest scale the scaled formatters accept (10^18 fits in i64) */
+#define NUM_SCALED_MAX_SCALE 18
+
+/** Sign only for negative values: `-1.50`, `1.50` */
+#define NUM_SIGN_MINUS 0
+
+/** Sign for every value: `-1.50`, `+1.50` */
+#define NUM_SIGN_PLUS 1
+
+/** Space in place of a plus sign, so columns line up: `-1.50`, ` 1.50` */
+#define NUM_SIGN_SPACE 2
+
+/**
+ * Options for the scaled integer formatter and parser
+ *
+ * A null pointer behaves like a zeroed struct: `.` as the decimal point, no
+ * grouping, sign only when negative and every trailing zero dropped.
+ */
+typedef struct NumScaledOptions {
+    /** Fraction digits always printed, zero-padded (may exceed the scale) */
+    uint8_t min_fraction_digits;
+    /** NUM_SIGN_MINUS, NUM_SIGN_PLUS or NUM_SIGN_SPACE */
+    uint8_t sign;
+    /** Separators and grouping, or null for an ungrouped `1234.56` */
+    const NumGrouping *grouping;
+} NumScaledOptions;
+
+/**
+ * Format an i64 holding `value * 10^scale` as a decimal such as `1,234.56`
+ *
+ * # Safety
+ * - options must be null or point to a valid NumScaledOptions whose grouping
+ *   is null or a valid NumGrouping
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if scale exceeds NUM_SCALED_MAX_SCALE or the sign style is unknown
+ */
+size_t num_format_scaled_i64(int64_t value, uint8_t scale, const NumScaledOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Parse the decimal at the start of buf as an i64 holding `value * 10^scale`
+ *
+ * Uses the decimal and group separators of options.grouping; group
+ * separators are skipped between integer digits.
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - options must be null or point to a valid NumScaledOptions whose grouping
+ *   is null or a valid NumGrouping
+ * - out_value and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code (NUM_PARSE_INVALID if scale exceeds NUM_SCALED_MAX_SCALE).
+ * out_value receives the value (0 on EMPTY/INVALID, i64::MIN/i64::MAX on
+ * NEG_OVERFLOW/OVERFLOW, truncated on EXCESS_PRECISION) and out_consumed the
+ * bytes parsed
+ */
+int32_t num_parse_scaled_i64(const uint8_t *buf, size_t buf_len, uint8_t scale, const NumScaledOptions *options, int64_t *out_value, size_t *out_consumed);
+
+// ============================================================================
+// Scientific-notation C FFI Wrappers - Always `d.ddde±x` output
+// ============================================================================
+// zmij picks its own switch point between plain decimal and exponent output;
+// these functions always emit exponent notation with a caller controlled
+// exponent style, so exported columns line up. Functions return the number of
+// bytes written, or 0 if the buffer was too small.
+
+/**
+ * Options for the scientific-notation formatters
+ *
+ * A null options pointer behaves like `{ precision: -1, uppercase: false,
+ * exp_plus_sign: false, min_exp_digits: 1 }`, e.g. `1.2345e-7`.
+ */
+typedef struct ZmijSciOptions {
+    /** Digits after the mantissa's decimal point, or < 0 for shortest round-trip */
+    int32_t precision;
+    /** Use `E` instead of `e` as the exponent marker */
+    bool uppercase;
+    /** Always print the exponent sign (`e+7` instead of `e7`) */
+    bool exp_plus_sign;
+    /** Zero-pad the exponent to at least this many digits (`e-07` for 2) */
+    uint8_t min_exp_digits;
+} ZmijSciOptions;
+
+/**
+ * Format f64 in scientific notation
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - options must be null or point to a valid ZmijSciOptions
+ * - buf_len should be >= zmij_sci_buffer_size(options) for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small or
+ * precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_sci_f64(double value, const ZmijSciOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 in scientific notation
+ *
+ * # Safety
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - options must be null or point to a valid ZmijSciOptions
+ * - buf_len should be >= zmij_sci_buffer_size(options) for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if buffer was too small or
+ * precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_sci_f32(float value, const ZmijSciOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 in scientific notation, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - options must be null or point to a valid ZmijSciOptions
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_sci_f64_fit(double value, const ZmijSciOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 in scientific notation, returning the required length
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ * - options must be null or point to a valid ZmijSciOptions
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+ */
+size_t zmij_format_sci_f32_fit(float value, const ZmijSciOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Buffer size that fits any f64 or f32 formatted with the given options
+ *
+ * # Safety
+ * - options must be null or point to a valid ZmijSciOptions
+ *
+ * # Returns
+ * Sign + mantissa + exponent marker, sign and digits
+ */
+size_t zmij_sci_buffer_size(const ZmijSciOptions *options);
+
+// ============================================================================
+// Significant-digits C FFI Wrappers - printf `%g` style output
+// ============================================================================
+// Rounds to N significant digits and picks fixed or exponent notation by
+// magnitude like C's `%.Ng`: `3.142`, `0.0001235`, `1.235e+20`. Trailing zeros
+// and a bare decimal point are stripped unless ZMIJ_SIG_KEEP_TRAILING_ZEROS
+// (`%#g`) is set. NaN and infinities use the zmij spellings. Functions return
+// the length the output requires and write it only if it is <= buf_len.
+
+/** Keep trailing zeros and the decimal point, like `%#g` */
+#define ZMIJ_SIG_KEEP_TRAILING_ZEROS 1
+
+/** Use `E` as the exponent marker, like `%G` */
+#define ZMIJ_SIG_UPPERCASE 2
+
+/**
+ * Format f64 with `digits` significant digits, like printf `%.{digits}g`
+ *
+ * digits 0 is treated as 1, as in C.
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if digits exceeds ZMIJ_MAX_FIXED_PRECISION or flags has unknown bits
+ */
+size_t zmij_format_sig_f64(double value, uint32_t digits, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with `digits` significant digits, like printf `%.{digits}g`
+ *
+ * digits 0 is treated as 1, as in C.
+ *
+ * # Safety
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if digits exceeds ZMIJ_MAX_FIXED_PRECISION or flags has unknown bits
+ */
+size_t zmij_format_sig_f32(float value, uint32_t digits, uint32_t flags, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Format-spec C FFI Wrappers - Python-style `[[fill]align][sign][#][0][width][grouping][.precision][type]`
+// ============================================================================
+// One entry point per value type instead of one per option: `>+12,.3e`,
+// `08x`, `_b`, `.2%`. Specs can be compiled once into an opaque NumSpec
+// handle for hot loops. Types: `d x X o b` for integers, `e E f F g G %` for
+// both, and none for shortest round-trip floats / decimal integers. Grouping
+// (`,` or `_`) is rejected with `g` and `G`. Functions return the length the
+// output requires and write it only if it is <= buf_len (snprintf-style); 0
+// means the spec was invalid or does not apply to the value type.
+
+/**
+ * A compiled format spec
+ *
+ * Create with num_spec_new and release with num_spec_free.
+ */
+typedef struct NumSpec NumSpec;
+
+/**
+ * Compile a format spec such as `>+12,.3e` for the num_spec_format_* functions
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ *
+ * # Returns
+ * A handle to release with num_spec_free, or null if the spec is malformed
+ */
+NumSpec *num_spec_new(const uint8_t *spec, size_t spec_len);
+
+/**
+ * Release a spec returned by num_spec_new (null is ignored)
+ *
+ * # Safety
+ * - spec must be null or a pointer returned by num_spec_new, not yet freed
+ */
+void num_spec_free(NumSpec *spec);
+
+/**
+ * Format i64 with a compiled spec (null spec = empty spec)
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to integers (e.g. a precision with `d`)
+ */
+size_t num_spec_format_i64(const NumSpec *spec, int64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 with a compiled spec (null spec = empty spec)
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to integers (e.g. a precision with `d`)
+ */
+size_t num_spec_format_u64(const NumSpec *spec, uint64_t value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128, passed as `(hi << 64) | lo`, with a compiled spec
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to integers (e.g. a precision with `d`)
+ */
+size_t num_spec_format_i128(const NumSpec *spec, int64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128, passed as `(hi << 64) | lo`, with a compiled spec
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to integers (e.g. a precision with `d`)
+ */
+size_t num_spec_format_u128(const NumSpec *spec, uint64_t hi, uint64_t lo, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 with a compiled spec (null spec = empty spec)
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to floats (e.g. `x`)
+ */
+size_t num_spec_format_f64(const NumSpec *spec, double value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with a compiled spec (null spec = empty spec)
+ *
+ * # Safety
+ * - spec must be null or a live pointer returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec does not apply to floats (e.g. `x`)
+ */
+size_t num_spec_format_f32(const NumSpec *spec, float value, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i64 with a spec string, parsed on every call
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to integers
+ */
+size_t num_format_spec_i64(int64_t value, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u64 with a spec string, parsed on every call
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to integers
+ */
+size_t num_format_spec_u64(uint64_t value, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format i128, passed as `(hi << 64) | lo`, with a spec string
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to integers
+ */
+size_t num_format_spec_i128(int64_t hi, uint64_t lo, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format u128, passed as `(hi << 64) | lo`, with a spec string
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to integers
+ */
+size_t num_format_spec_u128(uint64_t hi, uint64_t lo, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f64 with a spec string, parsed on every call
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to floats
+ */
+size_t num_format_spec_f64(double value, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format f32 with a spec string, parsed on every call
+ *
+ * # Safety
+ * - spec must be null or valid for reads of spec_len bytes (UTF-8)
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if the spec is malformed or does not apply to floats
+ */
+size_t num_format_spec_f32(float value, const uint8_t *spec, size_t spec_len, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Table C FFI Wrappers - Aligned text tables from numeric matrices
+// ============================================================================
+// Format a row-major matrix in one call: every cell is formatted with its
+// column's compiled NumSpec (null for shortest round-trip / plain decimal),
+// right-aligned with spaces to the column width, and columns are separated by
+// a single space. Each row ends with `\n`. Cells wider than their column are
+// written in full. Functions return the length the output requires and write
+// it only if it is <= buf_len (snprintf-style); 0 means a column spec does
+// not apply to the value type or the shape was invalid.
+
+/** Format and width of one table column */
+typedef struct NumTableColumn {
+    /** Compiled spec from num_spec_new, or null for the empty spec */
+    const NumSpec *spec;
+    /**
+     * Minimum width in characters (at most ITOA_MAX_WIDTH); cells are
+     * right-aligned with spaces
+     */
+    uint32_t width;
+} NumTableColumn;
+
+/**
+ * Format a row-major f64 matrix as right-aligned text rows
+ *
+ * # Safety
+ * - values must be valid for reads of rows * cols elements
+ * - columns must be valid for reads of cols NumTableColumn values whose
+ *   specs are null or live pointers returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if cols is 0, a pointer is null, a column is wider than ITOA_MAX_WIDTH or
+ * a spec does not apply to floats
+ */
+size_t num_format_table_f64(const double *values, size_t rows, size_t cols, const NumTableColumn *columns, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format a row-major i64 matrix as right-aligned text rows
+ *
+ * # Safety
+ * - values must be valid for reads of rows * cols elements
+ * - columns must be valid for reads of cols NumTableColumn values whose
+ *   specs are null or live pointers returned by num_spec_new
+ * - buf must be null or a valid mutable pointer to at least buf_len bytes
+ *
+ * # Returns
+ * Length of the formatted output; written only if it is <= buf_len.
+ * 0 if cols is 0, a pointer is null, a column is wider than ITOA_MAX_WIDTH or
+ * a spec does not apply to integers
+ */
+size_t num_format_table_i64(const int64_t *values, size_t rows, size_t cols, const NumTableColumn *columns, uint8_t *buf, size_t buf_len);
+
+// ============================================================================
+// Timestamp C FFI Wrappers - RFC 3339 `2026-10-16T12:34:56.123456789Z`
+// ============================================================================
+// Convert Unix time (seconds + nanoseconds, or i64 nanoseconds) to RFC 3339 /
+// ISO 8601 text and back, using the proleptic Gregorian calendar without
+// leap seconds. Years are limited to the four digits RFC 3339 allows
+// (0000-9999). Like rust_itoa_*, the formatters return the number of bytes
+// written, or 0 if the buffer was too small or the arguments were invalid;
+// NUM_TIMESTAMP_BUFFER_SIZE fits every output. The parsers follow the
+// rust_atoi_* contract: they read a timestamp at the start of the input and
+// return a NUM_PARSE_* code.
+
+/** Buffer size that fits any timestamp (`9999-12-31T23:59:59.999999999+23:59`) */
+#define NUM_TIMESTAMP_BUFFER_SIZE 35
+
+/**
+ * Options for the timestamp formatters
+ *
+ * A null pointer behaves like a zeroed struct: whole seconds in UTC with a
+ * `Z` suffix.
+ */
+typedef struct NumTimestampOptions {
+    /** Fraction digits (0..=9, truncated), or -1 for as many as needed */
+    int8_t fraction_digits;
+    /** Offset from UTC in minutes (-1439..=1439); the time is shown in that zone */
+    int16_t offset_minutes;
+    /** Write `+00:00` instead of `Z` for a zero offset */
+    bool numeric_utc;
+} NumTimestampOptions;
+
+/**
+ * Format Unix seconds and nanoseconds as an RFC 3339 timestamp
+ *
+ * # Safety
+ * - options must be null or point to a valid NumTimestampOptions
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= NUM_TIMESTAMP_BUFFER_SIZE for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if the buffer was too small,
+ * nanos is not below 1e9, the options are out of range or the local year is
+ * outside 0000-9999
+ */
+size_t num_format_timestamp(int64_t seconds, uint32_t nanos, const NumTimestampOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Format Unix time in nanoseconds as an RFC 3339 timestamp
+ *
+ * # Safety
+ * - options must be null or point to a valid NumTimestampOptions
+ * - buf must be a valid mutable pointer to at least buf_len bytes
+ * - buf_len should be >= NUM_TIMESTAMP_BUFFER_SIZE for guaranteed success
+ *
+ * # Returns
+ * Number of bytes written to buffer, or 0 if the buffer was too small or the
+ * options are out of range
+ */
+size_t num_format_timestamp_ns(int64_t nanoseconds, const NumTimestampOptions *options, uint8_t *buf, size_t buf_len);
+
+/**
+ * Parse the RFC 3339 timestamp at the start of buf as Unix seconds and nanoseconds
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_seconds, out_nanos, out_offset_minutes and out_consumed must each be
+ *   null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_OK, NUM_PARSE_EMPTY or NUM_PARSE_INVALID. On success the out
+ * pointers receive the UTC Unix time, the offset the text was written in and
+ * the bytes parsed; on failure they receive 0
+ */
+int32_t num_parse_timestamp(const uint8_t *buf, size_t buf_len, int64_t *out_seconds, uint32_t *out_nanos, int16_t *out_offset_minutes, size_t *out_consumed);
+
+/**
+ * Parse the RFC 3339 timestamp at the start of buf as Unix nanoseconds
+ *
+ * # Safety
+ * - buf must be null or a valid pointer to at least buf_len bytes
+ * - out_nanoseconds and out_consumed must each be null or valid for writes
+ *
+ * # Returns
+ * NUM_PARSE_* code. out_nanoseconds receives the value (0 on EMPTY/INVALID,
+ * i64::MIN/i64::MAX on NEG_OVERFLOW/OVERFLOW outside 1677-2262) and
+ * out_consumed the bytes parsed
+ */
+int32_t num_parse_timestamp_ns(const uint8_t *buf, size_t buf_len, int64_t *out_nanoseconds, size_t *out_consumed);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NUM_FORMAT_FFI_H */
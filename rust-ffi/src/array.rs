@@ -0,0 +1,684 @@
+// ============================================================================
+// Batch C FFI Wrappers - Delimited runs of numbers in one call
+// ============================================================================
+// Format `count` values separated by a delimiter into one buffer, saving an
+// FFI crossing per element for CSV and JSON exporters. Output stops cleanly
+// after the last whole element that fits; the number of elements written is
+// stored in `written_count` so the caller can flush the buffer and resume
+// from `values + written_count` (writing the delimiter between the runs).
+// Functions return the number of bytes written, never a partial element.
+//
+// 128-bit variants take `2 * count` u64 words holding the values in native
+// memory layout, which is how an Odin `[]i128` or C `__int128[]` is stored.
+
+use std::slice;
+
+use crate::grouping::c_bytes;
+use crate::out::Out;
+use crate::policy::{FloatPolicy, write_float};
+
+/// Write `count` delimited elements while they fit
+///
+/// # Safety
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+unsafe fn write_array(
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+    mut write_item: impl FnMut(&mut Out, usize),
+) -> usize {
+    // Safety: guaranteed by the caller
+    let delimiter = unsafe { c_bytes(delimiter, delimiter_len) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+
+    let mut written = 0;
+    while written < count {
+        let mark = out.len();
+        if written > 0 {
+            out.push_bytes(delimiter);
+        }
+        write_item(&mut out, written);
+        if !out.fits() {
+            out.truncate(mark);
+            break;
+        }
+        written += 1;
+    }
+
+    // Safety: written_count is null or valid for writes
+    if let Some(written_count) = unsafe { written_count.as_mut() } {
+        *written_count = written;
+    }
+    out.len()
+}
+
+/// Borrow `count` values from a pointer that may be null when count is 0
+///
+/// # Safety
+/// - values must be valid for reads of count elements when count > 0
+unsafe fn c_slice<'a, T>(values: *const T, count: usize) -> &'a [T] {
+    if count == 0 {
+        &[]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(values, count) }
+    }
+}
+
+/// Read the `index`th 128-bit value from native-layout u64 words
+///
+/// # Safety
+/// - words must be valid for reads of 2 * (index + 1) u64 values
+unsafe fn read_u128(words: *const u64, index: usize) -> u128 {
+    // Safety: guaranteed by the caller; u64 alignment may be below u128's
+    unsafe { words.cast::<u128>().add(index).read_unaligned() }
+}
+
+unsafe fn itoa_array<I: itoa::Integer + Copy>(
+    values: &[I],
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: guaranteed by the caller
+    unsafe {
+        write_array(
+            values.len(),
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+            |out, i| out.push_bytes(buffer.format(values[i]).as_bytes()),
+        )
+    }
+}
+
+/// Format an array of f64 values separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_f64_array(
+    values: *const f64,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        write_array(
+            count,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+            |out, i| write_float(out, values[i], &FloatPolicy::ZMIJ),
+        )
+    }
+}
+
+/// Format an array of f32 values separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_f32_array(
+    values: *const f32,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        write_array(
+            count,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+            |out, i| write_float(out, values[i], &FloatPolicy::ZMIJ),
+        )
+    }
+}
+
+/// Format an array of i64 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i64_array(
+    values: *const i64,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of u64 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u64_array(
+    values: *const u64,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of i32 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i32_array(
+    values: *const i32,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of u32 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u32_array(
+    values: *const u32,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of i16 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i16_array(
+    values: *const i16,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of u16 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u16_array(
+    values: *const u16,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of i8 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i8_array(
+    values: *const i8,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of u8 integers separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of count elements (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u8_array(
+    values: *const u8,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let values = unsafe { c_slice(values, count) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        itoa_array(
+            values,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+        )
+    }
+}
+
+/// Format an array of i128 integers, stored as native-layout u64 pairs, separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of 2 * count u64 words (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i128_array(
+    values: *const u64,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: guaranteed by the caller
+    unsafe {
+        write_array(
+            count,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+            |out, i| out.push_bytes(buffer.format(read_u128(values, i) as i128).as_bytes()),
+        )
+    }
+}
+
+/// Format an array of u128 integers, stored as native-layout u64 pairs, separated by a delimiter
+///
+/// # Safety
+/// - values must be valid for reads of 2 * count u64 words (may be null if count is 0)
+/// - delimiter must be null or valid for reads of delimiter_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - written_count must be null or valid for writes
+///
+/// # Returns
+/// Bytes written; the number of whole elements written is stored in written_count
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u128_array(
+    values: *const u64,
+    count: usize,
+    delimiter: *const u8,
+    delimiter_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written_count: *mut usize,
+) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: guaranteed by the caller
+    unsafe {
+        write_array(
+            count,
+            delimiter,
+            delimiter_len,
+            buf,
+            buf_len,
+            written_count,
+            |out, i| out.push_bytes(buffer.format(read_u128(values, i)).as_bytes()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMA: &str = ",";
+
+    /// Format with a `len` byte buffer, returning the text and element count
+    fn array_f64_test(values: &[f64], delimiter: &str, len: usize) -> (String, usize) {
+        let mut buf = vec![0u8; len];
+        let mut written = usize::MAX;
+        let bytes = unsafe {
+            zmij_format_f64_array(
+                values.as_ptr(),
+                values.len(),
+                delimiter.as_ptr(),
+                delimiter.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written,
+            )
+        };
+        (String::from_utf8(buf[..bytes].to_vec()).unwrap(), written)
+    }
+
+    #[test]
+    fn test_array_f64() {
+        let values = [1.5, -0.0, f64::NAN, 1e300];
+        assert_eq!(
+            array_f64_test(&values, ", ", 64),
+            ("1.5, -0.0, NaN, 1e+300".to_string(), 4)
+        );
+        assert_eq!(
+            array_f64_test(&values, "", 64),
+            ("1.5-0.0NaN1e+300".to_string(), 4)
+        );
+    }
+
+    #[test]
+    fn test_array_stops_at_whole_element() {
+        let values = [1.5, 22.25, 333.125];
+        // "1.5,22.25" is 9 bytes; the third element needs 8 more
+        for len in 9..17 {
+            assert_eq!(
+                array_f64_test(&values, COMMA, len),
+                ("1.5,22.25".to_string(), 2)
+            );
+        }
+        assert_eq!(array_f64_test(&values, COMMA, 17).1, 3);
+        assert_eq!(array_f64_test(&values, COMMA, 2), (String::new(), 0));
+    }
+
+    #[test]
+    fn test_array_resume() {
+        let values: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+        let mut joined = String::new();
+        let mut start = 0;
+        while start < values.len() {
+            let (text, written) = array_f64_test(&values[start..], COMMA, 40);
+            assert!(written > 0, "Should make progress");
+            if start > 0 {
+                joined.push_str(COMMA);
+            }
+            joined.push_str(&text);
+            start += written;
+        }
+        let expected: Vec<String> = values
+            .iter()
+            .map(|v| zmij::Buffer::new().format(*v).to_string())
+            .collect();
+        assert_eq!(joined, expected.join(COMMA));
+    }
+
+    #[test]
+    fn test_array_empty() {
+        let mut written = usize::MAX;
+        let mut buf = [0u8; 8];
+        let bytes = unsafe {
+            zmij_format_f32_array(
+                std::ptr::null(),
+                0,
+                COMMA.as_ptr(),
+                1,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written,
+            )
+        };
+        assert_eq!((bytes, written), (0, 0));
+    }
+
+    #[test]
+    fn test_array_integers() {
+        let mut buf = [0u8; 64];
+        let mut written = 0;
+        let values = [i8::MIN, 0, i8::MAX];
+        let bytes = unsafe {
+            rust_itoa_i8_array(
+                values.as_ptr(),
+                values.len(),
+                COMMA.as_ptr(),
+                1,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written,
+            )
+        };
+        assert_eq!((&buf[..bytes], written), (&b"-128,0,127"[..], 3));
+
+        let values = [u64::MAX, 7];
+        let bytes = unsafe {
+            rust_itoa_u64_array(
+                values.as_ptr(),
+                values.len(),
+                std::ptr::null(),
+                0,
+                buf.as_mut_ptr(),
+                buf.len(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(&buf[..bytes], b"184467440737095516157");
+    }
+
+    #[test]
+    fn test_array_i128() {
+        let values = [i128::MIN, -1, i128::MAX];
+        let mut buf = [0u8; 128];
+        let mut written = 0;
+        let bytes = unsafe {
+            rust_itoa_i128_array(
+                values.as_ptr().cast(),
+                values.len(),
+                COMMA.as_ptr(),
+                1,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written,
+            )
+        };
+        let expected = format!("{},-1,{}", i128::MIN, i128::MAX);
+        assert_eq!((&buf[..bytes], written), (expected.as_bytes(), 3));
+
+        let values = [u128::MAX];
+        let bytes = unsafe {
+            rust_itoa_u128_array(
+                values.as_ptr().cast(),
+                values.len(),
+                COMMA.as_ptr(),
+                1,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written,
+            )
+        };
+        assert_eq!(&buf[..bytes], u128::MAX.to_string().as_bytes());
+    }
+}
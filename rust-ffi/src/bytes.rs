@@ -0,0 +1,271 @@
+// ============================================================================
+// Byte-size C FFI Wrappers - `1.5 GiB`, `12.3 MB`, `512 bytes`
+// ============================================================================
+// Scale a u64 byte count to the largest IEC (1024-based) or SI (1000-based)
+// unit it reaches and print it with a fixed number of fraction digits. The
+// division is exact integer arithmetic rounded half-to-even, and a value that
+// rounds up to the next unit moves there (`1.0 MiB`, never `1024.0 KiB`).
+// Plain byte counts have no fraction. Functions return the length the output
+// requires and write it only if it is <= buf_len (snprintf-style); 0 means
+// the options were invalid.
+
+use crate::out::Out;
+
+/// 1024-based units: KiB, MiB, GiB, ...
+pub const NUM_BYTES_IEC: u8 = 0;
+
+/// 1000-based units: kB, MB, GB, ...
+pub const NUM_BYTES_SI: u8 = 1;
+
+/// Unit symbols: `B`, `KiB`, `kB`
+pub const NUM_BYTES_SHORT: u8 = 0;
+
+/// Unit names: `bytes`, `kibibytes`, `kilobytes`
+pub const NUM_BYTES_LONG: u8 = 1;
+
+/// Largest number of fraction digits num_format_bytes accepts
+pub const NUM_BYTES_MAX_PRECISION: u8 = 18;
+
+/// Options for num_format_bytes
+///
+/// A null pointer behaves like a zeroed struct: IEC symbols, no fraction
+/// digits, a space before the unit.
+#[repr(C)]
+pub struct NumByteSizeOptions {
+    /// NUM_BYTES_IEC or NUM_BYTES_SI
+    pub base: u8,
+    /// NUM_BYTES_SHORT or NUM_BYTES_LONG
+    pub units: u8,
+    /// Fraction digits for scaled values (0..=NUM_BYTES_MAX_PRECISION)
+    pub precision: u8,
+    /// Omit the space between number and unit (`1.5GiB`)
+    pub no_space: bool,
+}
+
+const IEC_SHORT: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const IEC_LONG: [&str; 7] = [
+    "bytes",
+    "kibibytes",
+    "mebibytes",
+    "gibibytes",
+    "tebibytes",
+    "pebibytes",
+    "exbibytes",
+];
+const SI_SHORT: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+const SI_LONG: [&str; 7] = [
+    "bytes",
+    "kilobytes",
+    "megabytes",
+    "gigabytes",
+    "terabytes",
+    "petabytes",
+    "exabytes",
+];
+
+/// `value / divisor` scaled by `10^precision`, rounded half-to-even
+fn scaled_quotient(value: u64, divisor: u64, pow10: u128) -> u128 {
+    let numerator = value as u128 * pow10;
+    let divisor = divisor as u128;
+    let (quotient, remainder) = (numerator / divisor, numerator % divisor);
+    match (2 * remainder).cmp(&divisor) {
+        std::cmp::Ordering::Greater => quotient + 1,
+        std::cmp::Ordering::Equal => quotient + (quotient & 1),
+        std::cmp::Ordering::Less => quotient,
+    }
+}
+
+/// Write `value` bytes scaled to the largest unit it reaches
+fn write_byte_size(
+    out: &mut Out,
+    value: u64,
+    base: u64,
+    units: &[&str; 7],
+    precision: u32,
+    space: bool,
+) {
+    let mut unit = 0;
+    let mut divisor = 1u64;
+    while unit + 1 < units.len() && value / divisor >= base {
+        divisor *= base;
+        unit += 1;
+    }
+
+    let mut buffer = itoa::Buffer::new();
+    if unit == 0 {
+        out.push_bytes(buffer.format(value).as_bytes());
+    } else {
+        let pow10 = 10u128.pow(precision);
+        let mut scaled = scaled_quotient(value, divisor, pow10);
+        // Rounding can reach the next unit: 1023.96 KiB is 1.0 MiB at 1 digit
+        if scaled >= base as u128 * pow10 && unit + 1 < units.len() {
+            divisor *= base;
+            unit += 1;
+            scaled = scaled_quotient(value, divisor, pow10);
+        }
+        out.push_bytes(buffer.format(scaled / pow10).as_bytes());
+        if precision > 0 {
+            out.push(b'.');
+            let fraction = buffer.format(scaled % pow10).as_bytes();
+            for _ in fraction.len()..precision as usize {
+                out.push(b'0');
+            }
+            out.push_bytes(fraction);
+        }
+    }
+
+    if space {
+        out.push(b' ');
+    }
+    // "1 byte", but "1.0 kilobytes" like other fractional amounts
+    let name = units[unit];
+    if value == 1 && name == "bytes" {
+        out.push_bytes(b"byte");
+    } else {
+        out.push_bytes(name.as_bytes());
+    }
+}
+
+/// Format a byte count as a human-readable size such as `1.5 GiB`
+///
+/// # Safety
+/// - options must be null or point to a valid NumByteSizeOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if base or units is unknown or precision exceeds NUM_BYTES_MAX_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_bytes(
+    value: u64,
+    options: *const NumByteSizeOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let (base, units, precision, no_space) = match unsafe { options.as_ref() } {
+        Some(options) => (
+            options.base,
+            options.units,
+            options.precision,
+            options.no_space,
+        ),
+        None => (NUM_BYTES_IEC, NUM_BYTES_SHORT, 0, false),
+    };
+    let (base, units) = match (base, units) {
+        (NUM_BYTES_IEC, NUM_BYTES_SHORT) => (1024, &IEC_SHORT),
+        (NUM_BYTES_IEC, NUM_BYTES_LONG) => (1024, &IEC_LONG),
+        (NUM_BYTES_SI, NUM_BYTES_SHORT) => (1000, &SI_SHORT),
+        (NUM_BYTES_SI, NUM_BYTES_LONG) => (1000, &SI_LONG),
+        _ => return 0,
+    };
+    if precision > NUM_BYTES_MAX_PRECISION {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_byte_size(&mut out, value, base, units, precision as u32, !no_space);
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(base: u8, units: u8, precision: u8) -> NumByteSizeOptions {
+        NumByteSizeOptions {
+            base,
+            units,
+            precision,
+            no_space: false,
+        }
+    }
+
+    fn bytes_test(value: u64, options: &NumByteSizeOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_format_bytes(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_bytes failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_bytes_iec() {
+        let iec = options(NUM_BYTES_IEC, NUM_BYTES_SHORT, 1);
+        assert_eq!(bytes_test(0, &iec), "0 B");
+        assert_eq!(bytes_test(1023, &iec), "1023 B");
+        assert_eq!(bytes_test(1024, &iec), "1.0 KiB");
+        assert_eq!(bytes_test(1536, &iec), "1.5 KiB");
+        assert_eq!(bytes_test(3 << 29, &iec), "1.5 GiB");
+        assert_eq!(bytes_test(u64::MAX, &iec), "16.0 EiB");
+    }
+
+    #[test]
+    fn test_bytes_si() {
+        let si = options(NUM_BYTES_SI, NUM_BYTES_SHORT, 2);
+        assert_eq!(bytes_test(999, &si), "999 B");
+        assert_eq!(bytes_test(1000, &si), "1.00 kB");
+        assert_eq!(bytes_test(12_345_678, &si), "12.35 MB");
+        assert_eq!(bytes_test(u64::MAX, &si), "18.45 EB");
+    }
+
+    #[test]
+    fn test_bytes_rounding_moves_to_next_unit() {
+        let iec = options(NUM_BYTES_IEC, NUM_BYTES_SHORT, 1);
+        assert_eq!(bytes_test(1024 * 1024 - 1, &iec), "1.0 MiB");
+        assert_eq!(bytes_test(1023 * 1024, &iec), "1023.0 KiB");
+        let si = options(NUM_BYTES_SI, NUM_BYTES_SHORT, 0);
+        assert_eq!(bytes_test(999_500, &si), "1 MB");
+        assert_eq!(bytes_test(2_500, &si), "2 kB");
+        assert_eq!(bytes_test(3_500, &si), "4 kB");
+    }
+
+    #[test]
+    fn test_bytes_long_units_and_spacing() {
+        let long = options(NUM_BYTES_SI, NUM_BYTES_LONG, 1);
+        assert_eq!(bytes_test(1, &long), "1 byte");
+        assert_eq!(bytes_test(2, &long), "2 bytes");
+        assert_eq!(bytes_test(1_500_000, &long), "1.5 megabytes");
+        let mut compact = options(NUM_BYTES_IEC, NUM_BYTES_LONG, 0);
+        compact.no_space = true;
+        assert_eq!(bytes_test(5 << 40, &compact), "5tebibytes");
+    }
+
+    #[test]
+    fn test_bytes_null_options() {
+        let mut buf = [0u8; 32];
+        let len =
+            unsafe { num_format_bytes(1 << 20, std::ptr::null(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"1 MiB");
+    }
+
+    #[test]
+    fn test_bytes_max_precision() {
+        let precise = options(NUM_BYTES_IEC, NUM_BYTES_SHORT, NUM_BYTES_MAX_PRECISION);
+        assert_eq!(bytes_test(u64::MAX, &precise), "15.999999999999999999 EiB");
+    }
+
+    #[test]
+    fn test_bytes_invalid_options() {
+        let mut buf = [0u8; 32];
+        for options in [
+            options(2, NUM_BYTES_SHORT, 0),
+            options(NUM_BYTES_SI, 2, 0),
+            options(NUM_BYTES_SI, NUM_BYTES_SHORT, NUM_BYTES_MAX_PRECISION + 1),
+        ] {
+            let len = unsafe { num_format_bytes(1, &options, buf.as_mut_ptr(), buf.len()) };
+            assert_eq!(len, 0, "Should return 0 for invalid options");
+        }
+    }
+
+    #[test]
+    fn test_bytes_reports_required_length() {
+        let iec = options(NUM_BYTES_IEC, NUM_BYTES_SHORT, 1);
+        let len = unsafe { num_format_bytes(3 << 29, &iec, std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1.5 GiB".len(), "Should report the required length");
+    }
+}
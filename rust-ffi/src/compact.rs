@@ -0,0 +1,491 @@
+// ============================================================================
+// Compact C FFI Wrappers - Abbreviated numbers such as `1.2K`, `45M`, `12.3 µs`
+// ============================================================================
+// Round to N significant digits, then pick the suffix with the largest power
+// of ten not above the rounded value, so 999,950 at 3 digits becomes `1M`
+// rather than `1000K`. Trailing zeros are dropped. Tables: short English
+// (K, M, B, T), SI prefixes (q ... µ m k M ... Q) or a caller supplied list
+// of (exponent, suffix) pairs. An optional separator and unit follow the
+// number (`12.3 µs`, `4.7 kHz`). NaN and infinities use the zmij spellings,
+// and -0.0 prints as `0` like 0.0.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style); 0 means the options were invalid.
+
+use std::slice;
+
+use crate::decimal::Decimal;
+use crate::fixed::non_finite_str;
+use crate::grouping::{Separators, c_bytes, write_grouped_decimal};
+use crate::out::Out;
+
+/// K, M, B, T (thousand, million, billion, trillion)
+pub const NUM_COMPACT_SHORT: u8 = 0;
+
+/// SI prefixes from q (10^-30) to Q (10^30), `µ` for micro
+pub const NUM_COMPACT_SI: u8 = 1;
+
+/// The suffixes / suffix_count table of NumCompactOptions
+pub const NUM_COMPACT_CUSTOM: u8 = 2;
+
+/// One entry of a custom suffix table
+#[repr(C)]
+pub struct NumCompactSuffix {
+    /// Power of ten the suffix stands for (3 for `K`)
+    pub exponent: i32,
+    /// Suffix text (UTF-8)
+    pub suffix: *const u8,
+    /// Length of suffix in bytes
+    pub suffix_len: usize,
+}
+
+/// Options for the compact formatters
+///
+/// A null pointer behaves like a zeroed struct: short English suffixes,
+/// 3 significant digits, no separator or unit.
+#[repr(C)]
+pub struct NumCompactOptions {
+    /// One of the NUM_COMPACT_* tables
+    pub table: u8,
+    /// Significant digits, 0 for 3
+    pub digits: u8,
+    /// Written between the number and a non-empty suffix or unit (UTF-8, may be null)
+    pub separator: *const u8,
+    /// Length of separator in bytes
+    pub separator_len: usize,
+    /// Written after the suffix, such as `s` or `Hz` (UTF-8, may be null)
+    pub unit: *const u8,
+    /// Length of unit in bytes
+    pub unit_len: usize,
+    /// Entries for NUM_COMPACT_CUSTOM, sorted by strictly increasing exponent
+    pub suffixes: *const NumCompactSuffix,
+    /// Number of entries in suffixes
+    pub suffix_count: usize,
+}
+
+const SHORT: [(i32, &str); 5] = [(0, ""), (3, "K"), (6, "M"), (9, "B"), (12, "T")];
+
+const SI: [(i32, &str); 21] = [
+    (-30, "q"),
+    (-27, "r"),
+    (-24, "y"),
+    (-21, "z"),
+    (-18, "a"),
+    (-15, "f"),
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "µ"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+    (15, "P"),
+    (18, "E"),
+    (21, "Z"),
+    (24, "Y"),
+    (27, "R"),
+    (30, "Q"),
+];
+
+enum Table<'a> {
+    Builtin(&'static [(i32, &'static str)]),
+    Custom(&'a [NumCompactSuffix]),
+}
+
+impl Table<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Table::Builtin(entries) => entries.len(),
+            Table::Custom(entries) => entries.len(),
+        }
+    }
+
+    fn exponent(&self, index: usize) -> i32 {
+        match self {
+            Table::Builtin(entries) => entries[index].0,
+            Table::Custom(entries) => entries[index].exponent,
+        }
+    }
+
+    /// Suffix text of an entry
+    ///
+    /// # Safety
+    /// - custom suffix strings must be null or valid for their lengths
+    unsafe fn suffix(&self, index: usize) -> &[u8] {
+        match self {
+            Table::Builtin(entries) => entries[index].1.as_bytes(),
+            // Safety: guaranteed by the caller
+            Table::Custom(entries) => unsafe {
+                c_bytes(entries[index].suffix, entries[index].suffix_len)
+            },
+        }
+    }
+}
+
+/// Resolved options
+struct Compact<'a> {
+    table: Table<'a>,
+    digits: i32,
+    separator: &'a [u8],
+    unit: &'a [u8],
+}
+
+/// Resolve a possibly null options pointer; None if it is invalid
+///
+/// # Safety
+/// - options must be null or point to a valid NumCompactOptions whose
+///   strings and suffix table are null or valid for their lengths
+unsafe fn compact_options<'a>(options: *const NumCompactOptions) -> Option<Compact<'a>> {
+    // Safety: guaranteed by the caller
+    let Some(options) = (unsafe { options.as_ref() }) else {
+        return Some(Compact {
+            table: Table::Builtin(&SHORT),
+            digits: 3,
+            separator: b"",
+            unit: b"",
+        });
+    };
+
+    let table = match options.table {
+        NUM_COMPACT_SHORT => Table::Builtin(&SHORT),
+        NUM_COMPACT_SI => Table::Builtin(&SI),
+        NUM_COMPACT_CUSTOM => {
+            if options.suffixes.is_null() || options.suffix_count == 0 {
+                return None;
+            }
+            // Safety: guaranteed by the caller
+            let entries = unsafe { slice::from_raw_parts(options.suffixes, options.suffix_count) };
+            if !entries
+                .windows(2)
+                .all(|pair| pair[0].exponent < pair[1].exponent)
+            {
+                return None;
+            }
+            Table::Custom(entries)
+        }
+        _ => return None,
+    };
+    let digits = match options.digits {
+        0 => 3,
+        digits => digits as i32,
+    };
+    Some(Compact {
+        table,
+        digits,
+        // Safety: guaranteed by the caller
+        separator: unsafe { c_bytes(options.separator, options.separator_len) },
+        // Safety: guaranteed by the caller
+        unit: unsafe { c_bytes(options.unit, options.unit_len) },
+    })
+}
+
+/// Write a finite decimal abbreviated with the table's suffixes
+///
+/// # Safety
+/// - custom suffix strings must be null or valid for their lengths
+unsafe fn write_compact(out: &mut Out, mut decimal: Decimal, compact: &Compact) {
+    decimal.round(compact.digits);
+    if decimal.is_zero() {
+        decimal.negative = false;
+    }
+
+    // Power of ten of the leading digit; zero picks the unscaled entry
+    let magnitude = if decimal.is_zero() {
+        0
+    } else {
+        decimal.exp - 1
+    };
+    // Below the first entry the value stays unscaled, unless the table goes
+    // below 10^0 (SI), where the smallest prefix beats a long run of zeros
+    let entry = (0..compact.table.len())
+        .rev()
+        .find(|&index| compact.table.exponent(index) <= magnitude)
+        .or_else(|| (compact.table.exponent(0) < 0).then_some(0));
+
+    let suffix: &[u8] = match entry {
+        Some(index) => {
+            if !decimal.is_zero() {
+                decimal.exp -= compact.table.exponent(index);
+            }
+            // Safety: guaranteed by the caller
+            unsafe { compact.table.suffix(index) }
+        }
+        None => b"",
+    };
+
+    write_grouped_decimal(out, &decimal, None, &Separators::NONE);
+    if !suffix.is_empty() || !compact.unit.is_empty() {
+        out.push_bytes(compact.separator);
+        out.push_bytes(suffix);
+        out.push_bytes(compact.unit);
+    }
+}
+
+/// Shared body of the exports: resolve options, then format into buf
+///
+/// # Safety
+/// - options must be null or point to a valid NumCompactOptions whose
+///   strings and suffix table are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn compact_into(
+    options: *const NumCompactOptions,
+    buf: *mut u8,
+    buf_len: usize,
+    decimal: impl FnOnce() -> Option<Decimal>,
+    non_finite: &[u8],
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(compact) = (unsafe { compact_options(options) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    match decimal() {
+        // Safety: the suffix table was validated above
+        Some(decimal) => unsafe { write_compact(&mut out, decimal, &compact) },
+        None => {
+            out.push_bytes(non_finite);
+            if !compact.unit.is_empty() {
+                out.push_bytes(compact.separator);
+                out.push_bytes(compact.unit);
+            }
+        }
+    }
+    out.len()
+}
+
+/// Format f64 in compact notation such as `1.2K` or `12.3 µs`
+///
+/// # Safety
+/// - options must be null or point to a valid NumCompactOptions whose
+///   strings and suffix table are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the table is unknown, or custom and empty or not sorted
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_compact_f64(
+    value: f64,
+    options: *const NumCompactOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let decimal = || value.is_finite().then(|| Decimal::exact_f64(value));
+    // Safety: guaranteed by the caller
+    unsafe { compact_into(options, buf, buf_len, decimal, non_finite_str(value)) }
+}
+
+/// Format i64 in compact notation such as `-45M`
+///
+/// # Safety
+/// - options must be null or point to a valid NumCompactOptions whose
+///   strings and suffix table are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the table is unknown, or custom and empty or not sorted
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_compact_i64(
+    value: i64,
+    options: *const NumCompactOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let decimal = || Some(Decimal::from_int(value < 0, value.unsigned_abs() as u128));
+    // Safety: guaranteed by the caller
+    unsafe { compact_into(options, buf, buf_len, decimal, b"") }
+}
+
+/// Format u64 in compact notation such as `3.1B`
+///
+/// # Safety
+/// - options must be null or point to a valid NumCompactOptions whose
+///   strings and suffix table are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the table is unknown, or custom and empty or not sorted
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_compact_u64(
+    value: u64,
+    options: *const NumCompactOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let decimal = || Some(Decimal::from_int(false, value as u128));
+    // Safety: guaranteed by the caller
+    unsafe { compact_into(options, buf, buf_len, decimal, b"") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(table: u8, digits: u8, separator: &str, unit: &str) -> NumCompactOptions {
+        NumCompactOptions {
+            table,
+            digits,
+            separator: separator.as_ptr(),
+            separator_len: separator.len(),
+            unit: unit.as_ptr(),
+            unit_len: unit.len(),
+            suffixes: std::ptr::null(),
+            suffix_count: 0,
+        }
+    }
+
+    fn compact_f64_test(value: f64, options: *const NumCompactOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_format_compact_f64(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_compact_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn compact_i64_test(value: i64, options: *const NumCompactOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_format_compact_i64(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_compact_i64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_compact_short() {
+        let short = std::ptr::null();
+        assert_eq!(compact_i64_test(0, short), "0");
+        assert_eq!(compact_i64_test(999, short), "999");
+        assert_eq!(compact_i64_test(1234, short), "1.23K");
+        assert_eq!(compact_i64_test(45_000_000, short), "45M");
+        assert_eq!(compact_i64_test(-3_140_000_000, short), "-3.14B");
+        assert_eq!(compact_i64_test(i64::MAX, short), "9220000T");
+        assert_eq!(compact_f64_test(0.125, short), "0.125");
+    }
+
+    #[test]
+    fn test_compact_rounding_moves_to_next_suffix() {
+        let short = std::ptr::null();
+        assert_eq!(compact_i64_test(999_950, short), "1M");
+        assert_eq!(compact_i64_test(999_499, short), "999K");
+        let two = options(NUM_COMPACT_SHORT, 2, "", "");
+        assert_eq!(compact_i64_test(1_250, &two), "1.2K");
+        assert_eq!(compact_i64_test(1_350, &two), "1.4K");
+        assert_eq!(compact_i64_test(99_500, &two), "100K");
+    }
+
+    #[test]
+    fn test_compact_si() {
+        let seconds = options(NUM_COMPACT_SI, 3, " ", "s");
+        assert_eq!(compact_f64_test(12.3e-6, &seconds), "12.3 µs");
+        assert_eq!(compact_f64_test(0.5, &seconds), "500 ms");
+        assert_eq!(compact_f64_test(2.0, &seconds), "2 s");
+        assert_eq!(compact_f64_test(0.0, &seconds), "0 s");
+        let hertz = options(NUM_COMPACT_SI, 2, " ", "Hz");
+        assert_eq!(compact_f64_test(4_700.0, &hertz), "4.7 kHz");
+        assert_eq!(compact_f64_test(-2.5e9, &hertz), "-2.5 GHz");
+        let bare = options(NUM_COMPACT_SI, 3, "", "");
+        assert_eq!(compact_f64_test(1e-33, &bare), "0.001q");
+    }
+
+    #[test]
+    fn test_compact_custom_table() {
+        let indian = [
+            NumCompactSuffix {
+                exponent: 3,
+                suffix: "K".as_ptr(),
+                suffix_len: 1,
+            },
+            NumCompactSuffix {
+                exponent: 5,
+                suffix: "L".as_ptr(),
+                suffix_len: 1,
+            },
+            NumCompactSuffix {
+                exponent: 7,
+                suffix: "Cr".as_ptr(),
+                suffix_len: 2,
+            },
+        ];
+        let mut custom = options(NUM_COMPACT_CUSTOM, 3, " ", "");
+        custom.suffixes = indian.as_ptr();
+        custom.suffix_count = indian.len();
+        assert_eq!(compact_i64_test(950, &custom), "950");
+        assert_eq!(compact_i64_test(250_000, &custom), "2.5 L");
+        assert_eq!(compact_i64_test(123_456_789, &custom), "12.3 Cr");
+    }
+
+    #[test]
+    fn test_compact_non_finite_matches_zmij() {
+        assert_eq!(compact_f64_test(f64::NAN, std::ptr::null()), "NaN");
+        assert_eq!(
+            compact_f64_test(f64::NEG_INFINITY, std::ptr::null()),
+            "-inf"
+        );
+        let seconds = options(NUM_COMPACT_SI, 3, " ", "s");
+        assert_eq!(compact_f64_test(f64::INFINITY, &seconds), "inf s");
+    }
+
+    #[test]
+    fn test_compact_negative_zero() {
+        assert_eq!(compact_f64_test(-0.0, std::ptr::null()), "0");
+        let seconds = options(NUM_COMPACT_SI, 3, " ", "s");
+        assert_eq!(compact_f64_test(-0.0, &seconds), "0 s");
+        assert_eq!(compact_f64_test(-2.5e-3, &seconds), "-2.5 ms");
+    }
+
+    #[test]
+    fn test_compact_u64() {
+        let mut buf = [0u8; 32];
+        let len = unsafe {
+            num_format_compact_u64(u64::MAX, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"18400000T");
+    }
+
+    #[test]
+    fn test_compact_invalid_options() {
+        let mut buf = [0u8; 32];
+        let unknown = options(3, 3, "", "");
+        let len = unsafe { num_format_compact_i64(1, &unknown, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for an unknown table");
+
+        let unsorted = [
+            NumCompactSuffix {
+                exponent: 6,
+                suffix: "M".as_ptr(),
+                suffix_len: 1,
+            },
+            NumCompactSuffix {
+                exponent: 3,
+                suffix: "K".as_ptr(),
+                suffix_len: 1,
+            },
+        ];
+        let mut custom = options(NUM_COMPACT_CUSTOM, 3, "", "");
+        custom.suffixes = unsorted.as_ptr();
+        custom.suffix_count = unsorted.len();
+        let len = unsafe { num_format_compact_i64(1, &custom, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for an unsorted custom table");
+
+        custom.suffix_count = 0;
+        let len = unsafe { num_format_compact_i64(1, &custom, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for an empty custom table");
+    }
+
+    #[test]
+    fn test_compact_reports_required_length() {
+        let len =
+            unsafe { num_format_compact_i64(1234, std::ptr::null(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1.23K".len(), "Should report the required length");
+    }
+}
@@ -0,0 +1,465 @@
+// ============================================================================
+// Currency handle C FFI Wrappers - `$1,234.56`, `(1.234,56 €)`, `JPY 1,235`
+// ============================================================================
+// A NumCurrency combines an ISO 4217 currency from a small built-in table
+// (symbol and minor-unit digits) with a display style: symbol or code, before
+// or after the amount, a space rule, and `-` or accounting parentheses for
+// negatives. Amounts are i64 minor units printed through the scaled integer
+// formatter, so every minor digit is shown (`$5.00`, `¥1,235`, `BD 1.250`).
+// The configuration strings are copied into the handle. Functions return the
+// length the output requires and write it only if it is <= buf_len
+// (snprintf-style).
+
+use crate::grouping::{NumGrouping, Separators, c_bytes, separators};
+use crate::out::Out;
+use crate::scaled::{NUM_SCALED_MAX_SCALE, write_scaled};
+
+/// Show the currency symbol (`$`, `€`, `CHF`)
+pub const NUM_CURRENCY_SYMBOL: u8 = 0;
+
+/// Show the ISO 4217 code (`USD`)
+pub const NUM_CURRENCY_CODE: u8 = 1;
+
+/// Symbol or code before the amount: `$1.00`
+pub const NUM_CURRENCY_PREFIX: u8 = 0;
+
+/// Symbol or code after the amount: `1,00 €`
+pub const NUM_CURRENCY_SUFFIX: u8 = 1;
+
+/// Space between amount and label, except after prefix symbols that do not
+/// end in a letter: `$1.00`, `CHF 1.00`, `USD 1.00`, `1.00 €`
+pub const NUM_CURRENCY_SPACE_AUTO: u8 = 0;
+
+/// Never a space: `USD1.00`, `1.00€`
+pub const NUM_CURRENCY_SPACE_NEVER: u8 = 1;
+
+/// Always a space: `$ 1.00`
+pub const NUM_CURRENCY_SPACE_ALWAYS: u8 = 2;
+
+/// Minus before everything: `-$1.00`, `-1,00 €`
+pub const NUM_CURRENCY_NEGATIVE_MINUS: u8 = 0;
+
+/// Accounting parentheses around everything: `($1.00)`, `(1,00 €)`
+pub const NUM_CURRENCY_NEGATIVE_ACCOUNTING: u8 = 1;
+
+/// ISO 4217 code, symbol and minor-unit digits
+const CURRENCIES: [(&str, &str, u8); 30] = [
+    ("AUD", "A$", 2),
+    ("BHD", "BD", 3),
+    ("BRL", "R$", 2),
+    ("CAD", "CA$", 2),
+    ("CHF", "CHF", 2),
+    ("CLP", "CLP$", 0),
+    ("CNY", "CN¥", 2),
+    ("CZK", "Kč", 2),
+    ("DKK", "kr", 2),
+    ("EUR", "€", 2),
+    ("GBP", "£", 2),
+    ("HKD", "HK$", 2),
+    ("IDR", "Rp", 2),
+    ("ILS", "₪", 2),
+    ("INR", "₹", 2),
+    ("ISK", "kr", 0),
+    ("JOD", "JD", 3),
+    ("JPY", "¥", 0),
+    ("KRW", "₩", 0),
+    ("KWD", "KD", 3),
+    ("MXN", "MX$", 2),
+    ("NOK", "kr", 2),
+    ("NZD", "NZ$", 2),
+    ("OMR", "OMR", 3),
+    ("PLN", "zł", 2),
+    ("SEK", "kr", 2),
+    ("SGD", "S$", 2),
+    ("TRY", "₺", 2),
+    ("USD", "$", 2),
+    ("VND", "₫", 0),
+];
+
+/// Look up a currency code (ASCII case-insensitive) in the built-in table
+fn lookup(code: &[u8]) -> Option<(&'static str, u8)> {
+    CURRENCIES
+        .iter()
+        .find(|(iso, _, _)| iso.as_bytes().eq_ignore_ascii_case(code))
+        .map(|&(_, symbol, digits)| (symbol, digits))
+}
+
+/// Configuration for num_currency_new
+///
+/// The code is required. It selects the symbol and minor digits from the
+/// built-in table; a code outside the table needs minor_digits >= 0 and uses
+/// the code as its symbol unless one is given.
+#[repr(C)]
+pub struct NumCurrencyConfig {
+    /// ISO 4217 code such as `USD` (UTF-8)
+    pub code: *const u8,
+    /// Length of code in bytes
+    pub code_len: usize,
+    /// Symbol replacing the table's (UTF-8, null for the table's)
+    pub symbol: *const u8,
+    /// Length of symbol in bytes
+    pub symbol_len: usize,
+    /// NUM_CURRENCY_SYMBOL or NUM_CURRENCY_CODE
+    pub display: u8,
+    /// NUM_CURRENCY_PREFIX or NUM_CURRENCY_SUFFIX
+    pub placement: u8,
+    /// One of the NUM_CURRENCY_SPACE_* constants
+    pub space: u8,
+    /// NUM_CURRENCY_NEGATIVE_MINUS or NUM_CURRENCY_NEGATIVE_ACCOUNTING
+    pub negative: u8,
+    /// Digits of the minor unit (0..=NUM_SCALED_MAX_SCALE), or -1 for the table's
+    pub minor_digits: i8,
+    /// Separators and grouping, or null for `1,234,567.89`
+    pub grouping: *const NumGrouping,
+}
+
+/// A currency display profile created by num_currency_new
+///
+/// Release with num_currency_free. A currency is immutable, so it may be
+/// shared between threads.
+pub struct NumCurrency {
+    label: Vec<u8>,
+    prefix: bool,
+    space: bool,
+    accounting: bool,
+    minor_digits: usize,
+    decimal_sep: Vec<u8>,
+    group_sep: Vec<u8>,
+    primary: usize,
+    secondary: usize,
+}
+
+impl NumCurrency {
+    /// Build a currency; None for a missing or unknown code or invalid styles
+    ///
+    /// # Safety
+    /// - the config strings and grouping must be null or valid
+    unsafe fn from_config(config: &NumCurrencyConfig) -> Option<Self> {
+        // Safety: guaranteed by the caller
+        let code = unsafe { c_bytes(config.code, config.code_len) };
+        if code.is_empty() || config.minor_digits > NUM_SCALED_MAX_SCALE as i8 {
+            return None;
+        }
+        let (table_symbol, minor_digits) = match (lookup(code), config.minor_digits) {
+            (Some((symbol, digits)), -1) => (symbol.as_bytes(), digits),
+            (Some((symbol, _)), digits) if digits >= 0 => (symbol.as_bytes(), digits as u8),
+            (None, digits) if digits >= 0 => (code, digits as u8),
+            _ => return None,
+        };
+
+        let label = match config.display {
+            NUM_CURRENCY_CODE => code.to_vec(),
+            NUM_CURRENCY_SYMBOL if config.symbol.is_null() => table_symbol.to_vec(),
+            // Safety: guaranteed by the caller
+            NUM_CURRENCY_SYMBOL => unsafe { c_bytes(config.symbol, config.symbol_len) }.to_vec(),
+            _ => return None,
+        };
+        let prefix = match config.placement {
+            NUM_CURRENCY_PREFIX => true,
+            NUM_CURRENCY_SUFFIX => false,
+            _ => return None,
+        };
+        let space = match config.space {
+            NUM_CURRENCY_SPACE_AUTO => !prefix || label.last().is_some_and(u8::is_ascii_alphabetic),
+            NUM_CURRENCY_SPACE_NEVER => false,
+            NUM_CURRENCY_SPACE_ALWAYS => true,
+            _ => return None,
+        };
+        let accounting = match config.negative {
+            NUM_CURRENCY_NEGATIVE_MINUS => false,
+            NUM_CURRENCY_NEGATIVE_ACCOUNTING => true,
+            _ => return None,
+        };
+
+        // Safety: guaranteed by the caller
+        let sep = unsafe { separators(config.grouping) };
+
+        Some(NumCurrency {
+            label,
+            prefix,
+            space,
+            accounting,
+            minor_digits: minor_digits as usize,
+            decimal_sep: sep.decimal.to_vec(),
+            group_sep: sep.group.to_vec(),
+            primary: sep.primary,
+            secondary: sep.secondary,
+        })
+    }
+
+    fn format(&self, out: &mut Out, minor_units: i64) {
+        let negative = minor_units < 0;
+        match (negative, self.accounting) {
+            (true, true) => out.push(b'('),
+            (true, false) => out.push(b'-'),
+            _ => {}
+        }
+        if self.prefix {
+            out.push_bytes(&self.label);
+            if self.space {
+                out.push(b' ');
+            }
+        }
+
+        let sep = Separators {
+            group: &self.group_sep,
+            decimal: &self.decimal_sep,
+            primary: self.primary,
+            secondary: self.secondary,
+        };
+        write_scaled(
+            out,
+            minor_units.unsigned_abs(),
+            self.minor_digits,
+            self.minor_digits,
+            &sep,
+        );
+
+        if !self.prefix {
+            if self.space {
+                out.push(b' ');
+            }
+            out.push_bytes(&self.label);
+        }
+        if negative && self.accounting {
+            out.push(b')');
+        }
+    }
+}
+
+/// Create a currency display profile
+///
+/// # Safety
+/// - config must be null or point to a valid NumCurrencyConfig whose strings
+///   and grouping are null or valid
+///
+/// # Returns
+/// A handle to release with num_currency_free, or null if config is null, the
+/// code is empty or unknown without minor_digits, or a style is invalid
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_currency_new(config: *const NumCurrencyConfig) -> *mut NumCurrency {
+    // Safety: guaranteed by the caller
+    let currency =
+        unsafe { config.as_ref() }.and_then(|config| unsafe { NumCurrency::from_config(config) });
+    match currency {
+        Some(currency) => Box::into_raw(Box::new(currency)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Release a currency returned by num_currency_new (null is ignored)
+///
+/// # Safety
+/// - currency must be null or a pointer returned by num_currency_new, not yet freed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_currency_free(currency: *mut NumCurrency) {
+    if !currency.is_null() {
+        // Safety: currency came from Box::into_raw in num_currency_new
+        drop(unsafe { Box::from_raw(currency) });
+    }
+}
+
+/// Format an amount in minor units (cents for USD) with a currency's profile
+///
+/// # Safety
+/// - currency must be null or a live pointer returned by num_currency_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if currency is null
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_currency_format(
+    currency: *const NumCurrency,
+    minor_units: i64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(currency) = (unsafe { currency.as_ref() }) else {
+        return 0;
+    };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    currency.format(&mut out, minor_units);
+    out.len()
+}
+
+/// Minor-unit digits of a currency in the built-in table
+///
+/// # Safety
+/// - code must be null or valid for reads of code_len bytes
+///
+/// # Returns
+/// 2 for USD, 0 for JPY, 3 for BHD, ...; -1 if the code is not in the table
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_currency_minor_digits(code: *const u8, code_len: usize) -> i32 {
+    // Safety: guaranteed by the caller
+    let code = unsafe { c_bytes(code, code_len) };
+    lookup(code).map_or(-1, |(_, digits)| i32::from(digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EUROPEAN: NumGrouping = NumGrouping {
+        group_sep: b".".as_ptr(),
+        group_sep_len: 1,
+        decimal_sep: b",".as_ptr(),
+        decimal_sep_len: 1,
+        primary: 3,
+        secondary: 3,
+    };
+
+    fn config(code: &str) -> NumCurrencyConfig {
+        NumCurrencyConfig {
+            code: code.as_ptr(),
+            code_len: code.len(),
+            symbol: std::ptr::null(),
+            symbol_len: 0,
+            display: NUM_CURRENCY_SYMBOL,
+            placement: NUM_CURRENCY_PREFIX,
+            space: NUM_CURRENCY_SPACE_AUTO,
+            negative: NUM_CURRENCY_NEGATIVE_MINUS,
+            minor_digits: -1,
+            grouping: std::ptr::null(),
+        }
+    }
+
+    /// Format each amount with a fresh currency built from `cfg`
+    fn currency_test(cfg: &NumCurrencyConfig, amounts: &[i64]) -> Vec<String> {
+        let currency = unsafe { num_currency_new(cfg) };
+        assert!(!currency.is_null(), "num_currency_new failed");
+        let results = amounts
+            .iter()
+            .map(|&amount| {
+                let mut buf = [0u8; 64];
+                let len =
+                    unsafe { num_currency_format(currency, amount, buf.as_mut_ptr(), buf.len()) };
+                assert!(len > 0 && len <= buf.len(), "num_currency_format failed");
+                String::from_utf8(buf[..len].to_vec()).unwrap()
+            })
+            .collect();
+        unsafe { num_currency_free(currency) };
+        results
+    }
+
+    #[test]
+    fn test_currency_minor_digits_from_table() {
+        assert_eq!(
+            currency_test(&config("USD"), &[123_456, 5, -500]),
+            ["$1,234.56", "$0.05", "-$5.00"]
+        );
+        assert_eq!(currency_test(&config("JPY"), &[1_235]), ["¥1,235"]);
+        assert_eq!(currency_test(&config("BHD"), &[1_250]), ["BD 1.250"]);
+        for (code, digits) in [("USD", 2), ("jpy", 0), ("BHD", 3), ("XXX", -1), ("", -1)] {
+            let found = unsafe { num_currency_minor_digits(code.as_ptr(), code.len()) };
+            assert_eq!(found, digits, "minor digits of {:?}", code);
+        }
+    }
+
+    #[test]
+    fn test_currency_code_and_placement() {
+        let mut cfg = config("USD");
+        cfg.display = NUM_CURRENCY_CODE;
+        assert_eq!(currency_test(&cfg, &[-123_456]), ["-USD 1,234.56"]);
+        cfg.placement = NUM_CURRENCY_SUFFIX;
+        assert_eq!(currency_test(&cfg, &[123_456]), ["1,234.56 USD"]);
+
+        let mut euro = config("EUR");
+        euro.placement = NUM_CURRENCY_SUFFIX;
+        euro.grouping = &EUROPEAN;
+        assert_eq!(
+            currency_test(&euro, &[123_456, -99]),
+            ["1.234,56 €", "-0,99 €"]
+        );
+    }
+
+    #[test]
+    fn test_currency_space_rules() {
+        assert_eq!(currency_test(&config("CHF"), &[100]), ["CHF 1.00"]);
+        assert_eq!(currency_test(&config("CAD"), &[100]), ["CA$1.00"]);
+        let mut always = config("USD");
+        always.space = NUM_CURRENCY_SPACE_ALWAYS;
+        assert_eq!(currency_test(&always, &[100]), ["$ 1.00"]);
+        let mut never = config("EUR");
+        never.placement = NUM_CURRENCY_SUFFIX;
+        never.space = NUM_CURRENCY_SPACE_NEVER;
+        assert_eq!(currency_test(&never, &[100]), ["1.00€"]);
+    }
+
+    #[test]
+    fn test_currency_accounting_negatives() {
+        let mut cfg = config("USD");
+        cfg.negative = NUM_CURRENCY_NEGATIVE_ACCOUNTING;
+        assert_eq!(
+            currency_test(&cfg, &[-123_456, 123_456, 0]),
+            ["($1,234.56)", "$1,234.56", "$0.00"]
+        );
+        cfg.placement = NUM_CURRENCY_SUFFIX;
+        cfg.display = NUM_CURRENCY_CODE;
+        assert_eq!(currency_test(&cfg, &[-1]), ["(0.01 USD)"]);
+        assert_eq!(
+            currency_test(&cfg, &[i64::MIN]),
+            ["(92,233,720,368,547,758.08 USD)"]
+        );
+    }
+
+    #[test]
+    fn test_currency_overrides_and_custom_codes() {
+        let mut cfg = config("USD");
+        let symbol = String::from("US$");
+        cfg.symbol = symbol.as_ptr();
+        cfg.symbol_len = symbol.len();
+        cfg.minor_digits = 4;
+        let currency = unsafe { num_currency_new(&cfg) };
+        drop(symbol);
+        let mut buf = [0u8; 32];
+        let len = unsafe { num_currency_format(currency, 12_345, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"US$1.2345");
+        unsafe { num_currency_free(currency) };
+
+        let mut custom = config("BTC");
+        custom.minor_digits = 8;
+        assert_eq!(currency_test(&custom, &[150_000_000]), ["BTC 1.50000000"]);
+    }
+
+    #[test]
+    fn test_currency_invalid_config() {
+        let mut unknown = config("XXX");
+        assert!(
+            unsafe { num_currency_new(&unknown) }.is_null(),
+            "Should reject unknown code"
+        );
+        unknown.minor_digits = NUM_SCALED_MAX_SCALE as i8 + 1;
+        assert!(
+            unsafe { num_currency_new(&unknown) }.is_null(),
+            "Should reject too many digits"
+        );
+        let mut bad_style = config("USD");
+        bad_style.negative = 2;
+        assert!(
+            unsafe { num_currency_new(&bad_style) }.is_null(),
+            "Should reject unknown style"
+        );
+        assert!(
+            unsafe { num_currency_new(&config("")) }.is_null(),
+            "Should reject empty code"
+        );
+        assert!(unsafe { num_currency_new(std::ptr::null()) }.is_null());
+        unsafe { num_currency_free(std::ptr::null_mut()) };
+
+        let mut buf = [0u8; 8];
+        let len = unsafe { num_currency_format(std::ptr::null(), 1, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for a null currency");
+    }
+
+    #[test]
+    fn test_currency_reports_required_length() {
+        let currency = unsafe { num_currency_new(&config("EUR")) };
+        let len = unsafe { num_currency_format(currency, -123_456, std::ptr::null_mut(), 0) };
+        assert_eq!(len, "-€1,234.56".len(), "Should report the required length");
+        unsafe { num_currency_free(currency) };
+    }
+}
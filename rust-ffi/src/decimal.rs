@@ -0,0 +1,433 @@
+// ============================================================================
+// Decimal digit expansion of binary floating point values
+// ============================================================================
+// `Decimal::exact_f64` produces every digit of the exact binary value (at most
+// 767 significant digits for f64), which lets the fixed and scientific
+// formatters round half-to-even without ever double rounding.
+// `Decimal::shortest_*` reuses zmij to get the shortest round-trip digits.
+
+/// Enough room for the exact expansion of any f64 (767 significant digits)
+pub(crate) const MAX_DIGITS: usize = 800;
+
+/// A decimal value `0.d1 d2 ... dn * 10^exp`
+///
+/// `digits` holds ASCII digits without leading or trailing zeros, so zero is
+/// represented by `len == 0`.
+pub(crate) struct Decimal {
+    pub(crate) digits: [u8; MAX_DIGITS],
+    pub(crate) len: usize,
+    pub(crate) exp: i32,
+    pub(crate) negative: bool,
+}
+
+impl Decimal {
+    fn zero(negative: bool) -> Self {
+        Decimal {
+            digits: [b'0'; MAX_DIGITS],
+            len: 0,
+            exp: 0,
+            negative,
+        }
+    }
+
+    /// Exact decimal expansion of a finite f64
+    pub(crate) fn exact_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let negative = bits >> 63 != 0;
+        let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1u64 << 52) - 1);
+
+        let (mut mantissa, mut exp2) = if biased_exp == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased_exp - 1075)
+        };
+
+        let mut decimal = Decimal::zero(negative);
+        if mantissa == 0 {
+            return decimal;
+        }
+
+        let trailing = mantissa.trailing_zeros();
+        mantissa >>= trailing;
+        exp2 += trailing as i32;
+
+        // value = mantissa * 2^exp2
+        //       = mantissa * 5^-exp2 / 10^-exp2   when exp2 < 0
+        let mut big = Big::from_u64(mantissa);
+        let shift = if exp2 >= 0 {
+            big.shl(exp2 as u32);
+            0
+        } else {
+            big.mul_pow5((-exp2) as u32);
+            -exp2
+        };
+
+        decimal.len = big.write_decimal(&mut decimal.digits);
+        decimal.exp = decimal.len as i32 - shift;
+        decimal.trim_trailing_zeros();
+        decimal
+    }
+
+    /// Decimal digits of an integer given as sign and magnitude
+    pub(crate) fn from_int(negative: bool, magnitude: u128) -> Self {
+        let mut decimal = Decimal::zero(negative);
+        let mut buffer = itoa::Buffer::new();
+        let digits = buffer.format(magnitude).as_bytes();
+        if magnitude != 0 {
+            decimal.digits[..digits.len()].copy_from_slice(digits);
+            decimal.len = digits.len();
+            decimal.exp = digits.len() as i32;
+            decimal.trim_trailing_zeros();
+        }
+        decimal
+    }
+
+    /// Exact decimal expansion of a finite f32
+    pub(crate) fn exact_f32(value: f32) -> Self {
+        // Widening is lossless, the digits are identical
+        Decimal::exact_f64(value as f64)
+    }
+
+    /// Shortest round-trip digits of a finite f64 (as produced by zmij)
+    pub(crate) fn shortest_f64(value: f64) -> Self {
+        let mut buffer = zmij::Buffer::new();
+        Decimal::parse_zmij(buffer.format_finite(value).as_bytes())
+    }
+
+    /// Shortest round-trip digits of a finite f32 (as produced by zmij)
+    pub(crate) fn shortest_f32(value: f32) -> Self {
+        let mut buffer = zmij::Buffer::new();
+        Decimal::parse_zmij(buffer.format_finite(value).as_bytes())
+    }
+
+    /// Parse zmij output such as `-123.45`, `0.0001` or `1.5e+300`
+    fn parse_zmij(text: &[u8]) -> Self {
+        let mut decimal = Decimal::zero(text.first() == Some(&b'-'));
+        let mut int_digits = 0i32;
+        let mut seen_point = false;
+        let mut exp10 = 0i32;
+
+        let mut i = usize::from(decimal.negative);
+        while i < text.len() {
+            match text[i] {
+                b'0'..=b'9' => {
+                    if !seen_point {
+                        int_digits += 1;
+                    }
+                    if decimal.len == 0 && text[i] == b'0' {
+                        // Leading zeros only move the decimal point
+                        int_digits -= 1;
+                    } else {
+                        decimal.digits[decimal.len] = text[i];
+                        decimal.len += 1;
+                    }
+                }
+                b'.' => seen_point = true,
+                b'e' | b'E' => {
+                    let mut sign = 1;
+                    for &b in &text[i + 1..] {
+                        match b {
+                            b'-' => sign = -1,
+                            b'+' => {}
+                            _ => exp10 = exp10 * 10 + (b - b'0') as i32,
+                        }
+                    }
+                    exp10 *= sign;
+                    break;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        decimal.exp = int_digits + exp10;
+        decimal.trim_trailing_zeros();
+        if decimal.len == 0 {
+            decimal.exp = 0;
+        }
+        decimal
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Digit at position `index` (0 = most significant), zero outside the digits
+    #[inline]
+    pub(crate) fn digit(&self, index: i32) -> u8 {
+        if index < 0 || index as usize >= self.len {
+            b'0'
+        } else {
+            self.digits[index as usize]
+        }
+    }
+
+    /// Round half-to-even so that at most `keep` significant digits remain
+    ///
+    /// `keep` may be zero or negative when rounding happens left of the first
+    /// digit (e.g. 0.004 rounded to two decimal places).
+    pub(crate) fn round(&mut self, keep: i32) {
+        if keep >= self.len as i32 {
+            return;
+        }
+        if keep < 0 {
+            self.len = 0;
+            self.exp = 0;
+            return;
+        }
+
+        let keep = keep as usize;
+        let first_dropped = self.digits[keep];
+        let round_up = match first_dropped {
+            b'6'..=b'9' => true,
+            b'5' => {
+                // Digits are trimmed, so anything after the 5 means "above half"
+                let above_half = self.len > keep + 1;
+                let odd = keep > 0 && (self.digits[keep - 1] - b'0') % 2 == 1;
+                above_half || odd
+            }
+            _ => false,
+        };
+
+        self.len = keep;
+        if round_up {
+            while self.len > 0 && self.digits[self.len - 1] == b'9' {
+                self.len -= 1;
+            }
+            if self.len == 0 {
+                self.digits[0] = b'1';
+                self.len = 1;
+                self.exp += 1;
+            } else {
+                self.digits[self.len - 1] += 1;
+            }
+        }
+
+        self.trim_trailing_zeros();
+        if self.len == 0 {
+            self.exp = 0;
+        }
+    }
+
+    fn trim_trailing_zeros(&mut self) {
+        while self.len > 0 && self.digits[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+    }
+}
+
+// ============================================================================
+// Minimal fixed-capacity big integer for the exact expansion
+// ============================================================================
+
+/// 53-bit mantissa * 5^1074 needs 2547 bits
+const LIMBS: usize = 84;
+
+struct Big {
+    limbs: [u32; LIMBS],
+    len: usize,
+}
+
+impl Big {
+    fn from_u64(value: u64) -> Self {
+        let mut big = Big {
+            limbs: [0; LIMBS],
+            len: 2,
+        };
+        big.limbs[0] = value as u32;
+        big.limbs[1] = (value >> 32) as u32;
+        big.normalize();
+        big
+    }
+
+    fn normalize(&mut self) {
+        while self.len > 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    fn shl(&mut self, bits: u32) {
+        let limb_shift = (bits / 32) as usize;
+        let bit_shift = bits % 32;
+
+        if bit_shift > 0 {
+            let mut carry = 0u32;
+            for limb in &mut self.limbs[..self.len] {
+                let next = *limb >> (32 - bit_shift);
+                *limb = (*limb << bit_shift) | carry;
+                carry = next;
+            }
+            if carry != 0 {
+                self.limbs[self.len] = carry;
+                self.len += 1;
+            }
+        }
+
+        if limb_shift > 0 {
+            self.limbs.copy_within(..self.len, limb_shift);
+            self.limbs[..limb_shift].fill(0);
+            self.len += limb_shift;
+        }
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0u64;
+        for limb in &mut self.limbs[..self.len] {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    fn mul_pow5(&mut self, mut exp: u32) {
+        const POW5_13: u32 = 1_220_703_125;
+        while exp >= 13 {
+            self.mul_small(POW5_13);
+            exp -= 13;
+        }
+        if exp > 0 {
+            self.mul_small(5u32.pow(exp));
+        }
+    }
+
+    /// Divide in place, returning the remainder
+    fn div_small(&mut self, divisor: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs[..self.len].iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / divisor as u64) as u32;
+            rem = cur % divisor as u64;
+        }
+        self.normalize();
+        rem as u32
+    }
+
+    /// Write the decimal digits (most significant first), returning the count
+    fn write_decimal(mut self, digits: &mut [u8]) -> usize {
+        const CHUNK: u32 = 1_000_000_000;
+        let mut chunks = [0u32; MAX_DIGITS / 9 + 1];
+        let mut count = 0;
+        while self.len > 0 {
+            chunks[count] = self.div_small(CHUNK);
+            count += 1;
+        }
+
+        let mut len = 0;
+        for (i, &chunk) in chunks[..count].iter().rev().enumerate() {
+            let mut buffer = itoa::Buffer::new();
+            let text = buffer.format(chunk).as_bytes();
+            if i > 0 {
+                // Inner chunks are always nine digits wide
+                for _ in text.len()..9 {
+                    digits[len] = b'0';
+                    len += 1;
+                }
+            }
+            digits[len..len + text.len()].copy_from_slice(text);
+            len += text.len();
+        }
+        len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(decimal: &Decimal) -> String {
+        String::from_utf8_lossy(&decimal.digits[..decimal.len]).into_owned()
+    }
+
+    #[test]
+    fn test_exact_f64_simple() {
+        let decimal = Decimal::exact_f64(0.5);
+        assert_eq!(digits(&decimal), "5");
+        assert_eq!(decimal.exp, 0);
+
+        let decimal = Decimal::exact_f64(-1250.0);
+        assert_eq!(digits(&decimal), "125");
+        assert_eq!(decimal.exp, 4);
+        assert!(decimal.negative);
+    }
+
+    #[test]
+    fn test_exact_f64_tenth_is_not_exact() {
+        let decimal = Decimal::exact_f64(0.1);
+        assert_eq!(
+            digits(&decimal),
+            "1000000000000000055511151231257827021181583404541015625"
+        );
+        assert_eq!(decimal.exp, 0);
+    }
+
+    #[test]
+    fn test_exact_f64_extremes() {
+        let decimal = Decimal::exact_f64(f64::MAX);
+        assert_eq!(decimal.exp, 309);
+        assert_eq!(&digits(&decimal)[..17], "17976931348623157");
+
+        let decimal = Decimal::exact_f64(5e-324);
+        assert_eq!(decimal.exp, -323);
+        assert_eq!(decimal.len, 751);
+        assert_eq!(&digits(&decimal)[..10], "4940656458");
+    }
+
+    #[test]
+    fn test_shortest_matches_zmij() {
+        let decimal = Decimal::shortest_f64(-2.5e-7);
+        assert_eq!(digits(&decimal), "25");
+        assert_eq!(decimal.exp, -6);
+        assert!(decimal.negative);
+
+        let decimal = Decimal::shortest_f64(1000.0);
+        assert_eq!(digits(&decimal), "1");
+        assert_eq!(decimal.exp, 4);
+
+        let decimal = Decimal::shortest_f32(0.1f32);
+        assert_eq!(digits(&decimal), "1");
+        assert_eq!(decimal.exp, 0);
+    }
+
+    #[test]
+    fn test_from_int() {
+        let decimal = Decimal::from_int(true, 1_234_000);
+        assert_eq!(digits(&decimal), "1234");
+        assert_eq!(decimal.exp, 7);
+        assert!(decimal.negative);
+
+        let decimal = Decimal::from_int(false, u128::MAX);
+        assert_eq!(decimal.exp, 39);
+
+        assert!(Decimal::from_int(false, 0).is_zero());
+    }
+
+    #[test]
+    fn test_round_half_even() {
+        let mut decimal = Decimal::exact_f64(2.5);
+        decimal.round(1);
+        assert_eq!(digits(&decimal), "2");
+
+        let mut decimal = Decimal::exact_f64(3.5);
+        decimal.round(1);
+        assert_eq!(digits(&decimal), "4");
+
+        let mut decimal = Decimal::exact_f64(9.96);
+        decimal.round(2);
+        assert_eq!(digits(&decimal), "1");
+        assert_eq!(decimal.exp, 2);
+
+        let mut decimal = Decimal::exact_f64(0.5);
+        decimal.round(0);
+        assert!(decimal.is_zero());
+
+        let mut decimal = Decimal::exact_f64(0.7);
+        decimal.round(0);
+        assert_eq!(digits(&decimal), "1");
+        assert_eq!(decimal.exp, 1);
+    }
+}
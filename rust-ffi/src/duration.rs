@@ -0,0 +1,325 @@
+// ============================================================================
+// Duration C FFI Wrappers - `1h2m3.5s`, `12.345ms`, `PT1H2M3.5S`
+// ============================================================================
+// Format i64 nanoseconds (Odin's time.Duration) in Go's compact style or as an
+// ISO 8601 duration. Compact output below one second uses the largest of ns,
+// µs and ms that fits (`850ns`, `12.345ms`); from one second up it counts
+// down from the largest unit to seconds (`1h0m5s`). ISO output omits zero
+// components (`PT1H5S`). Fractions drop trailing zeros and are rounded
+// half-to-even to at most `precision` digits before the units are chosen, so
+// 59.999s at precision 1 prints `1m0s`. Functions return the length the
+// output requires and write it only if it is <= buf_len (snprintf-style); 0
+// means the options were invalid.
+
+use crate::out::Out;
+
+/// Go's `time.Duration` style: `1h2m3.5s`, `12.345ms`, `850ns`
+pub const NUM_DURATION_COMPACT: u8 = 0;
+
+/// ISO 8601: `PT1H2M3.5S`, `P1DT2H`
+pub const NUM_DURATION_ISO8601: u8 = 1;
+
+/// Largest unit: hours (`26h3m`)
+pub const NUM_DURATION_HOURS: u8 = 0;
+
+/// Largest unit: days of 24 hours (`1d2h3m`)
+pub const NUM_DURATION_DAYS: u8 = 1;
+
+/// Largest unit: minutes (`1563m`)
+pub const NUM_DURATION_MINUTES: u8 = 2;
+
+/// Largest unit: seconds (`93784s`)
+pub const NUM_DURATION_SECONDS: u8 = 3;
+
+/// Options for num_format_duration
+///
+/// A null pointer selects compact style, hours and all fraction digits.
+#[repr(C)]
+pub struct NumDurationOptions {
+    /// NUM_DURATION_COMPACT or NUM_DURATION_ISO8601
+    pub style: u8,
+    /// One of NUM_DURATION_HOURS, _DAYS, _MINUTES, _SECONDS
+    pub largest_unit: u8,
+    /// Maximum fraction digits (0..=9), or -1 for all of them
+    pub precision: i8,
+}
+
+const MICROSECOND: u128 = 1_000;
+const MILLISECOND: u128 = 1_000_000;
+const SECOND: u128 = 1_000_000_000;
+const MINUTE: u128 = 60 * SECOND;
+const HOUR: u128 = 60 * MINUTE;
+const DAY: u128 = 24 * HOUR;
+
+/// Round `value` to a multiple of `quantum`, half-to-even
+fn round_to(value: u128, quantum: u128) -> u128 {
+    let (quotient, remainder) = (value / quantum, value % quantum);
+    let round_up = match (2 * remainder).cmp(&quantum) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => quotient % 2 == 1,
+        std::cmp::Ordering::Less => false,
+    };
+    (quotient + u128::from(round_up)) * quantum
+}
+
+/// Smallest step a fraction of `unit` keeps at `precision` digits
+fn quantum(unit: u128, precision: Option<u32>) -> u128 {
+    match precision {
+        Some(precision) => (unit / 10u128.pow(precision)).max(1),
+        None => 1,
+    }
+}
+
+/// Write `value / unit` with its fraction, trailing zeros dropped
+fn write_fraction(out: &mut Out, value: u128, unit: u128) {
+    let mut buffer = itoa::Buffer::new();
+    out.push_bytes(buffer.format(value / unit).as_bytes());
+    let mut fraction = value % unit;
+    if fraction == 0 {
+        return;
+    }
+
+    let mut width = unit.ilog10() as usize;
+    while fraction.is_multiple_of(10) {
+        fraction /= 10;
+        width -= 1;
+    }
+    out.push(b'.');
+    let digits = buffer.format(fraction).as_bytes();
+    for _ in digits.len()..width {
+        out.push(b'0');
+    }
+    out.push_bytes(digits);
+}
+
+/// Units from the largest allowed down to seconds, with their symbols
+fn units(largest: u8, iso: bool) -> &'static [(u128, u8)] {
+    const COMPACT: [(u128, u8); 4] = [(DAY, b'd'), (HOUR, b'h'), (MINUTE, b'm'), (SECOND, b's')];
+    const ISO: [(u128, u8); 4] = [(DAY, b'D'), (HOUR, b'H'), (MINUTE, b'M'), (SECOND, b'S')];
+    let table = if iso { &ISO } else { &COMPACT };
+    match largest {
+        NUM_DURATION_DAYS => &table[..],
+        NUM_DURATION_HOURS => &table[1..],
+        NUM_DURATION_MINUTES => &table[2..],
+        _ => &table[3..],
+    }
+}
+
+fn write_compact(out: &mut Out, nanos: u128, largest: u8, precision: Option<u32>) {
+    if nanos == 0 {
+        out.push_bytes(b"0s");
+        return;
+    }
+
+    // Rounding first can carry into the next unit (999.9996ms is 1s)
+    let sub_second = |nanos: u128| -> (u128, &'static [u8]) {
+        if nanos < MICROSECOND {
+            (1, b"ns")
+        } else if nanos < MILLISECOND {
+            (MICROSECOND, "µs".as_bytes())
+        } else {
+            (MILLISECOND, b"ms")
+        }
+    };
+    if nanos < SECOND {
+        let (unit, _) = sub_second(nanos);
+        let rounded = round_to(nanos, quantum(unit, precision));
+        if rounded < SECOND {
+            let (unit, symbol) = sub_second(rounded);
+            let rounded = round_to(nanos, quantum(unit, precision));
+            write_fraction(out, rounded, unit);
+            out.push_bytes(symbol);
+            return;
+        }
+    }
+
+    let mut rest = round_to(nanos, quantum(SECOND, precision));
+    if rest == 0 {
+        out.push_bytes(b"0s");
+        return;
+    }
+    let mut started = false;
+    for &(unit, symbol) in units(largest, false) {
+        if unit == SECOND {
+            write_fraction(out, rest, SECOND);
+        } else if rest >= unit || started {
+            let mut buffer = itoa::Buffer::new();
+            out.push_bytes(buffer.format(rest / unit).as_bytes());
+            rest %= unit;
+            started = true;
+        } else {
+            continue;
+        }
+        out.push(symbol);
+    }
+}
+
+fn write_iso(out: &mut Out, nanos: u128, largest: u8, precision: Option<u32>) {
+    let mut rest = round_to(nanos, quantum(SECOND, precision));
+    out.push(b'P');
+    if rest == 0 {
+        out.push_bytes(b"T0S");
+        return;
+    }
+
+    let mut buffer = itoa::Buffer::new();
+    let mut in_time = false;
+    for &(unit, symbol) in units(largest, true) {
+        let amount = if unit == SECOND { rest } else { rest / unit };
+        if amount == 0 {
+            continue;
+        }
+        if unit != DAY && !in_time {
+            out.push(b'T');
+            in_time = true;
+        }
+        if unit == SECOND {
+            write_fraction(out, rest, SECOND);
+        } else {
+            out.push_bytes(buffer.format(amount).as_bytes());
+            rest %= unit;
+        }
+        out.push(symbol);
+    }
+}
+
+/// Format nanoseconds as a duration such as `1h2m3.5s` or `PT1H2M3.5S`
+///
+/// # Safety
+/// - options must be null or point to a valid NumDurationOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if style or largest_unit is unknown or precision is outside -1..=9
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_duration(
+    nanoseconds: i64,
+    options: *const NumDurationOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let (style, largest, precision) = match unsafe { options.as_ref() } {
+        Some(options) => (options.style, options.largest_unit, options.precision),
+        None => (NUM_DURATION_COMPACT, NUM_DURATION_HOURS, -1),
+    };
+    if style > NUM_DURATION_ISO8601
+        || largest > NUM_DURATION_SECONDS
+        || !(-1..=9).contains(&precision)
+    {
+        return 0;
+    }
+    let precision = u32::try_from(precision).ok();
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if nanoseconds < 0 {
+        out.push(b'-');
+    }
+    let nanos = nanoseconds.unsigned_abs() as u128;
+    if style == NUM_DURATION_ISO8601 {
+        write_iso(&mut out, nanos, largest, precision);
+    } else {
+        write_compact(&mut out, nanos, largest, precision);
+    }
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(style: u8, largest_unit: u8, precision: i8) -> NumDurationOptions {
+        NumDurationOptions {
+            style,
+            largest_unit,
+            precision,
+        }
+    }
+
+    fn duration_test(nanoseconds: i64, options: *const NumDurationOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_format_duration(nanoseconds, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_duration failed for value: {}",
+            nanoseconds
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    const S: i64 = 1_000_000_000;
+
+    #[test]
+    fn test_duration_compact_matches_go() {
+        let go = std::ptr::null();
+        assert_eq!(duration_test(0, go), "0s");
+        assert_eq!(duration_test(850, go), "850ns");
+        assert_eq!(duration_test(1_500, go), "1.5µs");
+        assert_eq!(duration_test(12_345_000, go), "12.345ms");
+        assert_eq!(duration_test(3 * S + S / 2, go), "3.5s");
+        assert_eq!(duration_test(3723 * S + S / 2, go), "1h2m3.5s");
+        assert_eq!(duration_test(3600 * S, go), "1h0m0s");
+        assert_eq!(duration_test(-90 * S, go), "-1m30s");
+        assert_eq!(duration_test(i64::MIN, go), "-2562047h47m16.854775808s");
+    }
+
+    #[test]
+    fn test_duration_precision_rounds_before_choosing_units() {
+        let one = options(NUM_DURATION_COMPACT, NUM_DURATION_HOURS, 1);
+        assert_eq!(duration_test(59_999_000_000, &one), "1m0s");
+        assert_eq!(duration_test(12_345_678, &one), "12.3ms");
+        assert_eq!(duration_test(999_999_999, &one), "1s");
+        assert_eq!(duration_test(1_250, &one), "1.2µs");
+        let none = options(NUM_DURATION_COMPACT, NUM_DURATION_HOURS, 0);
+        assert_eq!(duration_test(3 * S + S / 2, &none), "4s");
+        assert_eq!(duration_test(400, &none), "400ns");
+    }
+
+    #[test]
+    fn test_duration_largest_unit() {
+        let value = 93_784 * S + S / 4;
+        let days = options(NUM_DURATION_COMPACT, NUM_DURATION_DAYS, -1);
+        assert_eq!(duration_test(value, &days), "1d2h3m4.25s");
+        let minutes = options(NUM_DURATION_COMPACT, NUM_DURATION_MINUTES, -1);
+        assert_eq!(duration_test(value, &minutes), "1563m4.25s");
+        let seconds = options(NUM_DURATION_COMPACT, NUM_DURATION_SECONDS, -1);
+        assert_eq!(duration_test(value, &seconds), "93784.25s");
+    }
+
+    #[test]
+    fn test_duration_iso8601() {
+        let iso = options(NUM_DURATION_ISO8601, NUM_DURATION_HOURS, -1);
+        assert_eq!(duration_test(0, &iso), "PT0S");
+        assert_eq!(duration_test(3723 * S + S / 2, &iso), "PT1H2M3.5S");
+        assert_eq!(duration_test(3605 * S, &iso), "PT1H5S");
+        assert_eq!(duration_test(7200 * S, &iso), "PT2H");
+        assert_eq!(duration_test(1_500_000, &iso), "PT0.0015S");
+        assert_eq!(duration_test(-S, &iso), "-PT1S");
+        let days = options(NUM_DURATION_ISO8601, NUM_DURATION_DAYS, -1);
+        assert_eq!(duration_test(93_600 * S, &days), "P1DT2H");
+        assert_eq!(duration_test(86_400 * S, &days), "P1D");
+    }
+
+    #[test]
+    fn test_duration_invalid_options() {
+        let mut buf = [0u8; 32];
+        for options in [
+            options(2, NUM_DURATION_HOURS, -1),
+            options(NUM_DURATION_COMPACT, 4, -1),
+            options(NUM_DURATION_COMPACT, NUM_DURATION_HOURS, 10),
+            options(NUM_DURATION_COMPACT, NUM_DURATION_HOURS, -2),
+        ] {
+            let len = unsafe { num_format_duration(S, &options, buf.as_mut_ptr(), buf.len()) };
+            assert_eq!(len, 0, "Should return 0 for invalid options");
+        }
+    }
+
+    #[test]
+    fn test_duration_reports_required_length() {
+        let len =
+            unsafe { num_format_duration(3723 * S, std::ptr::null(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1h2m3s".len(), "Should report the required length");
+    }
+}
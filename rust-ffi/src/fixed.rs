@@ -0,0 +1,372 @@
+// ============================================================================
+// Fixed-notation C FFI Wrappers - Exactly N digits after the decimal point
+// ============================================================================
+// Rounds half-to-even from the exact binary value, so the output matches
+// `format!("{:.N}", value)`. NaN and infinities use the same spellings as
+// zmij_format_f64. Functions return the number of bytes written, or 0 if the
+// buffer was too small; use zmij_fixed_buffer_size_* to size buffers.
+
+use crate::decimal::Decimal;
+use crate::out::Out;
+
+/// Largest supported precision (every digit of the smallest f64 subnormal)
+pub const ZMIJ_MAX_FIXED_PRECISION: u32 = 1074;
+
+/// Integer digits of f64::MAX
+const F64_MAX_INT_DIGITS: usize = 309;
+
+/// Integer digits of f32::MAX
+const F32_MAX_INT_DIGITS: usize = 39;
+
+/// Spelling of non-finite values, matching zmij
+pub(crate) fn non_finite_str(value: f64) -> &'static [u8] {
+    if value.is_nan() {
+        b"NaN"
+    } else if value.is_sign_negative() {
+        b"-inf"
+    } else {
+        b"inf"
+    }
+}
+
+/// Write `decimal` with exactly `precision` fractional digits
+///
+/// The decimal must already be rounded to `precision` places.
+pub(crate) fn write_fixed(out: &mut Out, decimal: &Decimal, precision: usize) {
+    if decimal.negative {
+        out.push(b'-');
+    }
+
+    if decimal.exp <= 0 || decimal.is_zero() {
+        out.push(b'0');
+    } else {
+        for i in 0..decimal.exp {
+            out.push(decimal.digit(i));
+        }
+    }
+
+    if precision > 0 {
+        out.push(b'.');
+        for i in 0..precision as i32 {
+            out.push(decimal.digit(decimal.exp + i));
+        }
+    }
+}
+
+/// Round an exact decimal to `precision` fractional digits
+pub(crate) fn round_fixed(decimal: &mut Decimal, precision: usize) {
+    decimal.round(decimal.exp + precision as i32);
+}
+
+fn format_fixed(out: &mut Out, value: f64, exact: impl FnOnce() -> Decimal, precision: usize) {
+    if !value.is_finite() {
+        out.push_bytes(non_finite_str(value));
+        return;
+    }
+
+    let mut decimal = exact();
+    round_fixed(&mut decimal, precision);
+    write_fixed(out, &decimal, precision);
+}
+
+/// Format f64 with exactly `precision` digits after the decimal point
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= zmij_fixed_buffer_size_f64(precision) for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small or
+/// precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_fixed_f64(
+    value: f64,
+    precision: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if buf.is_null() || precision > ZMIJ_MAX_FIXED_PRECISION {
+        return 0;
+    }
+
+    // Safety: buf is valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_fixed(
+        &mut out,
+        value,
+        || Decimal::exact_f64(value),
+        precision as usize,
+    );
+    out.written_or_zero()
+}
+
+/// Format f32 with exactly `precision` digits after the decimal point
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= zmij_fixed_buffer_size_f32(precision) for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small or
+/// precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_fixed_f32(
+    value: f32,
+    precision: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if buf.is_null() || precision > ZMIJ_MAX_FIXED_PRECISION {
+        return 0;
+    }
+
+    // Safety: buf is valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_fixed(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        precision as usize,
+    );
+    out.written_or_zero()
+}
+
+/// Format f64 with `precision` fractional digits, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_fixed_f64_fit(
+    value: f64,
+    precision: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if precision > ZMIJ_MAX_FIXED_PRECISION {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_fixed(
+        &mut out,
+        value,
+        || Decimal::exact_f64(value),
+        precision as usize,
+    );
+    out.len()
+}
+
+/// Format f32 with `precision` fractional digits, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_fixed_f32_fit(
+    value: f32,
+    precision: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if precision > ZMIJ_MAX_FIXED_PRECISION {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_fixed(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        precision as usize,
+    );
+    out.len()
+}
+
+/// Buffer size that fits any f64 formatted with zmij_format_fixed_f64
+///
+/// # Returns
+/// Sign + 309 integer digits + decimal point + precision
+#[unsafe(no_mangle)]
+pub extern "C" fn zmij_fixed_buffer_size_f64(precision: u32) -> usize {
+    1 + F64_MAX_INT_DIGITS + 1 + precision as usize
+}
+
+/// Buffer size that fits any f32 formatted with zmij_format_fixed_f32
+///
+/// # Returns
+/// Sign + 39 integer digits + decimal point + precision
+#[unsafe(no_mangle)]
+pub extern "C" fn zmij_fixed_buffer_size_f32(precision: u32) -> usize {
+    1 + F32_MAX_INT_DIGITS + 1 + precision as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_f64_test(value: f64, precision: u32) -> String {
+        let mut buf = vec![0u8; zmij_fixed_buffer_size_f64(precision)];
+        let len = unsafe { zmij_format_fixed_f64(value, precision, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "zmij_format_fixed_f64 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    fn fixed_f32_test(value: f32, precision: u32) -> String {
+        let mut buf = vec![0u8; zmij_fixed_buffer_size_f32(precision)];
+        let len = unsafe { zmij_format_fixed_f32(value, precision, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "zmij_format_fixed_f32 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    #[test]
+    fn test_fixed_f64_simple() {
+        assert_eq!(fixed_f64_test(1.23456, 2), "1.23");
+        assert_eq!(fixed_f64_test(-42.5, 3), "-42.500");
+        assert_eq!(fixed_f64_test(0.0, 0), "0");
+        assert_eq!(fixed_f64_test(123.456, 0), "123");
+    }
+
+    #[test]
+    fn test_fixed_f64_round_half_even() {
+        assert_eq!(fixed_f64_test(0.125, 2), "0.12");
+        assert_eq!(fixed_f64_test(0.375, 2), "0.38");
+        assert_eq!(fixed_f64_test(2.5, 0), "2");
+        assert_eq!(fixed_f64_test(3.5, 0), "4");
+        // 0.35 is stored as 0.34999999999999997779...
+        assert_eq!(fixed_f64_test(0.35, 1), "0.3");
+    }
+
+    #[test]
+    fn test_fixed_f64_carry() {
+        assert_eq!(fixed_f64_test(9.999, 2), "10.00");
+        assert_eq!(fixed_f64_test(0.0009, 3), "0.001");
+        assert_eq!(fixed_f64_test(0.0004, 3), "0.000");
+        assert_eq!(fixed_f64_test(-0.001, 2), "-0.00");
+    }
+
+    #[test]
+    fn test_fixed_f64_special_values() {
+        assert_eq!(fixed_f64_test(f64::NAN, 2), "NaN");
+        assert_eq!(fixed_f64_test(f64::INFINITY, 2), "inf");
+        assert_eq!(fixed_f64_test(f64::NEG_INFINITY, 2), "-inf");
+        assert_eq!(fixed_f64_test(-0.0, 1), "-0.0");
+    }
+
+    #[test]
+    fn test_fixed_f64_matches_std() {
+        let values = [
+            0.1,
+            1.0 / 3.0,
+            2.0 / 3.0,
+            1e-7,
+            123456.789,
+            1e22,
+            1e308,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            std::f64::consts::PI,
+            -std::f64::consts::E,
+        ];
+        for value in values {
+            for precision in [0, 1, 2, 5, 10, 17, 20, 340] {
+                assert_eq!(
+                    fixed_f64_test(value, precision),
+                    format!("{:.*}", precision as usize, value),
+                    "mismatch for {} at precision {}",
+                    value,
+                    precision
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_f32_matches_std() {
+        let values = [0.1f32, 3.4e38, 1e-45, 1.75, -1234.5];
+        for value in values {
+            for precision in [0, 1, 3, 8, 50] {
+                assert_eq!(
+                    fixed_f32_test(value, precision),
+                    format!("{:.*}", precision as usize, value)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_f64_large_output_exceeds_default_buffer() {
+        let result = fixed_f64_test(1e308, 2);
+        assert_eq!(result.len(), 312);
+        assert!(result.len() <= zmij_fixed_buffer_size_f64(2));
+
+        let mut buf = [0u8; 24];
+        let len = unsafe { zmij_format_fixed_f64(1e308, 2, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for buffer too small");
+    }
+
+    #[test]
+    fn test_fixed_f64_worst_case_fits() {
+        let result = fixed_f64_test(-f64::MAX, ZMIJ_MAX_FIXED_PRECISION);
+        assert!(result.len() <= zmij_fixed_buffer_size_f64(ZMIJ_MAX_FIXED_PRECISION));
+
+        let result = fixed_f32_test(-f32::MAX, 10);
+        assert!(result.len() <= zmij_fixed_buffer_size_f32(10));
+    }
+
+    #[test]
+    fn test_fixed_f64_precision_too_large() {
+        let mut buf = [0u8; 2048];
+        let len = unsafe {
+            zmij_format_fixed_f64(
+                1.0,
+                ZMIJ_MAX_FIXED_PRECISION + 1,
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(len, 0, "Should return 0 for unsupported precision");
+    }
+
+    #[test]
+    fn test_fixed_f64_fit_exact_length() {
+        let len = unsafe { zmij_format_fixed_f64_fit(1e308, 2, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 312);
+
+        let mut buf = [0u8; 4];
+        let len = unsafe { zmij_format_fixed_f64_fit(0.5, 2, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 4);
+        assert_eq!(&buf, b"0.50");
+
+        let len = unsafe { zmij_format_fixed_f32_fit(-0.5f32, 2, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 5, "Should report the required length");
+    }
+
+    #[test]
+    fn test_fixed_f64_null_buffer() {
+        let len = unsafe { zmij_format_fixed_f64(1.5, 2, std::ptr::null_mut(), 64) };
+        assert_eq!(len, 0, "Should return 0 for null buffer");
+    }
+
+    #[test]
+    fn test_fixed_f64_no_overflow() {
+        let mut buf = [0xAAu8; 16];
+        let len = unsafe { zmij_format_fixed_f64(1.23456, 3, buf[4..9].as_mut_ptr(), 5) };
+        assert_eq!(len, 5);
+        assert_eq!(&buf[4..9], b"1.235");
+        assert_eq!(buf[0..4], [0xAA; 4], "Buffer before output was modified");
+        assert!(
+            buf[9..16].iter().all(|&b| b == 0xAA),
+            "Buffer after output was modified"
+        );
+    }
+}
@@ -0,0 +1,754 @@
+// ============================================================================
+// Formatter handle C FFI Wrappers - Persistent formatting profiles
+// ============================================================================
+// A NumFormatter holds a decimal separator, digit grouping, a float policy
+// (NaN/infinity spellings, negative zero), notation, precision and exponent
+// style, so each subsystem can keep its own profile instead of passing every
+// option on every call. The configuration strings, including the policy's,
+// are copied, so they need not outlive the call to num_formatter_new.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style).
+
+use crate::decimal::Decimal;
+use crate::fixed::{ZMIJ_MAX_FIXED_PRECISION, round_fixed};
+use crate::grouping::{Separators, c_bytes, write_grouped_decimal, write_grouped_int};
+use crate::out::Out;
+use crate::policy::{FloatPolicy, ZmijFloatPolicy, float_policy, write_non_finite};
+use crate::scientific::{ExpStyle, write_exponent};
+use crate::significant::write_significant;
+use crate::{i128_from_halves, u128_from_halves};
+
+/// Shortest round-trip digits, positional or exponent like zmij_format_f64
+pub const NUM_NOTATION_SHORTEST: u8 = 0;
+
+/// Exactly `precision` digits after the decimal point (shortest if < 0)
+pub const NUM_NOTATION_FIXED: u8 = 1;
+
+/// Always an exponent, `precision` mantissa digits (shortest if < 0)
+pub const NUM_NOTATION_SCIENTIFIC: u8 = 2;
+
+/// `precision` significant digits like printf `%g` (6 if < 0)
+pub const NUM_NOTATION_GENERAL: u8 = 3;
+
+/// Configuration for num_formatter_new
+///
+/// A null separator is empty, as in NumGrouping, so fill in both separators.
+/// A null config pointer selects `.`, no grouping, shortest notation and a
+/// signed exponent, matching zmij_format_f64 exactly.
+#[repr(C)]
+pub struct NumFormatterConfig {
+    /// Separator between integer and fraction digits (UTF-8, null is empty)
+    pub decimal_sep: *const u8,
+    /// Length of decimal_sep in bytes
+    pub decimal_sep_len: usize,
+    /// Separator between digit groups (UTF-8, null is empty)
+    pub group_sep: *const u8,
+    /// Length of group_sep in bytes
+    pub group_sep_len: usize,
+    /// Digits in the group next to the decimal point, 0 disables grouping
+    pub primary: u8,
+    /// Digits in every further group, 0 repeats primary
+    pub secondary: u8,
+    /// NaN/infinity spellings and negative zero handling (null for zmij's)
+    pub policy: *const ZmijFloatPolicy,
+    /// One of the NUM_NOTATION_* constants
+    pub notation: u8,
+    /// Digits for the notation, or < 0 for its default
+    pub precision: i32,
+    /// Use `E` instead of `e` as the exponent marker
+    pub exp_uppercase: bool,
+    /// Always print the exponent sign (`e+7` instead of `e7`)
+    pub exp_plus_sign: bool,
+    /// Zero-pad the exponent to at least this many digits
+    pub min_exp_digits: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Shortest,
+    Fixed,
+    Scientific,
+    General,
+}
+
+/// A formatting profile created by num_formatter_new
+///
+/// Release with num_formatter_free. A formatter is immutable, so it may be
+/// shared between threads.
+pub struct NumFormatter {
+    decimal_sep: Vec<u8>,
+    group_sep: Vec<u8>,
+    primary: usize,
+    secondary: usize,
+    nan: Vec<u8>,
+    inf: Vec<u8>,
+    neg_inf: Vec<u8>,
+    normalize_negative_zero: bool,
+    notation: Notation,
+    precision: Option<usize>,
+    style: ExpStyle,
+}
+
+impl NumFormatter {
+    fn default_profile() -> Self {
+        NumFormatter {
+            decimal_sep: b".".to_vec(),
+            group_sep: b",".to_vec(),
+            primary: 0,
+            secondary: 0,
+            nan: FloatPolicy::ZMIJ.nan.to_vec(),
+            inf: FloatPolicy::ZMIJ.inf.to_vec(),
+            neg_inf: FloatPolicy::ZMIJ.neg_inf.to_vec(),
+            normalize_negative_zero: false,
+            notation: Notation::Shortest,
+            precision: None,
+            style: ExpStyle {
+                marker: b'e',
+                plus_sign: true,
+                min_digits: 1,
+            },
+        }
+    }
+
+    /// Build a formatter; None for an unknown notation or policy preset, or
+    /// too large precision
+    ///
+    /// # Safety
+    /// - the config strings must be null or valid for their lengths, and
+    ///   config.policy null or a valid ZmijFloatPolicy
+    unsafe fn from_config(config: &NumFormatterConfig) -> Option<Self> {
+        let notation = match config.notation {
+            NUM_NOTATION_SHORTEST => Notation::Shortest,
+            NUM_NOTATION_FIXED => Notation::Fixed,
+            NUM_NOTATION_SCIENTIFIC => Notation::Scientific,
+            NUM_NOTATION_GENERAL => Notation::General,
+            _ => return None,
+        };
+        if config.precision > ZMIJ_MAX_FIXED_PRECISION as i32 {
+            return None;
+        }
+        // Safety: guaranteed by the caller
+        let policy = unsafe { float_policy(config.policy) }?;
+
+        let primary = config.primary as usize;
+        let secondary = match config.secondary {
+            0 => primary,
+            secondary => secondary as usize,
+        };
+        // Safety: guaranteed by the caller
+        unsafe {
+            Some(NumFormatter {
+                decimal_sep: c_bytes(config.decimal_sep, config.decimal_sep_len).to_vec(),
+                group_sep: c_bytes(config.group_sep, config.group_sep_len).to_vec(),
+                primary,
+                secondary,
+                nan: policy.nan.to_vec(),
+                inf: policy.inf.to_vec(),
+                neg_inf: policy.neg_inf.to_vec(),
+                normalize_negative_zero: policy.normalize_negative_zero,
+                notation,
+                precision: usize::try_from(config.precision).ok(),
+                style: ExpStyle {
+                    marker: if config.exp_uppercase { b'E' } else { b'e' },
+                    plus_sign: config.exp_plus_sign,
+                    min_digits: config.min_exp_digits as usize,
+                },
+            })
+        }
+    }
+
+    fn separators(&self) -> Separators<'_> {
+        Separators {
+            group: &self.group_sep,
+            decimal: &self.decimal_sep,
+            primary: self.primary,
+            secondary: self.secondary,
+        }
+    }
+
+    fn policy(&self) -> FloatPolicy<'_> {
+        FloatPolicy {
+            nan: &self.nan,
+            inf: &self.inf,
+            neg_inf: &self.neg_inf,
+            normalize_negative_zero: self.normalize_negative_zero,
+        }
+    }
+
+    fn format_int(&self, out: &mut Out, negative: bool, magnitude: u128) {
+        write_grouped_int(out, negative, magnitude, &self.separators());
+    }
+
+    /// Format a float given its value and exact / shortest expansions
+    fn format_float(
+        &self,
+        out: &mut Out,
+        value: f64,
+        exact: impl FnOnce() -> Decimal,
+        shortest: impl FnOnce() -> Decimal,
+    ) {
+        if write_non_finite(out, value, &self.policy()) {
+            return;
+        }
+        // A normalized negative zero is formatted as positive zero
+        let unsigned = value == 0.0 && self.normalize_negative_zero;
+        let exact = || {
+            let mut decimal = exact();
+            decimal.negative &= !unsigned;
+            decimal
+        };
+        let shortest = || {
+            let mut decimal = shortest();
+            decimal.negative &= !unsigned;
+            decimal
+        };
+
+        let sep = self.separators();
+        match (self.notation, self.precision) {
+            (Notation::Shortest, _) => {
+                let decimal = shortest();
+                // Same switch to exponent notation as zmij: d.ddd * 10^exp
+                // is positional for -5 <= exp < 16
+                let exp = if decimal.is_zero() {
+                    0
+                } else {
+                    decimal.exp - 1
+                };
+                if (-5..16).contains(&exp) {
+                    let fraction = (decimal.len as i32 - decimal.exp).max(1) as usize;
+                    write_grouped_decimal(out, &decimal, Some(fraction), &sep);
+                } else {
+                    self.write_scientific(out, &decimal, None);
+                }
+            }
+            (Notation::Fixed, None) => write_grouped_decimal(out, &shortest(), None, &sep),
+            (Notation::Fixed, Some(precision)) => {
+                let mut decimal = exact();
+                round_fixed(&mut decimal, precision);
+                write_grouped_decimal(out, &decimal, Some(precision), &sep);
+            }
+            (Notation::Scientific, None) => self.write_scientific(out, &shortest(), None),
+            (Notation::Scientific, Some(precision)) => {
+                let mut decimal = exact();
+                decimal.round(precision as i32 + 1);
+                self.write_scientific(out, &decimal, Some(precision));
+            }
+            (Notation::General, precision) => {
+                let digits = precision.unwrap_or(6) as u32;
+                write_significant(out, exact(), digits, false, &sep, self.style);
+            }
+        }
+    }
+
+    /// `d.ddd` with this formatter's decimal separator, then the exponent
+    fn write_scientific(&self, out: &mut Out, decimal: &Decimal, precision: Option<usize>) {
+        if decimal.negative {
+            out.push(b'-');
+        }
+        let fraction = precision.unwrap_or(decimal.len.saturating_sub(1));
+        out.push(decimal.digit(0));
+        if fraction > 0 {
+            out.push_bytes(&self.decimal_sep);
+            for i in 1..=fraction as i32 {
+                out.push(decimal.digit(i));
+            }
+        }
+        let exp = if decimal.is_zero() {
+            0
+        } else {
+            decimal.exp - 1
+        };
+        write_exponent(out, exp, self.style);
+    }
+}
+
+/// Run `format` with the formatter (null = defaults), returning the length
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn with_formatter(
+    formatter: *const NumFormatter,
+    buf: *mut u8,
+    buf_len: usize,
+    format: impl FnOnce(&NumFormatter, &mut Out),
+) -> usize {
+    // Safety: guaranteed by the caller
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    // Safety: guaranteed by the caller
+    match unsafe { formatter.as_ref() } {
+        Some(formatter) => format(formatter, &mut out),
+        None => format(&NumFormatter::default_profile(), &mut out),
+    }
+    out.len()
+}
+
+/// Create a formatter from a configuration (null = all defaults)
+///
+/// # Safety
+/// - config must be null or point to a valid NumFormatterConfig whose
+///   strings are null or valid for their lengths
+///
+/// # Returns
+/// A handle to release with num_formatter_free, or null if notation or the
+/// policy preset is unknown or precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_new(config: *const NumFormatterConfig) -> *mut NumFormatter {
+    // Safety: guaranteed by the caller
+    let formatter = match unsafe { config.as_ref() } {
+        Some(config) => unsafe { NumFormatter::from_config(config) },
+        None => Some(NumFormatter::default_profile()),
+    };
+    match formatter {
+        Some(formatter) => Box::into_raw(Box::new(formatter)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Release a formatter returned by num_formatter_new (null is ignored)
+///
+/// # Safety
+/// - formatter must be null or a pointer returned by num_formatter_new, not yet freed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_free(formatter: *mut NumFormatter) {
+    if !formatter.is_null() {
+        // Safety: formatter came from Box::into_raw in num_formatter_new
+        drop(unsafe { Box::from_raw(formatter) });
+    }
+}
+
+/// Format f64 with a formatter's profile (null = defaults)
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_f64(
+    formatter: *const NumFormatter,
+    value: f64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_float(
+                out,
+                value,
+                || Decimal::exact_f64(value),
+                || Decimal::shortest_f64(value),
+            )
+        })
+    }
+}
+
+/// Format f32 with a formatter's profile (null = defaults)
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_f32(
+    formatter: *const NumFormatter,
+    value: f32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_float(
+                out,
+                value as f64,
+                || Decimal::exact_f32(value),
+                || Decimal::shortest_f32(value),
+            )
+        })
+    }
+}
+
+/// Format i64 with a formatter's grouping (null = defaults)
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_i64(
+    formatter: *const NumFormatter,
+    value: i64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_int(out, value < 0, value.unsigned_abs() as u128)
+        })
+    }
+}
+
+/// Format u64 with a formatter's grouping (null = defaults)
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_u64(
+    formatter: *const NumFormatter,
+    value: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_int(out, false, value as u128)
+        })
+    }
+}
+
+/// Format i128, passed as `(hi << 64) | lo`, with a formatter's grouping
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_i128(
+    formatter: *const NumFormatter,
+    hi: i64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let value = i128_from_halves(hi, lo);
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_int(out, value < 0, value.unsigned_abs())
+        })
+    }
+}
+
+/// Format u128, passed as `(hi << 64) | lo`, with a formatter's grouping
+///
+/// # Safety
+/// - formatter must be null or a live pointer returned by num_formatter_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_formatter_u128(
+    formatter: *const NumFormatter,
+    hi: u64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let value = u128_from_halves(hi, lo);
+    // Safety: guaranteed by the caller
+    unsafe {
+        with_formatter(formatter, buf, buf_len, |formatter, out| {
+            formatter.format_int(out, false, value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grouping::{NumGrouping, num_format_grouped_f64};
+    use crate::policy::{ZMIJ_NON_FINITE_JS, ZMIJ_NON_FINITE_ZMIJ};
+
+    fn config() -> NumFormatterConfig {
+        NumFormatterConfig {
+            decimal_sep: ".".as_ptr(),
+            decimal_sep_len: 1,
+            group_sep: ",".as_ptr(),
+            group_sep_len: 1,
+            primary: 0,
+            secondary: 0,
+            policy: std::ptr::null(),
+            notation: NUM_NOTATION_SHORTEST,
+            precision: -1,
+            exp_uppercase: false,
+            exp_plus_sign: false,
+            min_exp_digits: 1,
+        }
+    }
+
+    fn formatter_f64_test(formatter: *const NumFormatter, value: f64) -> String {
+        let mut buf = [0u8; 512];
+        let len = unsafe { num_formatter_f64(formatter, value, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_formatter_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn formatter_i64_test(formatter: *const NumFormatter, value: i64) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_formatter_i64(formatter, value, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_formatter_i64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_formatter_defaults_match_zmij() {
+        let formatter = unsafe { num_formatter_new(std::ptr::null()) };
+        assert!(!formatter.is_null());
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            0.1,
+            -1.5,
+            1e15,
+            1e16,
+            1.5e16,
+            1e-5,
+            1.5e-7,
+            1e21,
+            f64::MAX,
+            5e-324,
+        ];
+        let mut zmij = zmij::Buffer::new();
+        for value in values {
+            assert_eq!(
+                formatter_f64_test(formatter, value),
+                zmij.format(value),
+                "value {}",
+                value
+            );
+        }
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_european_profile() {
+        let mut cfg = config();
+        cfg.decimal_sep = ",".as_ptr();
+        cfg.decimal_sep_len = 1;
+        cfg.group_sep = ".".as_ptr();
+        cfg.group_sep_len = 1;
+        cfg.primary = 3;
+        cfg.notation = NUM_NOTATION_FIXED;
+        cfg.precision = 2;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+
+        assert_eq!(formatter_f64_test(formatter, 1234567.891), "1.234.567,89");
+        assert_eq!(formatter_f64_test(formatter, -0.004), "-0,00");
+        assert_eq!(formatter_i64_test(formatter, -9876543), "-9.876.543");
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_scientific_style() {
+        let mut cfg = config();
+        cfg.notation = NUM_NOTATION_SCIENTIFIC;
+        cfg.precision = 3;
+        cfg.exp_uppercase = true;
+        cfg.exp_plus_sign = true;
+        cfg.min_exp_digits = 2;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, 1234.5678), "1.235E+03");
+        assert_eq!(formatter_f64_test(formatter, 0.0), "0.000E+00");
+        unsafe { num_formatter_free(formatter) };
+
+        cfg.precision = -1;
+        cfg.decimal_sep = ",".as_ptr();
+        cfg.decimal_sep_len = 1;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, 1.25e-7), "1,25E-07");
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_general_notation() {
+        let mut cfg = config();
+        cfg.notation = NUM_NOTATION_GENERAL;
+        cfg.precision = 4;
+        cfg.exp_plus_sign = true;
+        cfg.min_exp_digits = 2;
+        cfg.decimal_sep = ",".as_ptr();
+        cfg.decimal_sep_len = 1;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, std::f64::consts::PI), "3,142");
+        assert_eq!(formatter_f64_test(formatter, 1.23456e20), "1,235e+20");
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_non_finite_spellings() {
+        let nan = String::from("n/a");
+        let policy = ZmijFloatPolicy {
+            preset: ZMIJ_NON_FINITE_JS,
+            normalize_negative_zero: false,
+            nan: nan.as_ptr(),
+            nan_len: nan.len(),
+            inf: "∞".as_ptr(),
+            inf_len: "∞".len(),
+            neg_inf: std::ptr::null(),
+            neg_inf_len: 0,
+        };
+        let mut cfg = config();
+        cfg.policy = &policy;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        drop(nan);
+        assert_eq!(formatter_f64_test(formatter, f64::NAN), "n/a");
+        assert_eq!(
+            formatter_f64_test(formatter, f64::NEG_INFINITY),
+            "-Infinity"
+        );
+
+        let mut buf = [0u8; 16];
+        let len =
+            unsafe { num_formatter_f32(formatter, f32::INFINITY, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], "∞".as_bytes());
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_normalizes_negative_zero() {
+        let policy = ZmijFloatPolicy {
+            preset: ZMIJ_NON_FINITE_ZMIJ,
+            normalize_negative_zero: true,
+            nan: std::ptr::null(),
+            nan_len: 0,
+            inf: std::ptr::null(),
+            inf_len: 0,
+            neg_inf: std::ptr::null(),
+            neg_inf_len: 0,
+        };
+        let mut cfg = config();
+        cfg.policy = &policy;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, -0.0), "0.0");
+        assert_eq!(formatter_f64_test(formatter, -1e-300), "-1e-300");
+        unsafe { num_formatter_free(formatter) };
+
+        cfg.notation = NUM_NOTATION_FIXED;
+        cfg.precision = 2;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, -0.0), "0.00");
+        assert_eq!(formatter_f64_test(formatter, -0.004), "-0.00");
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_strings_are_copied() {
+        let mut cfg = config();
+        let sep = String::from(" ");
+        cfg.group_sep = sep.as_ptr();
+        cfg.group_sep_len = sep.len();
+        cfg.primary = 3;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        drop(sep);
+
+        let mut buf = [0u8; 64];
+        let len =
+            unsafe { num_formatter_u128(formatter, 0, 1_000_000, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"1 000 000");
+        let len =
+            unsafe { num_formatter_i128(formatter, -1, u64::MAX, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-1");
+        let len = unsafe { num_formatter_u64(formatter, 1234, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"1 234");
+        unsafe { num_formatter_free(formatter) };
+    }
+
+    #[test]
+    fn test_formatter_null_separators_are_empty_like_grouping() {
+        let mut cfg = config();
+        cfg.decimal_sep = std::ptr::null();
+        cfg.group_sep = std::ptr::null();
+        cfg.primary = 3;
+        cfg.notation = NUM_NOTATION_FIXED;
+        cfg.precision = 1;
+        let formatter = unsafe { num_formatter_new(&cfg) };
+        assert_eq!(formatter_f64_test(formatter, 1234567.25), "12345672");
+        unsafe { num_formatter_free(formatter) };
+
+        let grouping = NumGrouping {
+            group_sep: std::ptr::null(),
+            group_sep_len: 0,
+            decimal_sep: std::ptr::null(),
+            decimal_sep_len: 0,
+            primary: 3,
+            secondary: 0,
+        };
+        let mut buf = [0u8; 32];
+        let len = unsafe {
+            num_format_grouped_f64(1234567.25, 1, &grouping, buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"12345672");
+    }
+
+    #[test]
+    fn test_formatter_invalid_config() {
+        let mut cfg = config();
+        cfg.notation = 4;
+        assert!(
+            unsafe { num_formatter_new(&cfg) }.is_null(),
+            "Should reject unknown notation"
+        );
+        cfg.notation = NUM_NOTATION_FIXED;
+        cfg.precision = 1075;
+        assert!(
+            unsafe { num_formatter_new(&cfg) }.is_null(),
+            "Should reject too large precision"
+        );
+        cfg.precision = -1;
+        let bad_policy = ZmijFloatPolicy {
+            preset: 5,
+            normalize_negative_zero: false,
+            nan: std::ptr::null(),
+            nan_len: 0,
+            inf: std::ptr::null(),
+            inf_len: 0,
+            neg_inf: std::ptr::null(),
+            neg_inf_len: 0,
+        };
+        cfg.policy = &bad_policy;
+        assert!(
+            unsafe { num_formatter_new(&cfg) }.is_null(),
+            "Should reject unknown policy preset"
+        );
+        unsafe { num_formatter_free(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_formatter_null_handle_uses_defaults() {
+        assert_eq!(formatter_f64_test(std::ptr::null(), 2.5), "2.5");
+        assert_eq!(formatter_i64_test(std::ptr::null(), 1234567), "1234567");
+    }
+
+    #[test]
+    fn test_formatter_reports_required_length() {
+        let formatter = unsafe { num_formatter_new(std::ptr::null()) };
+        let len = unsafe { num_formatter_f64(formatter, 0.125, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 5, "Should report the required length");
+        unsafe { num_formatter_free(formatter) };
+    }
+}
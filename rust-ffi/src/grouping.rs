@@ -0,0 +1,564 @@
+// ============================================================================
+// Grouped C FFI Wrappers - Thousands separators, e.g. `12,345,678.9`
+// ============================================================================
+// Integers and floats with caller supplied group and decimal separators
+// (any UTF-8, e.g. U+202F NARROW NO-BREAK SPACE) and grouping pattern: 3-3-3
+// for `1,234,567`, or 3-2-2 for Indian style `12,34,567`. Floats are always
+// printed positionally, never with an exponent. Functions return the length
+// the output requires and write it only if it is <= buf_len (snprintf-style).
+
+use std::slice;
+
+use crate::decimal::Decimal;
+use crate::fixed::{ZMIJ_MAX_FIXED_PRECISION, non_finite_str, round_fixed};
+use crate::out::Out;
+use crate::{i128_from_halves, u128_from_halves};
+
+/// Separators and grouping pattern for the grouped formatters
+///
+/// A null pointer behaves like `{ ",", ".", 3, 3 }`, e.g. `1,234,567.5`. A
+/// null separator inside the struct is empty, as in NumFormatterConfig.
+#[repr(C)]
+pub struct NumGrouping {
+    /// Separator between digit groups (UTF-8, null is empty)
+    pub group_sep: *const u8,
+    /// Length of group_sep in bytes
+    pub group_sep_len: usize,
+    /// Separator between the integer and fraction digits (UTF-8, null is empty)
+    pub decimal_sep: *const u8,
+    /// Length of decimal_sep in bytes
+    pub decimal_sep_len: usize,
+    /// Digits in the group next to the decimal point, 0 disables grouping
+    pub primary: u8,
+    /// Digits in every further group, 0 repeats primary (2 for Indian style)
+    pub secondary: u8,
+}
+
+/// Resolved separators, shared by every formatter that groups digits
+#[derive(Clone, Copy)]
+pub(crate) struct Separators<'a> {
+    pub(crate) group: &'a [u8],
+    pub(crate) decimal: &'a [u8],
+    pub(crate) primary: usize,
+    pub(crate) secondary: usize,
+}
+
+impl Separators<'static> {
+    pub(crate) const DEFAULT: Self = Separators {
+        group: b",",
+        decimal: b".",
+        primary: 3,
+        secondary: 3,
+    };
+
+    /// Plain `.` decimal point without grouping
+    pub(crate) const NONE: Self = Separators {
+        group: b"",
+        decimal: b".",
+        primary: 0,
+        secondary: 0,
+    };
+}
+
+/// Borrow a possibly null C string of `len` bytes
+///
+/// # Safety
+/// - ptr must be null or valid for reads of len bytes for 'a
+pub(crate) unsafe fn c_bytes<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(ptr, len) }
+    }
+}
+
+/// Resolve a possibly null grouping pointer
+///
+/// # Safety
+/// - grouping must be null or point to a valid NumGrouping whose separators
+///   are valid for their lengths
+pub(crate) unsafe fn separators<'a>(grouping: *const NumGrouping) -> Separators<'a> {
+    // Safety: guaranteed by the caller
+    let Some(grouping) = (unsafe { grouping.as_ref() }) else {
+        return Separators::DEFAULT;
+    };
+    let primary = grouping.primary as usize;
+    let secondary = match grouping.secondary {
+        0 => primary,
+        secondary => secondary as usize,
+    };
+    Separators {
+        // Safety: guaranteed by the caller
+        group: unsafe { c_bytes(grouping.group_sep, grouping.group_sep_len) },
+        decimal: unsafe { c_bytes(grouping.decimal_sep, grouping.decimal_sep_len) },
+        primary,
+        secondary,
+    }
+}
+
+/// Write `count` integer digits, inserting group separators
+pub(crate) fn write_grouped(
+    out: &mut Out,
+    count: usize,
+    digit: impl Fn(usize) -> u8,
+    sep: &Separators,
+) {
+    for i in 0..count {
+        let remaining = count - i;
+        if i > 0 && sep.primary > 0 && remaining >= sep.primary {
+            let past_primary = remaining - sep.primary;
+            if past_primary == 0
+                || (sep.secondary > 0 && past_primary.is_multiple_of(sep.secondary))
+            {
+                out.push_bytes(sep.group);
+            }
+        }
+        out.push(digit(i));
+    }
+}
+
+/// Write an integer given as sign and magnitude with grouped digits
+pub(crate) fn write_grouped_int(out: &mut Out, negative: bool, magnitude: u128, sep: &Separators) {
+    let mut buffer = itoa::Buffer::new();
+    let digits = buffer.format(magnitude).as_bytes();
+    if negative {
+        out.push(b'-');
+    }
+    write_grouped(out, digits.len(), |i| digits[i], sep);
+}
+
+/// Write `decimal` positionally with grouped integer digits
+///
+/// With `Some(precision)` the decimal must already be rounded to that many
+/// places and exactly that many fraction digits are printed. With `None` all
+/// digits are printed (shortest output).
+pub(crate) fn write_grouped_decimal(
+    out: &mut Out,
+    decimal: &Decimal,
+    precision: Option<usize>,
+    sep: &Separators,
+) {
+    if decimal.negative {
+        out.push(b'-');
+    }
+
+    if decimal.exp <= 0 || decimal.is_zero() {
+        out.push(b'0');
+    } else {
+        write_grouped(out, decimal.exp as usize, |i| decimal.digit(i as i32), sep);
+    }
+
+    let fraction_digits = match precision {
+        Some(precision) => precision,
+        None => (decimal.len as i32 - decimal.exp).max(0) as usize,
+    };
+    if fraction_digits > 0 {
+        out.push_bytes(sep.decimal);
+        for i in 0..fraction_digits as i32 {
+            out.push(decimal.digit(decimal.exp + i));
+        }
+    }
+}
+
+fn format_grouped_float(
+    out: &mut Out,
+    value: f64,
+    exact: impl FnOnce() -> Decimal,
+    shortest: impl FnOnce() -> Decimal,
+    precision: i32,
+    sep: &Separators,
+) {
+    if !value.is_finite() {
+        out.push_bytes(non_finite_str(value));
+        return;
+    }
+
+    if precision < 0 {
+        // At least one fraction digit, like zmij: `1,234.0`, `-0.0`
+        let decimal = shortest();
+        let fraction = (decimal.len as i32 - decimal.exp).max(1) as usize;
+        write_grouped_decimal(out, &decimal, Some(fraction), sep);
+    } else {
+        let precision = precision as usize;
+        let mut decimal = exact();
+        round_fixed(&mut decimal, precision);
+        write_grouped_decimal(out, &decimal, Some(precision), sep);
+    }
+}
+
+/// Format i64 integer with digit grouping
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_i64(
+    value: i64,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_grouped_int(&mut out, value < 0, value.unsigned_abs() as u128, &sep);
+    out.len()
+}
+
+/// Format u64 integer with digit grouping
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_u64(
+    value: u64,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_grouped_int(&mut out, false, value as u128, &sep);
+    out.len()
+}
+
+/// Format i128 integer, passed as `(hi << 64) | lo`, with digit grouping
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_i128(
+    hi: i64,
+    lo: u64,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let value = i128_from_halves(hi, lo);
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_grouped_int(&mut out, value < 0, value.unsigned_abs(), &sep);
+    out.len()
+}
+
+/// Format u128 integer, passed as `(hi << 64) | lo`, with digit grouping
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_u128(
+    hi: u64,
+    lo: u64,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_grouped_int(&mut out, false, u128_from_halves(hi, lo), &sep);
+    out.len()
+}
+
+/// Format f64 positionally with digit grouping
+///
+/// precision < 0 prints the shortest round-trip digits with at least one
+/// fraction digit (`1,234.0`), otherwise exactly `precision` fraction digits
+/// rounded half-to-even.
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_f64(
+    value: f64,
+    precision: i32,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if precision > ZMIJ_MAX_FIXED_PRECISION as i32 {
+        return 0;
+    }
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_grouped_float(
+        &mut out,
+        value,
+        || Decimal::exact_f64(value),
+        || Decimal::shortest_f64(value),
+        precision,
+        &sep,
+    );
+    out.len()
+}
+
+/// Format f32 positionally with digit grouping
+///
+/// precision < 0 prints the shortest round-trip digits with at least one
+/// fraction digit (`1,234.0`), otherwise exactly `precision` fraction digits
+/// rounded half-to-even.
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - grouping must be null or point to a valid NumGrouping
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_grouped_f32(
+    value: f32,
+    precision: i32,
+    grouping: *const NumGrouping,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if precision > ZMIJ_MAX_FIXED_PRECISION as i32 {
+        return 0;
+    }
+    // Safety: grouping is null or valid
+    let sep = unsafe { separators(grouping) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_grouped_float(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        || Decimal::shortest_f32(value),
+        precision,
+        &sep,
+    );
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grouping(group: &str, decimal: &str, primary: u8, secondary: u8) -> NumGrouping {
+        NumGrouping {
+            group_sep: group.as_ptr(),
+            group_sep_len: group.len(),
+            decimal_sep: decimal.as_ptr(),
+            decimal_sep_len: decimal.len(),
+            primary,
+            secondary,
+        }
+    }
+
+    fn grouped_i64_test(value: i64, grouping: *const NumGrouping) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { num_format_grouped_i64(value, grouping, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_grouped_i64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn grouped_f64_test(value: f64, precision: i32, grouping: *const NumGrouping) -> String {
+        let mut buf = [0u8; 512];
+        let len = unsafe {
+            num_format_grouped_f64(value, precision, grouping, buf.as_mut_ptr(), buf.len())
+        };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_grouped_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_grouped_i64_default() {
+        let null = std::ptr::null();
+        assert_eq!(grouped_i64_test(0, null), "0");
+        assert_eq!(grouped_i64_test(999, null), "999");
+        assert_eq!(grouped_i64_test(1000, null), "1,000");
+        assert_eq!(grouped_i64_test(12345678, null), "12,345,678");
+        assert_eq!(grouped_i64_test(-123456, null), "-123,456");
+        assert_eq!(
+            grouped_i64_test(i64::MIN, null),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+
+    #[test]
+    fn test_grouped_indian_style() {
+        let indian = grouping(",", ".", 3, 2);
+        assert_eq!(grouped_i64_test(1234567, &indian), "12,34,567");
+        assert_eq!(grouped_i64_test(123456789, &indian), "12,34,56,789");
+        assert_eq!(grouped_i64_test(1000, &indian), "1,000");
+        assert_eq!(grouped_i64_test(100, &indian), "100");
+        assert_eq!(grouped_f64_test(1234567.891, 2, &indian), "12,34,567.89");
+    }
+
+    #[test]
+    fn test_grouped_multibyte_separators() {
+        let french = grouping("\u{202F}", ",", 3, 0);
+        assert_eq!(
+            grouped_i64_test(1234567, &french),
+            "1\u{202F}234\u{202F}567"
+        );
+        assert_eq!(
+            grouped_f64_test(1234567.89, 2, &french),
+            "1\u{202F}234\u{202F}567,89"
+        );
+        let swiss = grouping("'", ".", 3, 3);
+        assert_eq!(grouped_f64_test(-9876543.25, -1, &swiss), "-9'876'543.25");
+    }
+
+    #[test]
+    fn test_grouped_disabled_and_empty() {
+        let none = grouping(",", ".", 0, 0);
+        assert_eq!(grouped_i64_test(1234567, &none), "1234567");
+        let empty = grouping("", "", 3, 3);
+        assert_eq!(grouped_f64_test(1234.5, 1, &empty), "12345");
+        let fours = grouping("_", ".", 4, 0);
+        assert_eq!(grouped_i64_test(123456789, &fours), "1_2345_6789");
+    }
+
+    #[test]
+    fn test_grouped_f64_shortest_is_positional() {
+        let null = std::ptr::null();
+        assert_eq!(
+            grouped_f64_test(1e20, -1, null),
+            "100,000,000,000,000,000,000.0"
+        );
+        assert_eq!(grouped_f64_test(0.000125, -1, null), "0.000125");
+        assert_eq!(grouped_f64_test(1234.5, -1, null), "1,234.5");
+        assert_eq!(grouped_f64_test(0.0, -1, null), "0.0");
+        assert_eq!(grouped_f64_test(-0.0, -1, null), "-0.0");
+    }
+
+    #[test]
+    fn test_grouped_f64_shortest_matches_zmij_when_ungrouped() {
+        let none = grouping("", ".", 0, 0);
+        let mut zmij = zmij::Buffer::new();
+        for value in [0.0, -0.0, 1.0, -1234.5, 0.000125, 0.1 + 0.2, 1e15] {
+            assert_eq!(
+                grouped_f64_test(value, -1, &none),
+                zmij.format(value),
+                "value {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_grouped_f64_precision_matches_fixed() {
+        let none = grouping("", ".", 0, 0);
+        for value in [0.0, 0.5, 2.5, 1234.5678, 1e15, 1.0 / 3.0, -7.125] {
+            for precision in [0, 1, 2, 6] {
+                assert_eq!(
+                    grouped_f64_test(value, precision, &none),
+                    format!("{:.*}", precision as usize, value)
+                );
+            }
+        }
+        assert_eq!(grouped_f64_test(999.996, 2, std::ptr::null()), "1,000.00");
+        assert_eq!(
+            grouped_f64_test(999999.996, 2, std::ptr::null()),
+            "1,000,000.00"
+        );
+    }
+
+    #[test]
+    fn test_grouped_f64_special_values() {
+        let null = std::ptr::null();
+        assert_eq!(grouped_f64_test(f64::NAN, 2, null), "NaN");
+        assert_eq!(grouped_f64_test(f64::NEG_INFINITY, -1, null), "-inf");
+    }
+
+    #[test]
+    fn test_grouped_f32_and_128() {
+        let mut buf = [0u8; 64];
+        let len = unsafe {
+            num_format_grouped_f32(
+                16777216.0,
+                -1,
+                std::ptr::null(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(&buf[..len], b"16,777,216.0");
+
+        let len = unsafe {
+            num_format_grouped_u128(
+                u64::MAX,
+                u64::MAX,
+                std::ptr::null(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(
+            &buf[..len],
+            b"340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+
+        let len = unsafe {
+            num_format_grouped_i128(-1, 0, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"-18,446,744,073,709,551,616");
+
+        let len = unsafe {
+            num_format_grouped_u64(1_000_000, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"1,000,000");
+    }
+
+    #[test]
+    fn test_grouped_reports_required_length() {
+        let len =
+            unsafe { num_format_grouped_i64(1234567, std::ptr::null(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1,234,567".len());
+
+        let mut buf = [0xAAu8; 8];
+        let len =
+            unsafe { num_format_grouped_i64(1234567, std::ptr::null(), buf[1..5].as_mut_ptr(), 4) };
+        assert_eq!(len, 9, "Should report the required length");
+        assert_eq!(buf[0], 0xAA, "Buffer before output was modified");
+        assert_eq!(buf[5..8], [0xAA; 3], "Buffer after output was modified");
+    }
+
+    #[test]
+    fn test_grouped_precision_too_large() {
+        let mut buf = [0u8; 16];
+        let len = unsafe {
+            num_format_grouped_f64(1.0, 1075, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(len, 0, "Should return 0 for precision above the maximum");
+    }
+}
@@ -0,0 +1,514 @@
+// ============================================================================
+// C header generation and ABI checks
+// ============================================================================
+// include/num_format.h is generated from the `#[unsafe(no_mangle)]` functions,
+// `#[repr(C)]` structs and `pub const`s in src/. The tests below fail when the
+// committed header is stale, or when a foreign proc in num_format.odin does
+// not match the exported C signature.
+//
+// Regenerate with: NUM_FORMAT_BLESS=1 cargo test header
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const HEADER_PATH: &str = "include/num_format.h";
+const ODIN_BINDINGS_PATH: &str = "../num_format.odin";
+
+const PREAMBLE: &str = "\
+/*
+ * num_format C FFI Interface
+ *
+ * Fast number <-> string conversion (zmij + itoa) for C and Odin.
+ *
+ * GENERATED from rust-ffi/src by `NUM_FORMAT_BLESS=1 cargo test header`.
+ * Do not edit by hand.
+ *
+ * All lengths and return values use size_t, matching Rust's usize.
+ * Output is UTF-8 without a null terminator.
+ */
+
+#ifndef NUM_FORMAT_FFI_H
+#define NUM_FORMAT_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+";
+
+const POSTAMBLE: &str = "
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NUM_FORMAT_FFI_H */
+";
+
+/// An exported C function signature
+#[derive(Debug, PartialEq)]
+struct CFunction {
+    name: String,
+    params: Vec<(String, String)>,
+    ret: String,
+}
+
+/// Non-test source files in the order they are declared in lib.rs
+fn source_files(src_dir: &Path) -> Vec<PathBuf> {
+    let lib = src_dir.join("lib.rs");
+    let text = fs::read_to_string(&lib).expect("read lib.rs");
+    let mut files = vec![lib];
+    let mut test_only = false;
+    for line in text.lines() {
+        let module = line
+            .strip_prefix("mod ")
+            .or_else(|| line.strip_prefix("pub mod "));
+        if let Some(module) = module.and_then(|m| m.strip_suffix(';'))
+            && !test_only
+        {
+            files.push(src_dir.join(format!("{module}.rs")));
+        }
+        test_only = line == "#[cfg(test)]";
+    }
+    files
+}
+
+/// Map a Rust FFI type to its C spelling
+fn c_type(rust: &str) -> String {
+    let rust = rust.trim();
+    if let Some(inner) = rust.strip_prefix("*const ") {
+        let inner = c_type(inner);
+        return if inner.ends_with('*') {
+            format!("{inner}const *")
+        } else {
+            format!("const {inner} *")
+        };
+    }
+    if let Some(inner) = rust.strip_prefix("*mut ") {
+        let inner = c_type(inner);
+        return if inner.ends_with('*') {
+            format!("{inner}*")
+        } else {
+            format!("{inner} *")
+        };
+    }
+    match rust {
+        "f64" => "double",
+        "f32" => "float",
+        "usize" => "size_t",
+        "isize" => "ptrdiff_t",
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "i8" => "int8_t",
+        "u16" => "uint16_t",
+        "i16" => "int16_t",
+        "u32" => "uint32_t",
+        "i32" => "int32_t",
+        "u64" => "uint64_t",
+        "i64" => "int64_t",
+        "()" | "" => "void",
+        other => other,
+    }
+    .to_string()
+}
+
+/// C type of a struct field; structs declared further down the header are
+/// named through their tag (`const struct NumGrouping *`), which C accepts
+/// before the typedef
+fn field_type(rust: &str, declared: &[String]) -> String {
+    let ty = c_type(rust);
+    let pointee = ty.trim_start_matches("const ").trim_end_matches([' ', '*']);
+    let is_struct = pointee.starts_with(|c: char| c.is_ascii_uppercase());
+    if is_struct && !declared.iter().any(|name| name == pointee) {
+        ty.replacen(pointee, &format!("struct {pointee}"), 1)
+    } else {
+        ty
+    }
+}
+
+/// `type name` with pointer stars attached to the name
+fn c_decl(ty: &str, name: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{ty}{name}")
+    } else {
+        format!("{ty} {name}")
+    }
+}
+
+fn parse_function(signature: &str) -> CFunction {
+    let after_fn = signature.split_once("fn ").expect("fn keyword").1;
+    let (name, rest) = after_fn.split_once('(').expect("parameter list");
+    let (params, rest) = rest.rsplit_once(')').expect("parameter list end");
+    let ret = rest
+        .split_once("->")
+        .map(|(_, ret)| ret.trim_end_matches('{').trim().to_string())
+        .unwrap_or_default();
+
+    let params = params
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (name, ty) = p.split_once(':').expect("typed parameter");
+            (name.trim().to_string(), ty.trim().to_string())
+        })
+        .collect();
+
+    CFunction {
+        name: name.trim().to_string(),
+        params,
+        ret,
+    }
+}
+
+fn write_doc(header: &mut String, doc: &[String], indent: &str) {
+    if doc.is_empty() {
+        return;
+    }
+    if doc.len() == 1 {
+        header.push_str(&format!("{indent}/** {} */\n", doc[0]));
+        return;
+    }
+    header.push_str(&format!("{indent}/**\n"));
+    for line in doc {
+        if line.is_empty() {
+            header.push_str(&format!("{indent} *\n"));
+        } else {
+            header.push_str(&format!("{indent} * {line}\n"));
+        }
+    }
+    header.push_str(&format!("{indent} */\n"));
+}
+
+/// Generate the header text and the list of exported functions
+fn generate(src_dir: &Path) -> (String, Vec<CFunction>) {
+    let mut header = String::from(PREAMBLE);
+    let mut functions = Vec::new();
+    let mut declared = Vec::new();
+
+    for file in source_files(src_dir) {
+        let text = fs::read_to_string(&file).expect("read source file");
+        let lines: Vec<&str> = text.lines().collect();
+        let mut doc: Vec<String> = Vec::new();
+        let mut no_mangle = false;
+        let mut repr_c = false;
+        let mut banners = String::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i];
+
+            // Only top-level items are exported; stop at the test module
+            if line == "mod tests {" {
+                break;
+            }
+
+            if line.starts_with("// ====") {
+                // Section banner: kept until the next exported item, so
+                // banners of internal modules never reach the header
+                banners.push('\n');
+                while i < lines.len() && lines[i].starts_with("//") {
+                    banners.push_str(lines[i]);
+                    banners.push('\n');
+                    i += 1;
+                }
+                continue;
+            }
+
+            let exported_item = (no_mangle
+                && (line.starts_with("pub unsafe extern") || line.starts_with("pub extern")))
+                || line.starts_with("pub struct ")
+                || line.starts_with("pub const ");
+            if exported_item {
+                header.push_str(&banners);
+                banners.clear();
+            }
+
+            if let Some(text) = line.strip_prefix("///") {
+                doc.push(text.strip_prefix(' ').unwrap_or(text).to_string());
+            } else if line == "#[unsafe(no_mangle)]" {
+                no_mangle = true;
+            } else if line == "#[repr(C)]" {
+                repr_c = true;
+            } else if no_mangle
+                && (line.starts_with("pub unsafe extern") || line.starts_with("pub extern"))
+            {
+                let mut signature = String::new();
+                while !lines[i].ends_with('{') {
+                    signature.push_str(lines[i].trim());
+                    signature.push(' ');
+                    i += 1;
+                }
+                signature.push_str(lines[i].trim());
+
+                let function = parse_function(&signature);
+                let params = if function.params.is_empty() {
+                    "void".to_string()
+                } else {
+                    function
+                        .params
+                        .iter()
+                        .map(|(name, ty)| c_decl(&c_type(ty), name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                header.push('\n');
+                write_doc(&mut header, &doc, "");
+                header.push_str(&format!(
+                    "{}({params});\n",
+                    c_decl(&c_type(&function.ret), &function.name)
+                ));
+                functions.push(function);
+                no_mangle = false;
+                doc.clear();
+            } else if let Some(rest) = line.strip_prefix("pub struct ") {
+                let name = rest.trim_end_matches(['{', ';', ' ']).trim();
+                header.push('\n');
+                write_doc(&mut header, &doc, "");
+                doc.clear();
+                if repr_c {
+                    header.push_str(&format!("typedef struct {name} {{\n"));
+                    i += 1;
+                    let mut field_doc = Vec::new();
+                    while lines[i].trim() != "}" {
+                        let field = lines[i].trim();
+                        if let Some(text) = field.strip_prefix("///") {
+                            field_doc.push(text.trim().to_string());
+                        } else if let Some(field) = field.strip_prefix("pub ") {
+                            let (field_name, ty) = field
+                                .trim_end_matches(',')
+                                .split_once(':')
+                                .expect("typed field");
+                            write_doc(&mut header, &field_doc, "    ");
+                            field_doc.clear();
+                            header.push_str(&format!(
+                                "    {};\n",
+                                c_decl(&field_type(ty, &declared), field_name.trim())
+                            ));
+                        }
+                        i += 1;
+                    }
+                    header.push_str(&format!("}} {name};\n"));
+                    declared.push(name.to_string());
+                } else {
+                    // Opaque handle, only ever used through a pointer
+                    header.push_str(&format!("typedef struct {name} {name};\n"));
+                    declared.push(name.to_string());
+                }
+                repr_c = false;
+            } else if let Some(rest) = line.strip_prefix("pub const ") {
+                let (name, value) = rest.split_once(':').expect("typed const");
+                let value = value
+                    .split_once('=')
+                    .expect("const value")
+                    .1
+                    .split(';')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .replace('_', "");
+                header.push('\n');
+                write_doc(&mut header, &doc, "");
+                doc.clear();
+                header.push_str(&format!("#define {name} {value}\n"));
+            } else if !line.starts_with("#[") {
+                doc.clear();
+                repr_c = false;
+            }
+            i += 1;
+        }
+    }
+
+    header.push_str(POSTAMBLE);
+    (header, functions)
+}
+
+/// Canonical C type used to compare Odin and C signatures
+///
+/// Struct names differ between the languages (`Sci_Options` vs
+/// `ZmijSciOptions`), so pointers to non-primitive types compare as `struct*`.
+fn canonical_c(c: &str) -> String {
+    let c = c.trim().replace("const ", "");
+    let stars = c.matches('*').count();
+    let base = c.trim_end_matches(['*', ' ']);
+    let base = match base {
+        "double" | "float" | "size_t" | "ptrdiff_t" | "bool" | "void" | "uint8_t" | "int8_t"
+        | "uint16_t" | "int16_t" | "uint32_t" | "int32_t" | "uint64_t" | "int64_t" => base,
+        _ if stars > 0 => "struct",
+        _ => "value-struct",
+    };
+    format!("{base}{}", "*".repeat(stars))
+}
+
+fn odin_canonical(odin: &str) -> String {
+    let odin = odin.trim();
+    if let Some(inner) = odin.strip_prefix("[^]").or_else(|| odin.strip_prefix('^')) {
+        let inner = odin_canonical(inner);
+        return if inner == "value-struct" {
+            "struct*".to_string()
+        } else {
+            format!("{inner}*")
+        };
+    }
+    match odin {
+        "f64" => "double",
+        "f32" => "float",
+        "c.size_t" | "uint" => "size_t",
+        "c.ptrdiff_t" | "int" => "ptrdiff_t",
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "i8" => "int8_t",
+        "u16" => "uint16_t",
+        "i16" => "int16_t",
+        "c.uint" | "u32" => "uint32_t",
+        "c.int" | "i32" => "int32_t",
+        "u64" => "uint64_t",
+        "i64" => "int64_t",
+        "rawptr" => "void*",
+        "" => "void",
+        _ => "value-struct",
+    }
+    .to_string()
+}
+
+/// (symbol name, parameter types, return type) for each Odin foreign proc
+fn odin_foreign_procs(odin: &str) -> Vec<(String, Vec<String>, String)> {
+    let mut procs = Vec::new();
+    let mut prefix: Option<String> = None;
+    let mut in_block = false;
+
+    for line in odin.lines() {
+        if line.starts_with("@(default_calling_convention") {
+            prefix = line
+                .split_once("link_prefix = \"")
+                .map(|(_, rest)| rest.split('"').next().unwrap_or_default().to_string());
+        } else if line.starts_with("foreign ") {
+            in_block = true;
+        } else if line.starts_with('}') {
+            in_block = false;
+        } else if in_block && line.trim_end().ends_with("---") {
+            let (name, rest) = line.trim().split_once(" :: proc(").expect("foreign proc");
+            let (params, ret) = rest.rsplit_once(')').expect("parameter list end");
+            let ret = ret
+                .trim_end_matches("---")
+                .trim()
+                .trim_start_matches("->")
+                .trim()
+                .to_string();
+            let params = params
+                .split(',')
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    p.split_once(':')
+                        .expect("typed parameter")
+                        .1
+                        .trim()
+                        .to_string()
+                })
+                .collect();
+            let symbol = format!("{}{name}", prefix.as_deref().unwrap_or_default());
+            procs.push((symbol, params, ret));
+        }
+    }
+    procs
+}
+
+mod tests {
+    use super::*;
+
+    fn manifest_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        let dir = manifest_dir();
+        let (generated, _) = generate(&dir.join("src"));
+        let path = dir.join(HEADER_PATH);
+
+        if std::env::var_os("NUM_FORMAT_BLESS").is_some() {
+            fs::write(&path, &generated).expect("write header");
+            return;
+        }
+
+        let committed = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            committed == generated,
+            "{HEADER_PATH} is stale, regenerate with: NUM_FORMAT_BLESS=1 cargo test header"
+        );
+    }
+
+    #[test]
+    fn test_header_declares_every_export() {
+        let dir = manifest_dir();
+        let (header, functions) = generate(&dir.join("src"));
+
+        let mut exported = 0;
+        for file in source_files(&dir.join("src")) {
+            let text = fs::read_to_string(file).unwrap();
+            let code = text.split("\nmod tests {").next().unwrap();
+            exported += code.matches("#[unsafe(no_mangle)]").count();
+        }
+        assert_eq!(functions.len(), exported, "every export must be declared");
+
+        for function in &functions {
+            assert!(
+                header.contains(&format!("{}(", function.name)),
+                "{} missing from header",
+                function.name
+            );
+        }
+        assert!(
+            header.contains("size_t zmij_format_f64(double value, uint8_t *buf, size_t buf_len);")
+        );
+        assert!(
+            header.contains("size_t rust_itoa_i64(int64_t value, uint8_t *buf, size_t buf_len);")
+        );
+        assert!(!header.contains("uint32_t buf_len"));
+    }
+
+    #[test]
+    fn test_odin_bindings_match_exports() {
+        let dir = manifest_dir();
+        let (_, functions) = generate(&dir.join("src"));
+        let odin = fs::read_to_string(dir.join(ODIN_BINDINGS_PATH)).expect("read num_format.odin");
+
+        let procs = odin_foreign_procs(&odin);
+        assert!(!procs.is_empty());
+
+        for (symbol, params, ret) in procs {
+            let function = functions
+                .iter()
+                .find(|f| f.name == symbol)
+                .unwrap_or_else(|| panic!("Odin binds {symbol} which is not exported"));
+
+            let c_params: Vec<String> = function
+                .params
+                .iter()
+                .map(|(_, ty)| canonical_c(&c_type(ty)))
+                .collect();
+            let odin_params: Vec<String> = params.iter().map(|ty| odin_canonical(ty)).collect();
+            assert_eq!(odin_params, c_params, "parameter types of {symbol}");
+            assert_eq!(
+                odin_canonical(&ret),
+                canonical_c(&c_type(&function.ret)),
+                "return type of {symbol}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_c_type_mapping() {
+        assert_eq!(c_type("usize"), "size_t");
+        assert_eq!(c_type("*mut u8"), "uint8_t *");
+        assert_eq!(c_type("*const ZmijSciOptions"), "const ZmijSciOptions *");
+        assert_eq!(c_type("*const *const u8"), "const uint8_t *const *");
+        assert_eq!(c_decl(&c_type("*mut u8"), "buf"), "uint8_t *buf");
+        assert_eq!(odin_canonical("[^]u8"), "uint8_t*");
+        assert_eq!(odin_canonical("^Sci_Options"), "struct*");
+        assert_eq!(canonical_c("const ZmijSciOptions *"), "struct*");
+    }
+}
@@ -0,0 +1,268 @@
+// ============================================================================
+// JSON C FFI Wrappers - RFC 8259 number output
+// ============================================================================
+// zmij's shortest output is already valid JSON for finite values (`1.0`,
+// `1e+16`, `-0.0`); NaN and infinities are not. These functions either
+// substitute a configured token such as `null` for them or fail, and can
+// print integral values up to 2^53 without the `.0` for schemas that expect
+// integers. Functions return the length the output requires and write it only
+// if it is <= buf_len (snprintf-style); 0 means the value was non-finite and
+// no substitute was configured.
+
+use crate::grouping::c_bytes;
+use crate::out::Out;
+use crate::policy::{FloatPolicy, write_float};
+
+/// Largest magnitude up to which every integer is exactly representable (2^53)
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Options for the JSON formatters
+///
+/// A null pointer behaves like a zeroed struct: integral values keep `.0`
+/// and non-finite values are an error.
+#[repr(C)]
+pub struct ZmijJsonOptions {
+    /// Print integral values with magnitude <= 2^53 without `.0`
+    pub integers_without_fraction: bool,
+    /// Token written for NaN and infinities (UTF-8, null to fail instead)
+    pub non_finite: *const u8,
+    /// Length of non_finite in bytes
+    pub non_finite_len: usize,
+}
+
+/// Write `value` as a JSON number; false if it is non-finite without substitute
+fn write_json<F: zmij::Float + Into<f64> + Copy>(
+    out: &mut Out,
+    value: F,
+    integers_without_fraction: bool,
+    non_finite: Option<&[u8]>,
+) -> bool {
+    let wide: f64 = value.into();
+    if !wide.is_finite() {
+        let Some(token) = non_finite else {
+            return false;
+        };
+        out.push_bytes(token);
+        return true;
+    }
+
+    if integers_without_fraction && wide.fract() == 0.0 && wide.abs() <= MAX_SAFE_INTEGER {
+        // Integral values in this range always print positionally as `N.0`
+        let mut buffer = zmij::Buffer::new();
+        let text = buffer.format_finite(value).as_bytes();
+        out.push_bytes(text.strip_suffix(b".0").unwrap_or(text));
+    } else {
+        write_float(out, value, &FloatPolicy::ZMIJ);
+    }
+    true
+}
+
+/// Resolve a possibly null options pointer
+///
+/// # Safety
+/// - options must be null or point to a valid ZmijJsonOptions whose
+///   non_finite string is null or valid for non_finite_len bytes
+unsafe fn json_options<'a>(options: *const ZmijJsonOptions) -> (bool, Option<&'a [u8]>) {
+    // Safety: guaranteed by the caller
+    match unsafe { options.as_ref() } {
+        None => (false, None),
+        Some(options) => {
+            let non_finite = if options.non_finite.is_null() {
+                None
+            } else {
+                // Safety: guaranteed by the caller
+                Some(unsafe { c_bytes(options.non_finite, options.non_finite_len) })
+            };
+            (options.integers_without_fraction, non_finite)
+        }
+    }
+}
+
+/// Format f64 as an RFC 8259 JSON number
+///
+/// # Safety
+/// - options must be null or point to a valid ZmijJsonOptions whose
+///   non_finite string is null or valid for non_finite_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if value is NaN or infinite and options has no non_finite token
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_json_f64(
+    value: f64,
+    options: *const ZmijJsonOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let (integers, non_finite) = unsafe { json_options(options) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_json(&mut out, value, integers, non_finite) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format f32 as an RFC 8259 JSON number
+///
+/// # Safety
+/// - options must be null or point to a valid ZmijJsonOptions whose
+///   non_finite string is null or valid for non_finite_len bytes
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if value is NaN or infinite and options has no non_finite token
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_json_f32(
+    value: f32,
+    options: *const ZmijJsonOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let (integers, non_finite) = unsafe { json_options(options) };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_json(&mut out, value, integers, non_finite) {
+        return 0;
+    }
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NULL: &str = "null";
+
+    fn options(integers_without_fraction: bool, non_finite: Option<&str>) -> ZmijJsonOptions {
+        ZmijJsonOptions {
+            integers_without_fraction,
+            non_finite: non_finite.map_or(std::ptr::null(), str::as_ptr),
+            non_finite_len: non_finite.map_or(0, str::len),
+        }
+    }
+
+    fn json_f64_test(value: f64, options: &ZmijJsonOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { zmij_format_json_f64(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "zmij_format_json_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    /// RFC 8259 `number` grammar
+    fn is_json_number(text: &str) -> bool {
+        let bytes = text.as_bytes();
+        let mut i = usize::from(bytes.first() == Some(&b'-'));
+        let digits = |i: &mut usize| {
+            let start = *i;
+            while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+                *i += 1;
+            }
+            *i - start
+        };
+        match bytes.get(i) {
+            Some(b'0') => i += 1,
+            Some(b'1'..=b'9') => {
+                digits(&mut i);
+            }
+            _ => return false,
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            if digits(&mut i) == 0 {
+                return false;
+            }
+        }
+        if matches!(bytes.get(i), Some(b'e' | b'E')) {
+            i += 1;
+            if matches!(bytes.get(i), Some(b'+' | b'-')) {
+                i += 1;
+            }
+            if digits(&mut i) == 0 {
+                return false;
+            }
+        }
+        i == bytes.len()
+    }
+
+    #[test]
+    fn test_json_output_is_valid() {
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            1e-7,
+            1e16,
+            1e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            123456.789,
+        ];
+        for integers in [false, true] {
+            let options = options(integers, None);
+            for value in values {
+                let text = json_f64_test(value, &options);
+                assert!(is_json_number(&text), "invalid JSON number: {}", text);
+                assert_eq!(text.parse::<f64>().unwrap(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_integers_without_fraction() {
+        let integers = options(true, None);
+        assert_eq!(json_f64_test(1.0, &integers), "1");
+        assert_eq!(json_f64_test(-42.0, &integers), "-42");
+        assert_eq!(json_f64_test(-0.0, &integers), "-0");
+        assert_eq!(
+            json_f64_test(9007199254740992.0, &integers),
+            "9007199254740992"
+        );
+        assert_eq!(json_f64_test(1.5, &integers), "1.5");
+        assert_eq!(json_f64_test(1e16, &integers), "1e+16");
+        assert_eq!(json_f64_test(1.0, &options(false, None)), "1.0");
+    }
+
+    #[test]
+    fn test_json_non_finite() {
+        let mut buf = [0u8; 64];
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let len = unsafe {
+                zmij_format_json_f64(value, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+            };
+            assert_eq!(len, 0, "Should return 0 for non-finite {}", value);
+            assert_eq!(json_f64_test(value, &options(false, Some(NULL))), NULL);
+        }
+    }
+
+    #[test]
+    fn test_json_f32() {
+        let mut buf = [0u8; 64];
+        let integers = options(true, Some(NULL));
+        let len =
+            unsafe { zmij_format_json_f32(16777216.0, &integers, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"16777216");
+        let len = unsafe { zmij_format_json_f32(0.1, &integers, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0.1");
+        let len = unsafe { zmij_format_json_f32(f32::NAN, &integers, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"null");
+    }
+
+    #[test]
+    fn test_json_reports_required_length() {
+        let len =
+            unsafe { zmij_format_json_f64(-1.5e-7, std::ptr::null(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, "-1.5e-7".len(), "Should report the required length");
+    }
+}
@@ -1,5 +1,31 @@
 use std::slice;
 
+mod array;
+mod bytes;
+mod compact;
+mod currency;
+mod decimal;
+mod duration;
+mod fixed;
+mod formatter;
+mod grouping;
+#[cfg(test)]
+mod header;
+mod json;
+mod out;
+mod pad;
+mod parse;
+mod policy;
+mod radix;
+mod scaled;
+mod scientific;
+mod significant;
+mod spec;
+mod table;
+mod timestamp;
+
+use out::Out;
+
 // ============================================================================
 // Zmij C FFI Wrappers - Fast floating point to string conversion
 // ============================================================================
@@ -12,8 +38,13 @@ use std::slice;
 // All functions write UTF-8 formatted output to the provided buffer
 // and return the number of bytes written (0 if buffer too small)
 
+/// Buffer size the zmij_format_* functions require (size of zmij::Buffer)
+pub const ZMIJ_BUFFER_SIZE: usize = 24;
+const _: () = assert!(ZMIJ_BUFFER_SIZE == std::mem::size_of::<zmij::Buffer>());
+
 // Buffer size constants - itoa uses i128::MAX_STR_LEN internally (40 bytes)
-const ITOA_BUFFER_SIZE: usize = 40; // i128::MAX_STR_LEN, covers all integer types
+/// Buffer size the rust_itoa_* functions require (i128::MAX_STR_LEN)
+pub const ITOA_BUFFER_SIZE: usize = 40; // i128::MAX_STR_LEN, covers all integer types
 
 /// Format f64 floating point to string
 ///
@@ -273,6 +304,367 @@ pub unsafe extern "C" fn rust_itoa_u32(value: u32, buf: *mut u8, buf_len: usize)
     }
 }
 
+/// Format any integer into the caller's buffer via itoa::Buffer
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn itoa_into<I: itoa::Integer>(value: I, buf: *mut u8, buf_len: usize) -> usize {
+    if buf.is_null() || buf_len < ITOA_BUFFER_SIZE {
+        return 0;
+    }
+
+    unsafe {
+        // Cast caller's buffer as itoa::Buffer
+        let buffer_ptr = buf as *mut itoa::Buffer;
+        // Format directly into that memory
+        let formatted = (*buffer_ptr).format(value);
+        let bytes = formatted.as_bytes();
+
+        // Only copy if the byte slice is not the same memory as buf
+        if !std::ptr::eq(bytes.as_ptr(), buf) {
+            // Use ptr::copy to handle potential overlap
+            std::ptr::copy(bytes.as_ptr(), buf, bytes.len());
+        }
+
+        bytes.len()
+    }
+}
+
+/// Reassemble an i128 passed as two 64-bit halves
+///
+/// 128-bit integers have no portable C ABI, so the 128-bit exports take the
+/// high half (carrying the sign) and the low half separately.
+fn i128_from_halves(hi: i64, lo: u64) -> i128 {
+    ((hi as i128) << 64) | lo as i128
+}
+
+/// Reassemble a u128 passed as two 64-bit halves
+fn u128_from_halves(hi: u64, lo: u64) -> u128 {
+    ((hi as u128) << 64) | lo as u128
+}
+
+/// Format i128 integer, passed as `(hi << 64) | lo`, to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i128(hi: i64, lo: u64, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(i128_from_halves(hi, lo), buf, buf_len) }
+}
+
+/// Format u128 integer, passed as `(hi << 64) | lo`, to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u128(hi: u64, lo: u64, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(u128_from_halves(hi, lo), buf, buf_len) }
+}
+
+/// Format i16 integer to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i16(value: i16, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(value, buf, buf_len) }
+}
+
+/// Format u16 integer to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u16(value: u16, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(value, buf, buf_len) }
+}
+
+/// Format i8 integer to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i8(value: i8, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(value, buf, buf_len) }
+}
+
+/// Format u8 integer to UTF-8 string
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= 40 for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u8(value: u8, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { itoa_into(value, buf, buf_len) }
+}
+
+// ============================================================================
+// snprintf-style C FFI Wrappers - Return the required length
+// ============================================================================
+// The `_fit` variants accept any buffer that is large enough for the actual
+// text instead of the 24/40 byte scratch size. They always return the length
+// the output requires: if it is <= buf_len the text was written, otherwise
+// the buffer contents are unspecified and the caller can retry with exactly
+// that many bytes. buf may be null when buf_len is 0 to only measure.
+
+/// Copy already formatted text into the caller's buffer, snprintf-style
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn write_fit(text: &str, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: guaranteed by the caller
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    out.push_bytes(text.as_bytes());
+    out.len()
+}
+
+/// Format f64 floating point to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_f64_fit(value: f64, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    policy::write_float(&mut out, value, &policy::FloatPolicy::ZMIJ);
+    out.len()
+}
+
+/// Format f32 floating point to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_f32_fit(value: f32, buf: *mut u8, buf_len: usize) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    policy::write_float(&mut out, value, &policy::FloatPolicy::ZMIJ);
+    out.len()
+}
+
+/// Format finite f64 (no NaN/inf checks), returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - value must be a finite floating point number (not NaN or infinity)
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_finite_f64_fit(
+    value: f64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let mut buffer = zmij::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format_finite(value), buf, buf_len) }
+}
+
+/// Format finite f32 (no NaN/inf checks), returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - value must be a finite floating point number (not NaN or infinity)
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_finite_f32_fit(
+    value: f32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let mut buffer = zmij::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format_finite(value), buf, buf_len) }
+}
+
+/// Format i64 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i64_fit(value: i64, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format u64 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u64_fit(value: u64, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format i32 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i32_fit(value: i32, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format u32 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u32_fit(value: u32, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format i128 integer, passed as `(hi << 64) | lo`, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i128_fit(
+    hi: i64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(i128_from_halves(hi, lo)), buf, buf_len) }
+}
+
+/// Format u128 integer, passed as `(hi << 64) | lo`, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u128_fit(
+    hi: u64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(u128_from_halves(hi, lo)), buf, buf_len) }
+}
+
+/// Format i16 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i16_fit(value: i16, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format u16 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u16_fit(value: u16, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format i8 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_i8_fit(value: i8, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
+/// Format u8 integer to string, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_u8_fit(value: u8, buf: *mut u8, buf_len: usize) -> usize {
+    let mut buffer = itoa::Buffer::new();
+    // Safety: buf is null or valid for buf_len bytes
+    unsafe { write_fit(buffer.format(value), buf, buf_len) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +738,22 @@ mod tests {
         String::from_utf8_lossy(&buf[..len]).into_owned()
     }
 
+    fn itoa_i128_test(value: i128) -> String {
+        let mut buf = [0u8; 40]; // i128::MAX_STR_LEN
+        let (hi, lo) = ((value >> 64) as i64, value as u64);
+        let len = unsafe { rust_itoa_i128(hi, lo, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "rust_itoa_i128 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    fn itoa_u128_test(value: u128) -> String {
+        let mut buf = [0u8; 40]; // i128::MAX_STR_LEN
+        let (hi, lo) = ((value >> 64) as u64, value as u64);
+        let len = unsafe { rust_itoa_u128(hi, lo, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "rust_itoa_u128 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
     // ========================================================================
     // zmij_format_f64 Tests
     // ========================================================================
@@ -363,6 +771,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f64_simple_positive() {
         let result = format_f64_test(3.14159);
         assert_eq!(result, "3.14159");
@@ -461,6 +870,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f32_simple_positive() {
         let result = format_f32_test(3.14f32);
         assert_eq!(result, "3.14");
@@ -592,6 +1002,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f64_null_buffer() {
         let value = 3.14;
         let len = unsafe { zmij_format_f64(value, std::ptr::null_mut(), 24) };
@@ -599,6 +1010,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f32_null_buffer() {
         let value = 3.14f32;
         let len = unsafe { zmij_format_f32(value, std::ptr::null_mut(), 24) };
@@ -623,6 +1035,7 @@ mod tests {
     // ========================================================================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f64_output_is_valid_utf8() {
         let mut buf = [0u8; 24];
         let len = unsafe { zmij_format_f64(3.14159, buf.as_mut_ptr(), buf.len()) };
@@ -631,6 +1044,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f32_output_is_valid_utf8() {
         let mut buf = [0u8; 24];
         let len = unsafe { zmij_format_f32(2.71828f32, buf.as_mut_ptr(), buf.len()) };
@@ -744,6 +1158,73 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // snprintf-style _fit Tests
+    // ========================================================================
+
+    #[test]
+    fn test_zmij_format_f64_fit_exact_buffer() {
+        // "1.0" fits in 3 bytes even though the scratch size is 24
+        let mut buf = [0u8; 3];
+        let len = unsafe { zmij_format_f64_fit(1.0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 3);
+        assert_eq!(&buf, b"1.0");
+    }
+
+    #[test]
+    fn test_zmij_format_f64_fit_reports_required_length() {
+        let mut buf = [0u8; 4];
+        let len = unsafe { zmij_format_f64_fit(1234.5678, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, "1234.5678".len());
+
+        let mut buf = vec![0u8; len];
+        let written = unsafe { zmij_format_f64_fit(1234.5678, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(written, len);
+        assert_eq!(buf, b"1234.5678");
+    }
+
+    #[test]
+    fn test_zmij_format_f64_fit_null_buffer_measures() {
+        let len = unsafe { zmij_format_f64_fit(f64::NEG_INFINITY, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 4);
+        let len = unsafe { zmij_format_finite_f64_fit(-0.5, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 4);
+    }
+
+    #[test]
+    fn test_zmij_format_f32_fit() {
+        let mut buf = [0u8; 4];
+        let len = unsafe { zmij_format_f32_fit(2.5f32, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"2.5");
+        let len = unsafe { zmij_format_finite_f32_fit(-8.9f32, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-8.9");
+    }
+
+    #[test]
+    fn test_itoa_fit_exact_buffer() {
+        let mut buf = [0u8; 1];
+        let len = unsafe { rust_itoa_i64_fit(0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 1);
+        assert_eq!(&buf, b"0");
+
+        let mut buf = [0u8; 20];
+        let len = unsafe { rust_itoa_u64_fit(u64::MAX, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"18446744073709551615");
+
+        let mut buf = [0u8; 11];
+        let len = unsafe { rust_itoa_i32_fit(i32::MIN, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-2147483648");
+    }
+
+    #[test]
+    fn test_itoa_fit_too_small_does_not_overflow() {
+        let mut buf = [0xAAu8; 8];
+        let len = unsafe { rust_itoa_u32_fit(123456, buf[2..5].as_mut_ptr(), 3) };
+        assert_eq!(len, 6, "Should report the required length");
+        assert_eq!(buf[0..2], [0xAA; 2], "Buffer before output was modified");
+        assert_eq!(buf[5..8], [0xAA; 3], "Buffer after output was modified");
+    }
+
     // ========================================================================
     // rust_itoa_i64 Tests
     // ========================================================================
@@ -876,6 +1357,106 @@ mod tests {
         assert_eq!(result, "4294967295");
     }
 
+    // ========================================================================
+    // rust_itoa_i128 / rust_itoa_u128 Tests
+    // ========================================================================
+
+    #[test]
+    fn test_itoa_i128_halves() {
+        assert_eq!(itoa_i128_test(0), "0");
+        assert_eq!(itoa_i128_test(-1), "-1");
+        assert_eq!(itoa_i128_test(i64::MIN as i128), "-9223372036854775808");
+        assert_eq!(itoa_i128_test(1 << 64), "18446744073709551616");
+        assert_eq!(
+            itoa_i128_test(i128::MAX),
+            "170141183460469231731687303715884105727"
+        );
+        assert_eq!(
+            itoa_i128_test(i128::MIN),
+            "-170141183460469231731687303715884105728"
+        );
+    }
+
+    #[test]
+    fn test_itoa_u128_halves() {
+        assert_eq!(itoa_u128_test(0), "0");
+        assert_eq!(itoa_u128_test(u64::MAX as u128 + 1), "18446744073709551616");
+        assert_eq!(
+            itoa_u128_test(u128::MAX),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_itoa_128_roundtrip() {
+        let values = [1i128, -42, 12345678901234567890123456789, -(1 << 100)];
+        for value in values {
+            let parsed: i128 = itoa_i128_test(value)
+                .parse()
+                .expect("Should parse back to i128");
+            assert_eq!(parsed, value);
+            let unsigned = value.unsigned_abs();
+            let parsed: u128 = itoa_u128_test(unsigned)
+                .parse()
+                .expect("Should parse back to u128");
+            assert_eq!(parsed, unsigned);
+        }
+    }
+
+    #[test]
+    fn test_itoa_128_fit() {
+        let len = unsafe { rust_itoa_u128_fit(u64::MAX, u64::MAX, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 39, "Should report the required length");
+
+        let mut buf = [0u8; 40];
+        let len = unsafe { rust_itoa_i128_fit(i64::MIN, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 40);
+        assert_eq!(&buf[..len], b"-170141183460469231731687303715884105728");
+
+        let len = unsafe { rust_itoa_i128(-1, u64::MAX, buf.as_mut_ptr(), 39) };
+        assert_eq!(len, 0, "Should return 0 for buffer smaller than 40 bytes");
+    }
+
+    // ========================================================================
+    // rust_itoa_i16 / u16 / i8 / u8 Tests
+    // ========================================================================
+
+    #[test]
+    fn test_itoa_small_widths() {
+        let mut buf = [0u8; 40];
+        let len = unsafe { rust_itoa_i16(i16::MIN, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-32768");
+        let len = unsafe { rust_itoa_u16(u16::MAX, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"65535");
+        let len = unsafe { rust_itoa_i8(i8::MIN, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-128");
+        let len = unsafe { rust_itoa_u8(u8::MAX, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"255");
+        let len = unsafe { rust_itoa_u8(0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0");
+    }
+
+    #[test]
+    fn test_itoa_small_widths_fit() {
+        let mut buf = [0u8; 4];
+        let len = unsafe { rust_itoa_i8_fit(-128, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-128");
+        let len = unsafe { rust_itoa_u8_fit(7, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"7");
+        let len = unsafe { rust_itoa_i16_fit(-32768, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 6, "Should report the required length");
+        let len = unsafe { rust_itoa_u16_fit(9999, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"9999");
+    }
+
+    #[test]
+    fn test_itoa_small_widths_null_buffer() {
+        let len = unsafe { rust_itoa_u8(42, std::ptr::null_mut(), 40) };
+        assert_eq!(len, 0, "Should return 0 for null buffer");
+        let len = unsafe { rust_itoa_i128(0, 42, std::ptr::null_mut(), 40) };
+        assert_eq!(len, 0, "Should return 0 for null buffer");
+    }
+
     // ========================================================================
     // itoa Buffer Validation Tests
     // ========================================================================
@@ -943,6 +1524,7 @@ mod tests {
     // ========================================================================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_library_contains_both_wrappers() {
         // Test that we can call both wrappers in the same test
         // This ensures the library properly exports both symbols
@@ -966,6 +1548,7 @@ mod tests {
     // ========================================================================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f64_length_bounds() {
         let values = vec![0.0, 1.0, 3.14159, 1e20, 1e-20, f64::MAX];
         for value in values {
@@ -1000,6 +1583,7 @@ mod tests {
     // ========================================================================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_zmij_format_f64_no_overflow() {
         // Test that function doesn't write beyond buffer bounds
         // Buffer too small is rejected
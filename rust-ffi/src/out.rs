@@ -0,0 +1,66 @@
+// ============================================================================
+// Output sink shared by the formatting exports
+// ============================================================================
+// Writes into the caller's buffer while it fits and keeps counting past the
+// end, so every formatter knows the exact length its output requires.
+
+use std::slice;
+
+pub(crate) struct Out<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Out<'a> {
+    /// Wrap a caller supplied C buffer
+    ///
+    /// # Safety
+    /// - buf must be null or a valid mutable pointer to at least buf_len bytes
+    pub(crate) unsafe fn from_raw(buf: *mut u8, buf_len: usize) -> Self {
+        if buf.is_null() {
+            return Out::new(&mut []);
+        }
+        // Safety: guaranteed by the caller
+        Out::new(unsafe { slice::from_raw_parts_mut(buf, buf_len) })
+    }
+
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Out { buf, len: 0 }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, byte: u8) {
+        if let Some(slot) = self.buf.get_mut(self.len) {
+            *slot = byte;
+        }
+        self.len += 1;
+    }
+
+    #[inline]
+    pub(crate) fn push_bytes(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        if end <= self.buf.len() {
+            self.buf[self.len..end].copy_from_slice(bytes);
+        }
+        self.len = end;
+    }
+
+    /// Number of bytes the output requires (may exceed the buffer)
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Drop everything pushed after the first `len` bytes
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    pub(crate) fn fits(&self) -> bool {
+        self.len <= self.buf.len()
+    }
+
+    /// Bytes written, or 0 if the output did not fit in the buffer
+    pub(crate) fn written_or_zero(&self) -> usize {
+        if self.fits() { self.len } else { 0 }
+    }
+}
@@ -0,0 +1,611 @@
+// ============================================================================
+// Padded itoa C FFI Wrappers - Fixed-width integer columns
+// ============================================================================
+// Integers padded to a minimum width with any fill character, aligned left,
+// right or centered, or zero padded after the sign (`-0042`), with an optional
+// forced `+`. Functions return the length the output requires and write it
+// only if it is <= buf_len (snprintf-style); 0 means the options were invalid.
+
+use crate::out::Out;
+use crate::{i128_from_halves, u128_from_halves};
+
+/// Fill before the number (the default for numbers)
+pub const ITOA_ALIGN_RIGHT: u8 = 0;
+
+/// Fill after the number
+pub const ITOA_ALIGN_LEFT: u8 = 1;
+
+/// Fill on both sides, the extra character going to the right
+pub const ITOA_ALIGN_CENTER: u8 = 2;
+
+/// Pad with `0` between the sign and the digits (`-0042`), ignoring fill
+pub const ITOA_ALIGN_ZERO_PAD: u8 = 3;
+
+/// Largest width the padded formatters accept
+pub const ITOA_MAX_WIDTH: u32 = 4096;
+
+/// Width, fill and sign options for the padded formatters
+///
+/// A null pointer or a zeroed struct means no padding and no forced sign.
+#[repr(C)]
+pub struct ItoaPadOptions {
+    /// Minimum width in characters, counting the sign (at most ITOA_MAX_WIDTH)
+    pub width: u32,
+    /// Fill character as a Unicode scalar value, 0 for a space
+    pub fill: u32,
+    /// One of the ITOA_ALIGN_* constants
+    pub align: u8,
+    /// Print `+` before non-negative values
+    pub plus_sign: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Align {
+    Right,
+    Left,
+    Center,
+    /// Fill between the sign (and prefix) and the digits
+    AfterSign,
+}
+
+/// Resolved padding, shared by every formatter that pads its output
+#[derive(Clone, Copy)]
+pub(crate) struct Padding {
+    pub(crate) width: usize,
+    fill: [u8; 4],
+    fill_len: usize,
+    pub(crate) align: Align,
+    /// Sign printed before non-negative values (`+` or a space)
+    pub(crate) positive_sign: Option<u8>,
+}
+
+impl Padding {
+    pub(crate) const NONE: Self = Padding {
+        width: 0,
+        fill: [b' ', 0, 0, 0],
+        fill_len: 1,
+        align: Align::Right,
+        positive_sign: None,
+    };
+
+    /// Padding with the given fill; None if width exceeds ITOA_MAX_WIDTH or
+    /// fill is not a Unicode scalar value
+    pub(crate) fn new(
+        width: usize,
+        fill: u32,
+        align: Align,
+        positive_sign: Option<u8>,
+    ) -> Option<Self> {
+        if width > ITOA_MAX_WIDTH as usize {
+            return None;
+        }
+        let fill = match fill {
+            0 => ' ',
+            fill => char::from_u32(fill)?,
+        };
+        let mut bytes = [0u8; 4];
+        let fill_len = fill.encode_utf8(&mut bytes).len();
+        Some(Padding {
+            width,
+            fill: bytes,
+            fill_len,
+            align,
+            positive_sign,
+        })
+    }
+
+    fn write_fill(&self, out: &mut Out, count: usize) {
+        for _ in 0..count {
+            out.push_bytes(&self.fill[..self.fill_len]);
+        }
+    }
+
+    /// Write the sign, an ASCII `prefix` and a body of `body_chars`
+    /// characters, padded to width
+    pub(crate) fn write(
+        &self,
+        out: &mut Out,
+        negative: bool,
+        prefix: &[u8],
+        body_chars: usize,
+        body: impl FnOnce(&mut Out),
+    ) {
+        let sign = if negative {
+            Some(b'-')
+        } else {
+            self.positive_sign
+        };
+        let chars = sign.is_some() as usize + prefix.len() + body_chars;
+        let padding = self.width.saturating_sub(chars);
+        let (before, after) = match self.align {
+            Align::Right => (padding, 0),
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding - padding / 2),
+            Align::AfterSign => (0, 0),
+        };
+
+        self.write_fill(out, before);
+        if let Some(sign) = sign {
+            out.push(sign);
+        }
+        out.push_bytes(prefix);
+        if self.align == Align::AfterSign {
+            self.write_fill(out, padding);
+        }
+        body(out);
+        self.write_fill(out, after);
+    }
+}
+
+/// Resolve a possibly null options pointer, rejecting invalid width, align or fill
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+unsafe fn pad_options(options: *const ItoaPadOptions) -> Option<Padding> {
+    // Safety: guaranteed by the caller
+    let Some(options) = (unsafe { options.as_ref() }) else {
+        return Some(Padding::NONE);
+    };
+    let (align, fill) = match options.align {
+        ITOA_ALIGN_RIGHT => (Align::Right, options.fill),
+        ITOA_ALIGN_LEFT => (Align::Left, options.fill),
+        ITOA_ALIGN_CENTER => (Align::Center, options.fill),
+        ITOA_ALIGN_ZERO_PAD => (Align::AfterSign, '0' as u32),
+        _ => return None,
+    };
+    let positive_sign = options.plus_sign.then_some(b'+');
+    Padding::new(options.width as usize, fill, align, positive_sign)
+}
+
+/// Write an integer given as sign and magnitude, padded
+pub(crate) fn write_padded_int(out: &mut Out, negative: bool, magnitude: u128, pad: &Padding) {
+    let mut buffer = itoa::Buffer::new();
+    let digits = buffer.format(magnitude).as_bytes();
+    pad.write(out, negative, b"", digits.len(), |out| {
+        out.push_bytes(digits)
+    });
+}
+
+/// Shared body of the exports: resolve options, then format into buf
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn pad_into(
+    negative: bool,
+    magnitude: u128,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: options is null or valid
+    let Some(pad) = (unsafe { pad_options(options) }) else {
+        return 0;
+    };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_padded_int(&mut out, negative, magnitude, &pad);
+    out.len()
+}
+
+/// Format i64 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_i64(
+    value: i64,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        pad_into(
+            value < 0,
+            value.unsigned_abs() as u128,
+            options,
+            buf,
+            buf_len,
+        )
+    }
+}
+
+/// Format u64 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_u64(
+    value: u64,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(false, value as u128, options, buf, buf_len) }
+}
+
+/// Format i32 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_i32(
+    value: i32,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        pad_into(
+            value < 0,
+            value.unsigned_abs() as u128,
+            options,
+            buf,
+            buf_len,
+        )
+    }
+}
+
+/// Format u32 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_u32(
+    value: u32,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(false, value as u128, options, buf, buf_len) }
+}
+
+/// Format i16 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_i16(
+    value: i16,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        pad_into(
+            value < 0,
+            value.unsigned_abs() as u128,
+            options,
+            buf,
+            buf_len,
+        )
+    }
+}
+
+/// Format u16 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_u16(
+    value: u16,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(false, value as u128, options, buf, buf_len) }
+}
+
+/// Format i8 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_i8(
+    value: i8,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        pad_into(
+            value < 0,
+            value.unsigned_abs() as u128,
+            options,
+            buf,
+            buf_len,
+        )
+    }
+}
+
+/// Format u8 integer padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_u8(
+    value: u8,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(false, value as u128, options, buf, buf_len) }
+}
+
+/// Format i128 integer, passed as `(hi << 64) | lo`, padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_i128(
+    hi: i64,
+    lo: u64,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let value = i128_from_halves(hi, lo);
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(value < 0, value.unsigned_abs(), options, buf, buf_len) }
+}
+
+/// Format u128 integer, passed as `(hi << 64) | lo`, padded to a minimum width
+///
+/// # Safety
+/// - options must be null or point to a valid ItoaPadOptions
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if width exceeds ITOA_MAX_WIDTH, align is unknown or fill is not a
+/// Unicode scalar value
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_pad_u128(
+    hi: u64,
+    lo: u64,
+    options: *const ItoaPadOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe { pad_into(false, u128_from_halves(hi, lo), options, buf, buf_len) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(width: u32, fill: char, align: u8, plus_sign: bool) -> ItoaPadOptions {
+        ItoaPadOptions {
+            width,
+            fill: fill as u32,
+            align,
+            plus_sign,
+        }
+    }
+
+    fn pad_i64_test(value: i64, options: &ItoaPadOptions) -> String {
+        let mut buf = [0u8; 128];
+        let len = unsafe { rust_itoa_pad_i64(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "rust_itoa_pad_i64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_pad_alignment() {
+        assert_eq!(
+            pad_i64_test(42, &options(6, ' ', ITOA_ALIGN_RIGHT, false)),
+            "    42"
+        );
+        assert_eq!(
+            pad_i64_test(42, &options(6, ' ', ITOA_ALIGN_LEFT, false)),
+            "42    "
+        );
+        assert_eq!(
+            pad_i64_test(42, &options(7, '*', ITOA_ALIGN_CENTER, false)),
+            "**42***"
+        );
+        assert_eq!(
+            pad_i64_test(-42, &options(6, ' ', ITOA_ALIGN_RIGHT, false)),
+            "   -42"
+        );
+    }
+
+    #[test]
+    fn test_pad_zero_pad_is_sign_aware() {
+        assert_eq!(
+            pad_i64_test(-42, &options(5, ' ', ITOA_ALIGN_ZERO_PAD, false)),
+            "-0042"
+        );
+        assert_eq!(
+            pad_i64_test(42, &options(5, 'x', ITOA_ALIGN_ZERO_PAD, true)),
+            "+0042"
+        );
+        assert_eq!(
+            pad_i64_test(0, &options(3, ' ', ITOA_ALIGN_ZERO_PAD, false)),
+            "000"
+        );
+    }
+
+    #[test]
+    fn test_pad_matches_std() {
+        for value in [0i64, 7, -7, 12345, -12345, i64::MIN, i64::MAX] {
+            assert_eq!(
+                pad_i64_test(value, &options(10, ' ', ITOA_ALIGN_RIGHT, false)),
+                format!("{:>10}", value)
+            );
+            assert_eq!(
+                pad_i64_test(value, &options(10, '_', ITOA_ALIGN_LEFT, true)),
+                format!("{:_<+10}", value)
+            );
+            assert_eq!(
+                pad_i64_test(value, &options(10, '.', ITOA_ALIGN_CENTER, false)),
+                format!("{:.^10}", value)
+            );
+            assert_eq!(
+                pad_i64_test(value, &options(10, ' ', ITOA_ALIGN_ZERO_PAD, true)),
+                format!("{:+010}", value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pad_width_shorter_than_number() {
+        assert_eq!(
+            pad_i64_test(123456, &options(3, ' ', ITOA_ALIGN_RIGHT, false)),
+            "123456"
+        );
+        assert_eq!(
+            pad_i64_test(5, &options(0, ' ', ITOA_ALIGN_RIGHT, true)),
+            "+5"
+        );
+    }
+
+    #[test]
+    fn test_pad_multibyte_fill() {
+        // Width counts characters, not bytes
+        assert_eq!(
+            pad_i64_test(7, &options(4, '·', ITOA_ALIGN_RIGHT, false)),
+            "···7"
+        );
+        let zeroed = ItoaPadOptions {
+            width: 3,
+            fill: 0,
+            align: ITOA_ALIGN_RIGHT,
+            plus_sign: false,
+        };
+        assert_eq!(pad_i64_test(7, &zeroed), "  7", "fill 0 should mean space");
+    }
+
+    #[test]
+    fn test_pad_null_options() {
+        let mut buf = [0u8; 40];
+        let len = unsafe { rust_itoa_pad_i64(-5, std::ptr::null(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-5");
+    }
+
+    #[test]
+    fn test_pad_other_widths() {
+        let opts = options(6, ' ', ITOA_ALIGN_ZERO_PAD, false);
+        let mut buf = [0u8; 64];
+        let len = unsafe { rust_itoa_pad_i8(i8::MIN, &opts, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"-00128");
+        let len = unsafe { rust_itoa_pad_u16(7, &opts, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"000007");
+
+        let wide = options(45, ' ', ITOA_ALIGN_RIGHT, false);
+        let len = unsafe { rust_itoa_pad_i128(i64::MIN, 0, &wide, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(
+            std::str::from_utf8(&buf[..len]).unwrap(),
+            format!("{:>45}", i128::MIN)
+        );
+        let len =
+            unsafe { rust_itoa_pad_u128(u64::MAX, u64::MAX, &wide, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(
+            std::str::from_utf8(&buf[..len]).unwrap(),
+            format!("{:>45}", u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pad_invalid_options() {
+        let mut buf = [0u8; 40];
+        let bad_align = options(4, ' ', 4, false);
+        let len = unsafe { rust_itoa_pad_i64(1, &bad_align, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for unknown align");
+        let mut bad_fill = options(4, ' ', ITOA_ALIGN_RIGHT, false);
+        bad_fill.fill = 0xD800;
+        let len = unsafe { rust_itoa_pad_i64(1, &bad_fill, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for a surrogate fill");
+        let too_wide = options(ITOA_MAX_WIDTH + 1, ' ', ITOA_ALIGN_RIGHT, false);
+        let len = unsafe { rust_itoa_pad_i64(1, &too_wide, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 0, "Should return 0 for too large width");
+        let widest = options(ITOA_MAX_WIDTH, ' ', ITOA_ALIGN_RIGHT, false);
+        let len = unsafe { rust_itoa_pad_i64(1, &widest, std::ptr::null_mut(), 0) };
+        assert_eq!(len, ITOA_MAX_WIDTH as usize, "Should accept ITOA_MAX_WIDTH");
+    }
+
+    #[test]
+    fn test_pad_reports_required_length() {
+        let opts = options(8, '·', ITOA_ALIGN_LEFT, false);
+        let len = unsafe { rust_itoa_pad_i64(42, &opts, std::ptr::null_mut(), 0) };
+        assert_eq!(
+            len,
+            2 + 6 * '·'.len_utf8(),
+            "Should report the required length"
+        );
+
+        let mut buf = [0u8; 8];
+        let len = unsafe { rust_itoa_pad_i64(42, &opts, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(
+            len, 14,
+            "Should report the required length for a small buffer"
+        );
+    }
+}
@@ -0,0 +1,989 @@
+// ============================================================================
+// Parsing C FFI Wrappers - The inverse of zmij_format_* and rust_itoa_*
+// ============================================================================
+// Parses the longest prefix of the input that forms a number, like strtod but
+// without skipping whitespace. Floats are rounded correctly (Eisel-Lemire with
+// a big-decimal fallback, as in Rust's `str::parse`) and everything
+// zmij_format_* emits is accepted, including `NaN`, `inf` and `-inf`.
+// Functions return a NUM_PARSE_* code and store the value and bytes consumed
+// through the optional out pointers.
+
+use std::slice;
+
+/// The input was parsed into a finite value or an explicit NaN/infinity
+pub const NUM_PARSE_OK: i32 = 0;
+
+/// The input was empty
+pub const NUM_PARSE_EMPTY: i32 = 1;
+
+/// The input does not start with a number; nothing was consumed
+pub const NUM_PARSE_INVALID: i32 = 2;
+
+/// The number is too large for the type; the value is saturated (±inf for floats,
+/// the integer maximum)
+pub const NUM_PARSE_OVERFLOW: i32 = 3;
+
+/// The number is non-zero but too small for the type; the value is ±0
+pub const NUM_PARSE_UNDERFLOW: i32 = 4;
+
+/// The integer is below the type's minimum; the value is saturated
+pub const NUM_PARSE_NEG_OVERFLOW: i32 = 5;
+
+/// The number has more fraction digits than the scale holds; the value is truncated
+pub const NUM_PARSE_EXCESS_PRECISION: i32 = 6;
+
+/// Length of the longest number at the start of `bytes`, 0 if there is none
+///
+/// Grammar: `[+-]? (digits [. digits?]? | . digits) ([eE] [+-]? digits)?`,
+/// or `[+-]? (nan | inf | infinity)` in any case.
+pub(crate) fn scan_float(bytes: &[u8]) -> usize {
+    let mut i = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        i += 1;
+    }
+
+    let rest = &bytes[i..];
+    for word in [&b"infinity"[..], b"inf", b"nan"] {
+        if rest.len() >= word.len() && rest[..word.len()].eq_ignore_ascii_case(word) {
+            return i + word.len();
+        }
+    }
+
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let int_digits = digits(i);
+    i += int_digits;
+    let mut frac_digits = 0;
+    if bytes.get(i) == Some(&b'.') {
+        frac_digits = digits(i + 1);
+        if int_digits > 0 || frac_digits > 0 {
+            i += 1 + frac_digits;
+        }
+    }
+    if int_digits == 0 && frac_digits == 0 {
+        return 0;
+    }
+
+    // The exponent only counts if it has at least one digit
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        let exp_digits = digits(j);
+        if exp_digits > 0 {
+            i = j + exp_digits;
+        }
+    }
+    i
+}
+
+/// Whether the mantissa of a scanned number has a non-zero digit
+fn has_nonzero_digit(number: &[u8]) -> bool {
+    number
+        .iter()
+        .take_while(|&&b| b != b'e' && b != b'E')
+        .any(|&b| (b'1'..=b'9').contains(&b))
+}
+
+/// Parse the leading number of `bytes` into `(code, value, consumed)`
+fn parse_prefix<F>(bytes: &[u8]) -> (i32, F, usize)
+where
+    F: std::str::FromStr + Default + Classify,
+{
+    if bytes.is_empty() {
+        return (NUM_PARSE_EMPTY, F::default(), 0);
+    }
+    let len = scan_float(bytes);
+    // Safety: the scanned prefix is ASCII
+    let text = unsafe { std::str::from_utf8_unchecked(&bytes[..len]) };
+    let Ok(value) = text.parse::<F>() else {
+        return (NUM_PARSE_INVALID, F::default(), 0);
+    };
+
+    let is_number = text.bytes().any(|b| b.is_ascii_digit());
+    let code = if is_number && value.is_infinite() {
+        NUM_PARSE_OVERFLOW
+    } else if value.is_zero() && has_nonzero_digit(text.as_bytes()) {
+        NUM_PARSE_UNDERFLOW
+    } else {
+        NUM_PARSE_OK
+    };
+    (code, value, len)
+}
+
+/// The float properties parse_prefix needs to classify a result
+trait Classify {
+    fn is_infinite(&self) -> bool;
+    fn is_zero(&self) -> bool;
+}
+
+impl Classify for f64 {
+    fn is_infinite(&self) -> bool {
+        f64::is_infinite(*self)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0.0
+    }
+}
+
+impl Classify for f32 {
+    fn is_infinite(&self) -> bool {
+        f32::is_infinite(*self)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0.0
+    }
+}
+
+/// Parse the leading number of a C buffer and store the results
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+unsafe fn parse_into<F>(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut F,
+    out_consumed: *mut usize,
+) -> i32
+where
+    F: std::str::FromStr + Default + Classify,
+{
+    let bytes = if buf.is_null() {
+        &[][..]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(buf, buf_len) }
+    };
+    let (code, value, consumed) = parse_prefix::<F>(bytes);
+
+    // Safety: out pointers are null or valid for writes
+    unsafe {
+        if let Some(out_value) = out_value.as_mut() {
+            *out_value = value;
+        }
+        if let Some(out_consumed) = out_consumed.as_mut() {
+            *out_consumed = consumed;
+        }
+    }
+    code
+}
+
+/// Parse the number at the start of buf as f64, correctly rounded
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// ±inf on OVERFLOW, ±0 on UNDERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_parse_f64(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut f64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: guaranteed by the caller
+    unsafe { parse_into(buf, buf_len, out_value, out_consumed) }
+}
+
+/// Parse the number at the start of buf as f32, correctly rounded
+///
+/// Rounds the decimal input directly to f32, not via f64.
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// ±inf on OVERFLOW, ±0 on UNDERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_parse_f32(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut f32,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: guaranteed by the caller
+    unsafe { parse_into(buf, buf_len, out_value, out_consumed) }
+}
+
+/// An integer scanned by scan_int: sign, saturated magnitude, bytes consumed
+struct ScannedInt {
+    code: i32,
+    negative: bool,
+    magnitude: u128,
+    consumed: usize,
+}
+
+impl ScannedInt {
+    /// The value as i128; magnitudes up to 2^127 wrap to i128::MIN
+    fn signed(&self) -> i128 {
+        if self.negative {
+            (self.magnitude as i128).wrapping_neg()
+        } else {
+            self.magnitude as i128
+        }
+    }
+}
+
+/// Parse `[+-]? digits` and saturate it to `[-max_neg, max_pos]`
+fn scan_int(bytes: &[u8], max_neg: u128, max_pos: u128) -> ScannedInt {
+    let mut scanned = ScannedInt {
+        code: NUM_PARSE_OK,
+        negative: false,
+        magnitude: 0,
+        consumed: 0,
+    };
+    if bytes.is_empty() {
+        scanned.code = NUM_PARSE_EMPTY;
+        return scanned;
+    }
+
+    let mut i = 0;
+    if let Some(&sign @ (b'+' | b'-')) = bytes.first() {
+        scanned.negative = sign == b'-';
+        i += 1;
+    }
+    let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        scanned.code = NUM_PARSE_INVALID;
+        scanned.negative = false;
+        return scanned;
+    }
+    let digits = &bytes[i..i + digits];
+    scanned.consumed = i + digits.len();
+
+    // 19 digits always fit a u64, which is much faster than u128 math
+    let (head, tail) = digits.split_at(digits.len().min(19));
+    let mut small = 0u64;
+    for &digit in head {
+        small = small * 10 + (digit - b'0') as u64;
+    }
+    let mut magnitude = Some(small as u128);
+    for &digit in tail {
+        magnitude = magnitude
+            .and_then(|m| m.checked_mul(10))
+            .and_then(|m| m.checked_add((digit - b'0') as u128));
+    }
+
+    let limit = if scanned.negative { max_neg } else { max_pos };
+    match magnitude {
+        Some(magnitude) if magnitude <= limit => scanned.magnitude = magnitude,
+        _ => {
+            scanned.code = if scanned.negative {
+                NUM_PARSE_NEG_OVERFLOW
+            } else {
+                NUM_PARSE_OVERFLOW
+            };
+            scanned.magnitude = limit;
+        }
+    }
+    scanned
+}
+
+/// Parse a C buffer as an integer and store the results
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_consumed must be null or valid for writes
+unsafe fn atoi_into(
+    buf: *const u8,
+    buf_len: usize,
+    max_neg: u128,
+    max_pos: u128,
+    out_consumed: *mut usize,
+) -> ScannedInt {
+    let bytes = if buf.is_null() {
+        &[][..]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(buf, buf_len) }
+    };
+    let scanned = scan_int(bytes, max_neg, max_pos);
+
+    // Safety: out_consumed is null or valid for writes
+    if let Some(out_consumed) = unsafe { out_consumed.as_mut() } {
+        *out_consumed = scanned.consumed;
+    }
+    scanned
+}
+
+/// Parse the integer at the start of buf as i64
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// i64::MIN/i64::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_i64(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut i64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe {
+        atoi_into(
+            buf,
+            buf_len,
+            i64::MIN.unsigned_abs() as u128,
+            i64::MAX as u128,
+            out_consumed,
+        )
+    };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.signed() as i64;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as u64
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// 0/u64::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_u64(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut u64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { atoi_into(buf, buf_len, 0, u64::MAX as u128, out_consumed) };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.magnitude as u64;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as i32
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// i32::MIN/i32::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_i32(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut i32,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe {
+        atoi_into(
+            buf,
+            buf_len,
+            i32::MIN.unsigned_abs() as u128,
+            i32::MAX as u128,
+            out_consumed,
+        )
+    };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.signed() as i32;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as u32
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// 0/u32::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_u32(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut u32,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { atoi_into(buf, buf_len, 0, u32::MAX as u128, out_consumed) };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.magnitude as u32;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as i16
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// i16::MIN/i16::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_i16(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut i16,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe {
+        atoi_into(
+            buf,
+            buf_len,
+            i16::MIN.unsigned_abs() as u128,
+            i16::MAX as u128,
+            out_consumed,
+        )
+    };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.signed() as i16;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as u16
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// 0/u16::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_u16(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut u16,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { atoi_into(buf, buf_len, 0, u16::MAX as u128, out_consumed) };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.magnitude as u16;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as i8
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// i8::MIN/i8::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_i8(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut i8,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe {
+        atoi_into(
+            buf,
+            buf_len,
+            i8::MIN.unsigned_abs() as u128,
+            i8::MAX as u128,
+            out_consumed,
+        )
+    };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.signed() as i8;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as u8
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_value receives the value (0 on EMPTY/INVALID,
+/// 0/u8::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_u8(
+    buf: *const u8,
+    buf_len: usize,
+    out_value: *mut u8,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { atoi_into(buf, buf_len, 0, u8::MAX as u128, out_consumed) };
+    // Safety: out_value is null or valid for writes
+    if let Some(out_value) = unsafe { out_value.as_mut() } {
+        *out_value = scanned.magnitude as u8;
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as i128, returned as two halves
+///
+/// The value is `(out_hi << 64) | out_lo`, see rust_itoa_i128.
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_hi, out_lo and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. The halves receive the value (0 on EMPTY/INVALID,
+/// i128::MIN/i128::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_i128(
+    buf: *const u8,
+    buf_len: usize,
+    out_hi: *mut i64,
+    out_lo: *mut u64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe {
+        atoi_into(
+            buf,
+            buf_len,
+            i128::MIN.unsigned_abs(),
+            i128::MAX as u128,
+            out_consumed,
+        )
+    };
+    let bits = scanned.signed() as u128;
+    // Safety: out_hi and out_lo are null or valid for writes
+    unsafe {
+        if let Some(out_hi) = out_hi.as_mut() {
+            *out_hi = (bits >> 64) as i64;
+        }
+        if let Some(out_lo) = out_lo.as_mut() {
+            *out_lo = bits as u64;
+        }
+    }
+    scanned.code
+}
+
+/// Parse the integer at the start of buf as u128, returned as two halves
+///
+/// The value is `(out_hi << 64) | out_lo`, see rust_itoa_u128.
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_hi, out_lo and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. The halves receive the value (0 on EMPTY/INVALID,
+/// 0/u128::MAX on NEG_OVERFLOW/OVERFLOW) and out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_atoi_u128(
+    buf: *const u8,
+    buf_len: usize,
+    out_hi: *mut u64,
+    out_lo: *mut u64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { atoi_into(buf, buf_len, 0, u128::MAX, out_consumed) };
+    let bits = scanned.magnitude;
+    // Safety: out_hi and out_lo are null or valid for writes
+    unsafe {
+        if let Some(out_hi) = out_hi.as_mut() {
+            *out_hi = (bits >> 64) as u64;
+        }
+        if let Some(out_lo) = out_lo.as_mut() {
+            *out_lo = bits as u64;
+        }
+    }
+    scanned.code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_f64_test(text: &str) -> (i32, f64, usize) {
+        let mut value = f64::NAN;
+        let mut consumed = usize::MAX;
+        let code = unsafe { rust_parse_f64(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        (code, value, consumed)
+    }
+
+    fn parse_f32_test(text: &str) -> (i32, f32, usize) {
+        let mut value = f32::NAN;
+        let mut consumed = usize::MAX;
+        let code = unsafe { rust_parse_f32(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        (code, value, consumed)
+    }
+
+    /// xorshift64, enough to sample bit patterns deterministically
+    fn next_random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn test_parse_f64_simple() {
+        assert_eq!(parse_f64_test("0"), (NUM_PARSE_OK, 0.0, 1));
+        assert_eq!(parse_f64_test("1.5"), (NUM_PARSE_OK, 1.5, 3));
+        assert_eq!(parse_f64_test("-2.5e-3"), (NUM_PARSE_OK, -2.5e-3, 7));
+        assert_eq!(parse_f64_test("+1E+20"), (NUM_PARSE_OK, 1e20, 6));
+        assert_eq!(parse_f64_test(".5"), (NUM_PARSE_OK, 0.5, 2));
+        assert_eq!(parse_f64_test("7."), (NUM_PARSE_OK, 7.0, 2));
+    }
+
+    #[test]
+    fn test_parse_f64_prefix() {
+        assert_eq!(parse_f64_test("12.5abc"), (NUM_PARSE_OK, 12.5, 4));
+        assert_eq!(parse_f64_test("3e"), (NUM_PARSE_OK, 3.0, 1));
+        assert_eq!(parse_f64_test("3e+x"), (NUM_PARSE_OK, 3.0, 1));
+        assert_eq!(parse_f64_test("1.2.3"), (NUM_PARSE_OK, 1.2, 3));
+        assert_eq!(parse_f64_test("42,0"), (NUM_PARSE_OK, 42.0, 2));
+        assert_eq!(parse_f64_test("info"), (NUM_PARSE_OK, f64::INFINITY, 3));
+    }
+
+    #[test]
+    fn test_parse_f64_special_values() {
+        let (code, value, consumed) = parse_f64_test("NaN");
+        assert_eq!((code, consumed), (NUM_PARSE_OK, 3));
+        assert!(value.is_nan());
+        assert_eq!(parse_f64_test("inf"), (NUM_PARSE_OK, f64::INFINITY, 3));
+        assert_eq!(parse_f64_test("-inf"), (NUM_PARSE_OK, f64::NEG_INFINITY, 4));
+        assert_eq!(parse_f64_test("Infinity"), (NUM_PARSE_OK, f64::INFINITY, 8));
+        let (_, value, _) = parse_f64_test("-0");
+        assert!(value == 0.0 && value.is_sign_negative());
+    }
+
+    #[test]
+    fn test_parse_f64_errors() {
+        assert_eq!(parse_f64_test(""), (NUM_PARSE_EMPTY, 0.0, 0));
+        assert_eq!(parse_f64_test("abc"), (NUM_PARSE_INVALID, 0.0, 0));
+        assert_eq!(parse_f64_test("-"), (NUM_PARSE_INVALID, 0.0, 0));
+        assert_eq!(parse_f64_test("."), (NUM_PARSE_INVALID, 0.0, 0));
+        assert_eq!(parse_f64_test(" 1"), (NUM_PARSE_INVALID, 0.0, 0));
+        assert_eq!(parse_f64_test("e5"), (NUM_PARSE_INVALID, 0.0, 0));
+    }
+
+    #[test]
+    fn test_parse_f64_overflow_underflow() {
+        assert_eq!(
+            parse_f64_test("1e309"),
+            (NUM_PARSE_OVERFLOW, f64::INFINITY, 5)
+        );
+        assert_eq!(
+            parse_f64_test("-1e99999999999999999999"),
+            (NUM_PARSE_OVERFLOW, f64::NEG_INFINITY, 23)
+        );
+        assert_eq!(parse_f64_test("1e-400"), (NUM_PARSE_UNDERFLOW, 0.0, 6));
+        let (code, value, _) = parse_f64_test("-1e-400");
+        assert_eq!(code, NUM_PARSE_UNDERFLOW);
+        assert!(value.is_sign_negative());
+        assert_eq!(parse_f64_test("0e-400"), (NUM_PARSE_OK, 0.0, 6));
+        assert_eq!(parse_f64_test("0.000e999"), (NUM_PARSE_OK, 0.0, 9));
+        assert_eq!(parse_f64_test("5e-324"), (NUM_PARSE_OK, 5e-324, 6));
+    }
+
+    #[test]
+    fn test_parse_f64_correct_rounding() {
+        // Halfway between 1 and the next f64, rounds to even
+        assert_eq!(
+            parse_f64_test("1.00000000000000011102230246251565404236316680908203125").1,
+            1.0
+        );
+        assert_eq!(
+            parse_f64_test("1.00000000000000011102230246251565404236316680908203126").1,
+            1.0000000000000002
+        );
+        assert_eq!(
+            parse_f64_test("2.2250738585072011e-308").1,
+            2.225073858507201e-308
+        );
+        let long = format!("0.{}1", "0".repeat(500));
+        assert_eq!(parse_f64_test(&long).0, NUM_PARSE_UNDERFLOW);
+    }
+
+    #[test]
+    fn test_parse_f32_rounds_directly() {
+        // Rounding through f64 first would give 1.0000001
+        assert_eq!(parse_f32_test("1.000000059604644775390625001").1, 1.0000001);
+        assert_eq!(
+            parse_f32_test("3.4028236e38"),
+            (NUM_PARSE_OVERFLOW, f32::INFINITY, 12)
+        );
+        assert_eq!(parse_f32_test("1e-46").0, NUM_PARSE_UNDERFLOW);
+        assert_eq!(parse_f32_test("0.1"), (NUM_PARSE_OK, 0.1f32, 3));
+    }
+
+    #[test]
+    fn test_parse_roundtrips_zmij_f64_output() {
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        let mut buf = [0u8; 24];
+        let specials = [
+            0.0,
+            -0.0,
+            5e-324,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            1e15,
+            1e16,
+            0.1,
+        ];
+        let random = (0..20_000).map(|_| f64::from_bits(next_random(&mut state)));
+        for value in specials.into_iter().chain(random) {
+            let len = unsafe { crate::zmij_format_f64(value, buf.as_mut_ptr(), buf.len()) };
+            let text = std::str::from_utf8(&buf[..len]).unwrap();
+            let (code, parsed, consumed) = parse_f64_test(text);
+            assert_eq!(consumed, len, "partial parse of {}", text);
+            if value.is_nan() {
+                assert!(parsed.is_nan());
+            } else {
+                assert_eq!(code, NUM_PARSE_OK, "error parsing {}", text);
+                assert_eq!(parsed.to_bits(), value.to_bits(), "mismatch for {}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrips_zmij_f32_output() {
+        let mut state = 0x2545_F491_4F6C_DD1D;
+        let mut buf = [0u8; 24];
+        let specials = [0.0f32, -0.0, 1e-45, f32::MIN_POSITIVE, f32::MAX, 16777216.0];
+        let random = (0..20_000).map(|_| f32::from_bits(next_random(&mut state) as u32));
+        for value in specials.into_iter().chain(random) {
+            let len = unsafe { crate::zmij_format_f32(value, buf.as_mut_ptr(), buf.len()) };
+            let text = std::str::from_utf8(&buf[..len]).unwrap();
+            let (code, parsed, consumed) = parse_f32_test(text);
+            assert_eq!(consumed, len, "partial parse of {}", text);
+            if value.is_nan() {
+                assert!(parsed.is_nan());
+            } else {
+                assert_eq!(code, NUM_PARSE_OK, "error parsing {}", text);
+                assert_eq!(parsed.to_bits(), value.to_bits(), "mismatch for {}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_null_pointers() {
+        let code = unsafe {
+            rust_parse_f64(
+                std::ptr::null(),
+                8,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, NUM_PARSE_EMPTY, "Should treat null buffer as empty");
+
+        let text = b"2.5";
+        let code = unsafe {
+            rust_parse_f64(
+                text.as_ptr(),
+                text.len(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, NUM_PARSE_OK, "Out pointers are optional");
+    }
+
+    fn atoi_i64_test(text: &str) -> (i32, i64, usize) {
+        let mut value = 12345;
+        let mut consumed = usize::MAX;
+        let code = unsafe { rust_atoi_i64(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        (code, value, consumed)
+    }
+
+    fn atoi_u8_test(text: &str) -> (i32, u8, usize) {
+        let mut value = 123;
+        let mut consumed = usize::MAX;
+        let code = unsafe { rust_atoi_u8(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        (code, value, consumed)
+    }
+
+    fn atoi_i128_test(text: &str) -> (i32, i128, usize) {
+        let (mut hi, mut lo, mut consumed) = (1, 1, usize::MAX);
+        let code =
+            unsafe { rust_atoi_i128(text.as_ptr(), text.len(), &mut hi, &mut lo, &mut consumed) };
+        (code, ((hi as i128) << 64) | lo as i128, consumed)
+    }
+
+    #[test]
+    fn test_atoi_i64_simple() {
+        assert_eq!(atoi_i64_test("0"), (NUM_PARSE_OK, 0, 1));
+        assert_eq!(atoi_i64_test("42"), (NUM_PARSE_OK, 42, 2));
+        assert_eq!(atoi_i64_test("-42"), (NUM_PARSE_OK, -42, 3));
+        assert_eq!(atoi_i64_test("+7"), (NUM_PARSE_OK, 7, 2));
+        assert_eq!(
+            atoi_i64_test("0000000000000000000000000001"),
+            (NUM_PARSE_OK, 1, 28)
+        );
+        assert_eq!(
+            atoi_i64_test("-9223372036854775808"),
+            (NUM_PARSE_OK, i64::MIN, 20)
+        );
+        assert_eq!(
+            atoi_i64_test("9223372036854775807"),
+            (NUM_PARSE_OK, i64::MAX, 19)
+        );
+    }
+
+    #[test]
+    fn test_atoi_prefix() {
+        assert_eq!(atoi_i64_test("123,456"), (NUM_PARSE_OK, 123, 3));
+        assert_eq!(atoi_i64_test("12.5"), (NUM_PARSE_OK, 12, 2));
+        assert_eq!(atoi_i64_test("-3\n"), (NUM_PARSE_OK, -3, 2));
+    }
+
+    #[test]
+    fn test_atoi_errors() {
+        assert_eq!(atoi_i64_test(""), (NUM_PARSE_EMPTY, 0, 0));
+        assert_eq!(atoi_i64_test("x1"), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(atoi_i64_test("-"), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(atoi_i64_test(" 1"), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(atoi_i64_test("+-1"), (NUM_PARSE_INVALID, 0, 0));
+    }
+
+    #[test]
+    fn test_atoi_overflow_saturates() {
+        assert_eq!(
+            atoi_i64_test("9223372036854775808"),
+            (NUM_PARSE_OVERFLOW, i64::MAX, 19)
+        );
+        assert_eq!(
+            atoi_i64_test("-9223372036854775809"),
+            (NUM_PARSE_NEG_OVERFLOW, i64::MIN, 20)
+        );
+        let huge = "9".repeat(100);
+        assert_eq!(atoi_i64_test(&huge), (NUM_PARSE_OVERFLOW, i64::MAX, 100));
+        assert_eq!(atoi_u8_test("256"), (NUM_PARSE_OVERFLOW, u8::MAX, 3));
+        assert_eq!(atoi_u8_test("-1"), (NUM_PARSE_NEG_OVERFLOW, 0, 2));
+        assert_eq!(atoi_u8_test("-0"), (NUM_PARSE_OK, 0, 2));
+        assert_eq!(atoi_u8_test("255"), (NUM_PARSE_OK, 255, 3));
+    }
+
+    #[test]
+    fn test_atoi_small_widths() {
+        let text = b"-129";
+        let (mut value, mut consumed) = (0i8, 0);
+        let code = unsafe { rust_atoi_i8(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        assert_eq!((code, value), (NUM_PARSE_NEG_OVERFLOW, i8::MIN));
+
+        let text = b"65535";
+        let mut value = 0u16;
+        let code = unsafe { rust_atoi_u16(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        assert_eq!((code, value), (NUM_PARSE_OK, u16::MAX));
+
+        let text = b"-32768";
+        let mut value = 0i16;
+        let code = unsafe { rust_atoi_i16(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        assert_eq!((code, value), (NUM_PARSE_OK, i16::MIN));
+
+        let text = b"4294967296";
+        let mut value = 0u32;
+        let code = unsafe { rust_atoi_u32(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        assert_eq!((code, value), (NUM_PARSE_OVERFLOW, u32::MAX));
+
+        let text = b"-2147483648";
+        let mut value = 0i32;
+        let code = unsafe { rust_atoi_i32(text.as_ptr(), text.len(), &mut value, &mut consumed) };
+        assert_eq!((code, value, consumed), (NUM_PARSE_OK, i32::MIN, 11));
+    }
+
+    #[test]
+    fn test_atoi_128_halves() {
+        assert_eq!(
+            atoi_i128_test("-170141183460469231731687303715884105728"),
+            (NUM_PARSE_OK, i128::MIN, 40)
+        );
+        assert_eq!(
+            atoi_i128_test("170141183460469231731687303715884105728"),
+            (NUM_PARSE_OVERFLOW, i128::MAX, 39)
+        );
+        assert_eq!(
+            atoi_i128_test("-18446744073709551616"),
+            (NUM_PARSE_OK, -(1 << 64), 21)
+        );
+
+        let text = b"340282366920938463463374607431768211455";
+        let (mut hi, mut lo, mut consumed) = (0u64, 0u64, 0);
+        let code =
+            unsafe { rust_atoi_u128(text.as_ptr(), text.len(), &mut hi, &mut lo, &mut consumed) };
+        assert_eq!((code, hi, lo), (NUM_PARSE_OK, u64::MAX, u64::MAX));
+
+        let text = b"340282366920938463463374607431768211456";
+        let code =
+            unsafe { rust_atoi_u128(text.as_ptr(), text.len(), &mut hi, &mut lo, &mut consumed) };
+        assert_eq!((code, hi, lo), (NUM_PARSE_OVERFLOW, u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_atoi_roundtrips_itoa_output() {
+        let mut state = 0xD1B5_4A32_D192_ED03;
+        let mut buf = [0u8; 40];
+        for _ in 0..20_000 {
+            let value = next_random(&mut state) as i64 >> (next_random(&mut state) % 64);
+            let len = unsafe { crate::rust_itoa_i64(value, buf.as_mut_ptr(), buf.len()) };
+            let text = std::str::from_utf8(&buf[..len]).unwrap();
+            assert_eq!(atoi_i64_test(text), (NUM_PARSE_OK, value, len));
+        }
+        assert_eq!(atoi_i64_test(&i64::MIN.to_string()).1, i64::MIN);
+    }
+
+    #[test]
+    fn test_atoi_null_pointers() {
+        let code = unsafe {
+            rust_atoi_u64(
+                std::ptr::null(),
+                8,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, NUM_PARSE_EMPTY, "Should treat null buffer as empty");
+
+        let text = b"12";
+        let code = unsafe {
+            rust_atoi_u128(
+                text.as_ptr(),
+                text.len(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, NUM_PARSE_OK, "Out pointers are optional");
+    }
+}
@@ -0,0 +1,329 @@
+// ============================================================================
+// Non-finite policy C FFI Wrappers - Spellings of NaN, infinity and -0.0
+// ============================================================================
+// zmij_format_f64 always prints `NaN`, `inf`, `-inf` and keeps `-0.0`. These
+// variants take a ZmijFloatPolicy choosing a preset (C, JavaScript, YAML,
+// JSON), optional custom strings, and whether negative zero prints as `0.0`.
+// Finite values are formatted exactly like zmij_format_f64, and the `_fit`
+// variants go through the same code with the zmij preset. NumFormatter
+// profiles resolve their spellings through a policy as well. Functions return
+// the length the output requires and write it only if it is <= buf_len
+// (snprintf-style); 0 means the preset was unknown.
+
+use crate::grouping::c_bytes;
+use crate::out::Out;
+
+/// `NaN`, `inf`, `-inf` (zmij_format_f64)
+pub const ZMIJ_NON_FINITE_ZMIJ: u8 = 0;
+
+/// `nan`, `inf`, `-inf` (C printf)
+pub const ZMIJ_NON_FINITE_C: u8 = 1;
+
+/// `NaN`, `Infinity`, `-Infinity` (JavaScript)
+pub const ZMIJ_NON_FINITE_JS: u8 = 2;
+
+/// `.nan`, `.inf`, `-.inf` (YAML)
+pub const ZMIJ_NON_FINITE_YAML: u8 = 3;
+
+/// `null` for every non-finite value (JSON)
+pub const ZMIJ_NON_FINITE_JSON: u8 = 4;
+
+/// How non-finite values and negative zero are printed
+///
+/// Non-null strings override the preset's spelling for that value. A null
+/// pointer behaves like a zeroed struct: zmij spellings, `-0.0` kept.
+#[repr(C)]
+pub struct ZmijFloatPolicy {
+    /// One of the ZMIJ_NON_FINITE_* presets
+    pub preset: u8,
+    /// Print negative zero as `0.0`
+    pub normalize_negative_zero: bool,
+    /// Spelling of NaN (UTF-8, null for the preset's)
+    pub nan: *const u8,
+    /// Length of nan in bytes
+    pub nan_len: usize,
+    /// Spelling of positive infinity (UTF-8, null for the preset's)
+    pub inf: *const u8,
+    /// Length of inf in bytes
+    pub inf_len: usize,
+    /// Spelling of negative infinity (UTF-8, null for the preset's)
+    pub neg_inf: *const u8,
+    /// Length of neg_inf in bytes
+    pub neg_inf_len: usize,
+}
+
+/// Resolved policy, shared by every formatter that honours one
+#[derive(Clone, Copy)]
+pub(crate) struct FloatPolicy<'a> {
+    pub(crate) nan: &'a [u8],
+    pub(crate) inf: &'a [u8],
+    pub(crate) neg_inf: &'a [u8],
+    pub(crate) normalize_negative_zero: bool,
+}
+
+impl FloatPolicy<'static> {
+    pub(crate) const ZMIJ: Self = FloatPolicy {
+        nan: b"NaN",
+        inf: b"inf",
+        neg_inf: b"-inf",
+        normalize_negative_zero: false,
+    };
+
+    fn preset(preset: u8) -> Option<Self> {
+        let (nan, inf, neg_inf): (&[u8], &[u8], &[u8]) = match preset {
+            ZMIJ_NON_FINITE_ZMIJ => (b"NaN", b"inf", b"-inf"),
+            ZMIJ_NON_FINITE_C => (b"nan", b"inf", b"-inf"),
+            ZMIJ_NON_FINITE_JS => (b"NaN", b"Infinity", b"-Infinity"),
+            ZMIJ_NON_FINITE_YAML => (b".nan", b".inf", b"-.inf"),
+            ZMIJ_NON_FINITE_JSON => (b"null", b"null", b"null"),
+            _ => return None,
+        };
+        Some(FloatPolicy {
+            nan,
+            inf,
+            neg_inf,
+            normalize_negative_zero: false,
+        })
+    }
+}
+
+/// Resolve a possibly null policy pointer; None for an unknown preset
+///
+/// # Safety
+/// - policy must be null or point to a valid ZmijFloatPolicy whose strings
+///   are null or valid for their lengths
+pub(crate) unsafe fn float_policy<'a>(policy: *const ZmijFloatPolicy) -> Option<FloatPolicy<'a>> {
+    // Safety: guaranteed by the caller
+    let Some(policy) = (unsafe { policy.as_ref() }) else {
+        return Some(FloatPolicy::ZMIJ);
+    };
+    let preset = FloatPolicy::preset(policy.preset)?;
+    // Safety: guaranteed by the caller
+    let custom = |ptr: *const u8, len: usize, default: &'static [u8]| {
+        if ptr.is_null() {
+            default
+        } else {
+            unsafe { c_bytes(ptr, len) }
+        }
+    };
+    Some(FloatPolicy {
+        nan: custom(policy.nan, policy.nan_len, preset.nan),
+        inf: custom(policy.inf, policy.inf_len, preset.inf),
+        neg_inf: custom(policy.neg_inf, policy.neg_inf_len, preset.neg_inf),
+        normalize_negative_zero: policy.normalize_negative_zero,
+    })
+}
+
+/// Write NaN or an infinity as `policy` spells it; false if `value` is finite
+pub(crate) fn write_non_finite(out: &mut Out, value: f64, policy: &FloatPolicy) -> bool {
+    if value.is_nan() {
+        out.push_bytes(policy.nan);
+    } else if value == f64::INFINITY {
+        out.push_bytes(policy.inf);
+    } else if value == f64::NEG_INFINITY {
+        out.push_bytes(policy.neg_inf);
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Write the shortest representation of `value` under `policy`
+pub(crate) fn write_float<F: zmij::Float + Into<f64> + Copy>(
+    out: &mut Out,
+    value: F,
+    policy: &FloatPolicy,
+) {
+    let wide: f64 = value.into();
+    if write_non_finite(out, wide, policy) {
+        return;
+    }
+    if wide == 0.0 && policy.normalize_negative_zero {
+        out.push_bytes(b"0.0");
+    } else {
+        let mut buffer = zmij::Buffer::new();
+        out.push_bytes(buffer.format_finite(value).as_bytes());
+    }
+}
+
+/// Format f64 with the non-finite spellings of a policy (null = zmij's)
+///
+/// # Safety
+/// - policy must be null or point to a valid ZmijFloatPolicy whose strings
+///   are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the preset is unknown
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_policy_f64(
+    value: f64,
+    policy: *const ZmijFloatPolicy,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(policy) = (unsafe { float_policy(policy) }) else {
+        return 0;
+    };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_float(&mut out, value, &policy);
+    out.len()
+}
+
+/// Format f32 with the non-finite spellings of a policy (null = zmij's)
+///
+/// # Safety
+/// - policy must be null or point to a valid ZmijFloatPolicy whose strings
+///   are null or valid for their lengths
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the preset is unknown
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_policy_f32(
+    value: f32,
+    policy: *const ZmijFloatPolicy,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(policy) = (unsafe { float_policy(policy) }) else {
+        return 0;
+    };
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    write_float(&mut out, value, &policy);
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(preset: u8, normalize_negative_zero: bool) -> ZmijFloatPolicy {
+        ZmijFloatPolicy {
+            preset,
+            normalize_negative_zero,
+            nan: std::ptr::null(),
+            nan_len: 0,
+            inf: std::ptr::null(),
+            inf_len: 0,
+            neg_inf: std::ptr::null(),
+            neg_inf_len: 0,
+        }
+    }
+
+    fn policy_f64_test(value: f64, policy: &ZmijFloatPolicy) -> String {
+        let mut buf = [0u8; 64];
+        let len = unsafe { zmij_format_policy_f64(value, policy, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "zmij_format_policy_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_policy_presets() {
+        let cases = [
+            (ZMIJ_NON_FINITE_ZMIJ, ["NaN", "inf", "-inf"]),
+            (ZMIJ_NON_FINITE_C, ["nan", "inf", "-inf"]),
+            (ZMIJ_NON_FINITE_JS, ["NaN", "Infinity", "-Infinity"]),
+            (ZMIJ_NON_FINITE_YAML, [".nan", ".inf", "-.inf"]),
+            (ZMIJ_NON_FINITE_JSON, ["null", "null", "null"]),
+        ];
+        for (preset, [nan, inf, neg_inf]) in cases {
+            let policy = policy(preset, false);
+            assert_eq!(policy_f64_test(f64::NAN, &policy), nan);
+            assert_eq!(policy_f64_test(f64::INFINITY, &policy), inf);
+            assert_eq!(policy_f64_test(f64::NEG_INFINITY, &policy), neg_inf);
+            assert_eq!(policy_f64_test(1.5, &policy), "1.5");
+        }
+    }
+
+    #[test]
+    fn test_policy_custom_strings_override_preset() {
+        let mut custom = policy(ZMIJ_NON_FINITE_JS, false);
+        custom.nan = "n/a".as_ptr();
+        custom.nan_len = 3;
+        custom.neg_inf = "−∞".as_ptr();
+        custom.neg_inf_len = "−∞".len();
+        assert_eq!(policy_f64_test(f64::NAN, &custom), "n/a");
+        assert_eq!(policy_f64_test(f64::INFINITY, &custom), "Infinity");
+        assert_eq!(policy_f64_test(f64::NEG_INFINITY, &custom), "−∞");
+    }
+
+    #[test]
+    fn test_policy_negative_zero() {
+        assert_eq!(
+            policy_f64_test(-0.0, &policy(ZMIJ_NON_FINITE_ZMIJ, false)),
+            "-0.0"
+        );
+        assert_eq!(
+            policy_f64_test(-0.0, &policy(ZMIJ_NON_FINITE_ZMIJ, true)),
+            "0.0"
+        );
+        assert_eq!(
+            policy_f64_test(0.0, &policy(ZMIJ_NON_FINITE_ZMIJ, true)),
+            "0.0"
+        );
+        assert_eq!(
+            policy_f64_test(-1e-300, &policy(ZMIJ_NON_FINITE_ZMIJ, true)),
+            "-1e-300"
+        );
+    }
+
+    #[test]
+    fn test_policy_null_matches_zmij() {
+        let mut zmij = zmij::Buffer::new();
+        let mut buf = [0u8; 64];
+        for value in [
+            0.1,
+            -0.0,
+            1e300,
+            f64::NAN,
+            f64::NEG_INFINITY,
+            f64::MIN_POSITIVE,
+        ] {
+            let len = unsafe {
+                zmij_format_policy_f64(value, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+            };
+            assert_eq!(&buf[..len], zmij.format(value).as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_policy_f32() {
+        let mut buf = [0u8; 64];
+        let yaml = policy(ZMIJ_NON_FINITE_YAML, true);
+        let len = unsafe {
+            zmij_format_policy_f32(f32::NEG_INFINITY, &yaml, buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"-.inf");
+        let len = unsafe { zmij_format_policy_f32(-0.0, &yaml, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0.0");
+        let len = unsafe { zmij_format_policy_f32(0.1, &yaml, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0.1");
+    }
+
+    #[test]
+    fn test_policy_invalid_preset() {
+        let mut buf = [0u8; 64];
+        let bad = policy(5, false);
+        let len = unsafe { zmij_format_policy_f64(1.0, &bad, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for an unknown preset");
+    }
+
+    #[test]
+    fn test_policy_reports_required_length() {
+        let js = policy(ZMIJ_NON_FINITE_JS, false);
+        let len =
+            unsafe { zmij_format_policy_f64(f64::NEG_INFINITY, &js, std::ptr::null_mut(), 0) };
+        assert_eq!(len, "-Infinity".len(), "Should report the required length");
+    }
+}
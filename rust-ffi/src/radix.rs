@@ -0,0 +1,499 @@
+// ============================================================================
+// Radix itoa C FFI Wrappers - Integers in any base from 2 to 36
+// ============================================================================
+// Digits above 9 are letters (`a`-`z`, or `A`-`Z` with ITOA_RADIX_UPPERCASE).
+// Negative values print as `-` and the magnitude, or with
+// ITOA_RADIX_TWOS_COMPLEMENT as the unsigned bit pattern of their own width.
+// Functions return the length the output requires and write it only if it is
+// <= buf_len (snprintf-style); 0 means the radix or flags were invalid.
+
+use crate::out::Out;
+use crate::{i128_from_halves, u128_from_halves};
+
+/// Use upper-case letters for digits above 9
+pub const ITOA_RADIX_UPPERCASE: u32 = 1;
+
+/// Prefix base 16, 8 and 2 with `0x`, `0o` and `0b` (other bases have none)
+pub const ITOA_RADIX_PREFIX: u32 = 2;
+
+/// Print negative values as the two's-complement bit pattern of their width
+pub const ITOA_RADIX_TWOS_COMPLEMENT: u32 = 4;
+
+/// Buffer size that fits any integer in any radix: sign, prefix, 128 digits
+#[allow(dead_code, reason = "only used from C and Odin")]
+pub const ITOA_RADIX_BUFFER_SIZE: usize = 131;
+
+const ALL_FLAGS: u32 = ITOA_RADIX_UPPERCASE | ITOA_RADIX_PREFIX | ITOA_RADIX_TWOS_COMPLEMENT;
+
+const LOWER_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const UPPER_DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Write `magnitude` in `radix`, preceded by `-` when `negative`
+///
+/// Returns false (writing nothing) for an invalid radix or unknown flags.
+pub(crate) fn write_radix(
+    out: &mut Out,
+    negative: bool,
+    magnitude: u128,
+    radix: u32,
+    flags: u32,
+) -> bool {
+    if !(2..=36).contains(&radix) || flags & !ALL_FLAGS != 0 {
+        return false;
+    }
+    let table = if flags & ITOA_RADIX_UPPERCASE != 0 {
+        UPPER_DIGITS
+    } else {
+        LOWER_DIGITS
+    };
+
+    // Digits are produced least significant first
+    let mut digits = [0u8; 128];
+    let mut start = digits.len();
+    if radix.is_power_of_two() {
+        let shift = radix.trailing_zeros();
+        let mask = radix as u128 - 1;
+        let mut rest = magnitude;
+        loop {
+            start -= 1;
+            digits[start] = table[(rest & mask) as usize];
+            rest >>= shift;
+            if rest == 0 {
+                break;
+            }
+        }
+    } else {
+        let radix_wide = radix as u128;
+        let mut rest = magnitude;
+        // Stay in u128 only until the value fits the much faster u64 path
+        while rest > u64::MAX as u128 {
+            start -= 1;
+            digits[start] = table[(rest % radix_wide) as usize];
+            rest /= radix_wide;
+        }
+        let radix = radix as u64;
+        let mut rest = rest as u64;
+        loop {
+            start -= 1;
+            digits[start] = table[(rest % radix) as usize];
+            rest /= radix;
+            if rest == 0 {
+                break;
+            }
+        }
+    }
+
+    if negative {
+        out.push(b'-');
+    }
+    if flags & ITOA_RADIX_PREFIX != 0 {
+        let marker = match radix {
+            16 => Some(b'x'),
+            8 => Some(b'o'),
+            2 => Some(b'b'),
+            _ => None,
+        };
+        if let Some(marker) = marker {
+            out.push(b'0');
+            out.push(marker);
+        }
+    }
+    out.push_bytes(&digits[start..]);
+    true
+}
+
+/// Write a signed value whose two's-complement pattern is `bits` wide
+fn write_signed(out: &mut Out, value: i128, bits: u32, radix: u32, flags: u32) -> bool {
+    if value < 0 && flags & ITOA_RADIX_TWOS_COMPLEMENT != 0 {
+        let pattern = (value as u128) & (u128::MAX >> (128 - bits));
+        write_radix(out, false, pattern, radix, flags)
+    } else {
+        write_radix(out, value < 0, value.unsigned_abs(), radix, flags)
+    }
+}
+
+/// Format i64 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_i64(
+    value: i64,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_signed(&mut out, value as i128, 64, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format u64 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_u64(
+    value: u64,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_radix(&mut out, false, value as u128, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format i32 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_i32(
+    value: i32,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_signed(&mut out, value as i128, 32, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format u32 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_u32(
+    value: u32,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_radix(&mut out, false, value as u128, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format i16 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_i16(
+    value: i16,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_signed(&mut out, value as i128, 16, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format u16 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_u16(
+    value: u16,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_radix(&mut out, false, value as u128, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format i8 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_i8(
+    value: i8,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_signed(&mut out, value as i128, 8, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format u8 integer in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_u8(
+    value: u8,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_radix(&mut out, false, value as u128, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format i128 integer, passed as `(hi << 64) | lo`, in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_i128(
+    hi: i64,
+    lo: u64,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_signed(&mut out, i128_from_halves(hi, lo), 128, radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format u128 integer, passed as `(hi << 64) | lo`, in the given radix
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if radix is outside 2..=36 or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_itoa_radix_u128(
+    hi: u64,
+    lo: u64,
+    radix: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_radix(&mut out, false, u128_from_halves(hi, lo), radix, flags) {
+        return 0;
+    }
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn radix_i64_test(value: i64, radix: u32, flags: u32) -> String {
+        let mut buf = [0u8; ITOA_RADIX_BUFFER_SIZE];
+        let len = unsafe { rust_itoa_radix_i64(value, radix, flags, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "rust_itoa_radix_i64 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    fn radix_u64_test(value: u64, radix: u32, flags: u32) -> String {
+        let mut buf = [0u8; ITOA_RADIX_BUFFER_SIZE];
+        let len = unsafe { rust_itoa_radix_u64(value, radix, flags, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "rust_itoa_radix_u64 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    #[test]
+    fn test_radix_common_bases() {
+        assert_eq!(radix_u64_test(255, 16, 0), "ff");
+        assert_eq!(radix_u64_test(255, 16, ITOA_RADIX_UPPERCASE), "FF");
+        assert_eq!(radix_u64_test(8, 8, 0), "10");
+        assert_eq!(radix_u64_test(5, 2, 0), "101");
+        assert_eq!(radix_u64_test(0, 2, 0), "0");
+        assert_eq!(radix_u64_test(35, 36, 0), "z");
+        assert_eq!(radix_u64_test(1295, 36, ITOA_RADIX_UPPERCASE), "ZZ");
+    }
+
+    #[test]
+    fn test_radix_matches_std() {
+        let values = [0u64, 1, 7, 100, 0xdead_beef, u64::MAX, 1 << 63];
+        for value in values {
+            assert_eq!(radix_u64_test(value, 16, 0), format!("{:x}", value));
+            assert_eq!(radix_u64_test(value, 8, 0), format!("{:o}", value));
+            assert_eq!(radix_u64_test(value, 2, 0), format!("{:b}", value));
+            assert_eq!(radix_u64_test(value, 10, 0), value.to_string());
+            for radix in 2..=36 {
+                let text = radix_u64_test(value, radix, 0);
+                assert_eq!(u64::from_str_radix(&text, radix), Ok(value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix_prefix() {
+        assert_eq!(radix_u64_test(255, 16, ITOA_RADIX_PREFIX), "0xff");
+        assert_eq!(radix_u64_test(8, 8, ITOA_RADIX_PREFIX), "0o10");
+        assert_eq!(radix_u64_test(5, 2, ITOA_RADIX_PREFIX), "0b101");
+        assert_eq!(radix_u64_test(42, 10, ITOA_RADIX_PREFIX), "42");
+        assert_eq!(radix_i64_test(-255, 16, ITOA_RADIX_PREFIX), "-0xff");
+        assert_eq!(
+            radix_u64_test(0xab, 16, ITOA_RADIX_PREFIX | ITOA_RADIX_UPPERCASE),
+            "0xAB"
+        );
+    }
+
+    #[test]
+    fn test_radix_negative_signed() {
+        assert_eq!(radix_i64_test(-1, 16, 0), "-1");
+        assert_eq!(radix_i64_test(i64::MIN, 16, 0), "-8000000000000000");
+        assert_eq!(radix_i64_test(-10, 2, 0), "-1010");
+    }
+
+    #[test]
+    fn test_radix_twos_complement_uses_width() {
+        let flags = ITOA_RADIX_TWOS_COMPLEMENT;
+        let mut buf = [0u8; ITOA_RADIX_BUFFER_SIZE];
+
+        let len = unsafe { rust_itoa_radix_i8(-1, 16, flags, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"ff");
+        let len = unsafe { rust_itoa_radix_i16(-2, 16, flags, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"fffe");
+        let len = unsafe { rust_itoa_radix_i32(-1, 2, flags, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 32);
+        assert_eq!(radix_i64_test(-1, 16, flags), "ffffffffffffffff");
+        assert_eq!(radix_i64_test(-1, 16, flags), format!("{:x}", -1i64));
+        assert_eq!(radix_i64_test(42, 16, flags), "2a");
+
+        let len =
+            unsafe { rust_itoa_radix_i128(-1, u64::MAX, 16, flags, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 32);
+        assert_eq!(&buf[..len], format!("{:x}", -1i128).as_bytes());
+    }
+
+    #[test]
+    fn test_radix_128_bit_worst_case() {
+        let mut buf = [0u8; ITOA_RADIX_BUFFER_SIZE];
+        let flags = ITOA_RADIX_PREFIX;
+        let len =
+            unsafe { rust_itoa_radix_i128(i64::MIN, 0, 2, flags, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, ITOA_RADIX_BUFFER_SIZE);
+        assert_eq!(&buf[..len], format!("-0b1{}", "0".repeat(127)).as_bytes());
+
+        let len = unsafe {
+            rust_itoa_radix_u128(u64::MAX, u64::MAX, 2, flags, buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(len, 130);
+
+        let len = unsafe { rust_itoa_radix_u128(1, 0, 10, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"18446744073709551616");
+        let len =
+            unsafe { rust_itoa_radix_u128(u64::MAX, u64::MAX, 36, 0, buf.as_mut_ptr(), buf.len()) };
+        let text = std::str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(u128::from_str_radix(text, 36), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn test_radix_small_unsigned() {
+        let mut buf = [0u8; 8];
+        let len = unsafe { rust_itoa_radix_u8(0xa5, 2, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"10100101");
+        let len = unsafe { rust_itoa_radix_u16(0xbeef, 16, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"beef");
+        let len = unsafe { rust_itoa_radix_u32(u32::MAX, 8, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 11, "Should report the required length");
+    }
+
+    #[test]
+    fn test_radix_invalid_arguments() {
+        let mut buf = [0u8; ITOA_RADIX_BUFFER_SIZE];
+        for radix in [0, 1, 37, u32::MAX] {
+            let len = unsafe { rust_itoa_radix_u64(42, radix, 0, buf.as_mut_ptr(), buf.len()) };
+            assert_eq!(len, 0, "Should return 0 for radix {}", radix);
+        }
+        let len = unsafe { rust_itoa_radix_u64(42, 16, 8, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for unknown flags");
+    }
+
+    #[test]
+    fn test_radix_null_buffer_measures() {
+        let len = unsafe { rust_itoa_radix_u64(u64::MAX, 2, 0, std::ptr::null_mut(), 0) };
+        assert_eq!(len, 64);
+    }
+
+    #[test]
+    fn test_radix_too_small_does_not_overflow() {
+        let mut buf = [0xAAu8; 8];
+        let len = unsafe { rust_itoa_radix_u32(0xdead_beef, 16, 0, buf[2..5].as_mut_ptr(), 3) };
+        assert_eq!(len, 8, "Should report the required length");
+        assert_eq!(buf, [0xAA; 8], "Buffer was modified");
+    }
+}
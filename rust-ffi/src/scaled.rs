@@ -0,0 +1,496 @@
+// ============================================================================
+// Scaled integer C FFI Wrappers - Fixed-point decimals such as `1,234.56`
+// ============================================================================
+// Amounts stored as i64 minor units (cents, satoshis) print with the decimal
+// point `scale` digits from the right: 123456 at scale 2 is `1234.56`. The
+// digits come straight from itoa, so there is no float rounding anywhere.
+// Trailing fraction zeros are dropped down to a minimum count, and the
+// integer digits can be grouped with a NumGrouping. The formatter returns the
+// length the output requires and writes it only if it is <= buf_len
+// (snprintf-style); 0 means the arguments were invalid. The parser is its
+// inverse and reports a NUM_PARSE_* code like rust_atoi_i64.
+
+use std::slice;
+
+use crate::grouping::{NumGrouping, Separators, separators, write_grouped};
+use crate::out::Out;
+use crate::parse::{
+    NUM_PARSE_EMPTY, NUM_PARSE_EXCESS_PRECISION, NUM_PARSE_INVALID, NUM_PARSE_NEG_OVERFLOW,
+    NUM_PARSE_OK, NUM_PARSE_OVERFLOW,
+};
+
+/// Largest scale the scaled formatters accept (10^18 fits in i64)
+pub const NUM_SCALED_MAX_SCALE: u8 = 18;
+
+/// Sign only for negative values: `-1.50`, `1.50`
+pub const NUM_SIGN_MINUS: u8 = 0;
+
+/// Sign for every value: `-1.50`, `+1.50`
+pub const NUM_SIGN_PLUS: u8 = 1;
+
+/// Space in place of a plus sign, so columns line up: `-1.50`, ` 1.50`
+pub const NUM_SIGN_SPACE: u8 = 2;
+
+/// Options for the scaled integer formatter and parser
+///
+/// A null pointer behaves like a zeroed struct: `.` as the decimal point, no
+/// grouping, sign only when negative and every trailing zero dropped.
+#[repr(C)]
+pub struct NumScaledOptions {
+    /// Fraction digits always printed, zero-padded (may exceed the scale)
+    pub min_fraction_digits: u8,
+    /// NUM_SIGN_MINUS, NUM_SIGN_PLUS or NUM_SIGN_SPACE
+    pub sign: u8,
+    /// Separators and grouping, or null for an ungrouped `1234.56`
+    pub grouping: *const NumGrouping,
+}
+
+/// Resolve a possibly null options pointer to (min fraction digits, sign, separators)
+///
+/// # Safety
+/// - options must be null or point to a valid NumScaledOptions whose grouping
+///   is null or valid
+unsafe fn scaled_options<'a>(options: *const NumScaledOptions) -> (usize, u8, Separators<'a>) {
+    // Safety: guaranteed by the caller
+    match unsafe { options.as_ref() } {
+        None => (0, NUM_SIGN_MINUS, Separators::NONE),
+        Some(options) => {
+            let sep = if options.grouping.is_null() {
+                Separators::NONE
+            } else {
+                // Safety: guaranteed by the caller
+                unsafe { separators(options.grouping) }
+            };
+            (options.min_fraction_digits as usize, options.sign, sep)
+        }
+    }
+}
+
+/// Write `magnitude / 10^scale` with grouped integer digits
+///
+/// The caller writes any sign; the currency formatter wraps it differently.
+pub(crate) fn write_scaled(
+    out: &mut Out,
+    magnitude: u64,
+    scale: usize,
+    min_fraction_digits: usize,
+    sep: &Separators,
+) {
+    let mut buffer = itoa::Buffer::new();
+    let digits = buffer.format(magnitude).as_bytes();
+    let int_len = digits.len().saturating_sub(scale);
+    if int_len == 0 {
+        out.push(b'0');
+    } else {
+        write_grouped(out, int_len, |i| digits[i], sep);
+    }
+
+    // Fraction digit k of `scale`, zero-padded on the left and right
+    let padding = scale - (digits.len() - int_len);
+    let fraction_digit = |k: usize| match k {
+        k if k < padding || k >= scale => b'0',
+        k => digits[int_len + k - padding],
+    };
+    let mut kept = scale;
+    while kept > min_fraction_digits && fraction_digit(kept - 1) == b'0' {
+        kept -= 1;
+    }
+    let count = kept.max(min_fraction_digits);
+    if count > 0 {
+        out.push_bytes(sep.decimal);
+        for k in 0..count {
+            out.push(fraction_digit(k));
+        }
+    }
+}
+
+/// Format an i64 holding `value * 10^scale` as a decimal such as `1,234.56`
+///
+/// # Safety
+/// - options must be null or point to a valid NumScaledOptions whose grouping
+///   is null or a valid NumGrouping
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if scale exceeds NUM_SCALED_MAX_SCALE or the sign style is unknown
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_scaled_i64(
+    value: i64,
+    scale: u8,
+    options: *const NumScaledOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let (min_fraction_digits, sign, sep) = unsafe { scaled_options(options) };
+    if scale > NUM_SCALED_MAX_SCALE || sign > NUM_SIGN_SPACE {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    match sign {
+        _ if value < 0 => out.push(b'-'),
+        NUM_SIGN_PLUS => out.push(b'+'),
+        NUM_SIGN_SPACE => out.push(b' '),
+        _ => {}
+    }
+    write_scaled(
+        &mut out,
+        value.unsigned_abs(),
+        scale as usize,
+        min_fraction_digits,
+        &sep,
+    );
+    out.len()
+}
+
+/// A scaled integer read from text: code, sign, saturated magnitude, bytes consumed
+struct ScannedScaled {
+    code: i32,
+    negative: bool,
+    magnitude: u64,
+    consumed: usize,
+}
+
+/// Parse `[+-]? digits [decimal digits*]` (or `[+-]? decimal digits`) as
+/// `value * 10^scale`
+///
+/// Group separators are skipped between integer digits. Fraction digits past
+/// the scale must be zeros, otherwise the value is truncated and the code is
+/// NUM_PARSE_EXCESS_PRECISION. The magnitude saturates at i64's limits.
+fn scan_scaled(bytes: &[u8], scale: usize, sep: &Separators) -> ScannedScaled {
+    let mut scanned = ScannedScaled {
+        code: NUM_PARSE_OK,
+        negative: false,
+        magnitude: 0,
+        consumed: 0,
+    };
+    if bytes.is_empty() {
+        scanned.code = NUM_PARSE_EMPTY;
+        return scanned;
+    }
+
+    let mut i = 0;
+    if let Some(&sign @ (b'+' | b'-')) = bytes.first() {
+        scanned.negative = sign == b'-';
+        i += 1;
+    }
+    let is_digit = |at: usize| bytes.get(at).is_some_and(u8::is_ascii_digit);
+
+    // Integer digits; anything past 10^19 overflows i64 at every scale
+    let (mut integer, mut int_digits, mut too_large) = (0u128, 0, false);
+    loop {
+        if is_digit(i) {
+            integer = integer * 10 + u128::from(bytes[i] - b'0');
+            if integer >= 10u128.pow(19) {
+                integer = 10u128.pow(19);
+                too_large = true;
+            }
+            int_digits += 1;
+            i += 1;
+        } else if int_digits > 0
+            && !sep.group.is_empty()
+            && bytes[i..].starts_with(sep.group)
+            && is_digit(i + sep.group.len())
+        {
+            i += sep.group.len();
+        } else {
+            break;
+        }
+    }
+
+    // fraction holds the first `scale` digits, fraction_len counts them all
+    let (mut fraction, mut fraction_digits, mut fraction_len) = (0u128, 0, 0);
+    if !sep.decimal.is_empty() && bytes[i..].starts_with(sep.decimal) {
+        let start = i + sep.decimal.len();
+        if int_digits > 0 || is_digit(start) {
+            i = start;
+        }
+        while is_digit(i) {
+            let digit = bytes[i] - b'0';
+            if fraction_digits < scale {
+                fraction = fraction * 10 + u128::from(digit);
+                fraction_digits += 1;
+            } else if digit != 0 {
+                scanned.code = NUM_PARSE_EXCESS_PRECISION;
+            }
+            fraction_len += 1;
+            i += 1;
+        }
+    }
+    if int_digits == 0 && fraction_len == 0 {
+        scanned.code = NUM_PARSE_INVALID;
+        return scanned;
+    }
+
+    scanned.consumed = i;
+    let pow10 = 10u128.pow(scale as u32);
+    let magnitude = integer * pow10 + fraction * 10u128.pow((scale - fraction_digits) as u32);
+    let limit = if scanned.negative {
+        i64::MIN.unsigned_abs() as u128
+    } else {
+        i64::MAX as u128
+    };
+    if too_large || magnitude > limit {
+        scanned.code = if scanned.negative {
+            NUM_PARSE_NEG_OVERFLOW
+        } else {
+            NUM_PARSE_OVERFLOW
+        };
+        scanned.magnitude = limit as u64;
+    } else {
+        scanned.magnitude = magnitude as u64;
+    }
+    scanned
+}
+
+/// Parse the decimal at the start of buf as an i64 holding `value * 10^scale`
+///
+/// Uses the decimal and group separators of options.grouping; group
+/// separators are skipped between integer digits.
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - options must be null or point to a valid NumScaledOptions whose grouping
+///   is null or a valid NumGrouping
+/// - out_value and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code (NUM_PARSE_INVALID if scale exceeds NUM_SCALED_MAX_SCALE).
+/// out_value receives the value (0 on EMPTY/INVALID, i64::MIN/i64::MAX on
+/// NEG_OVERFLOW/OVERFLOW, truncated on EXCESS_PRECISION) and out_consumed the
+/// bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_parse_scaled_i64(
+    buf: *const u8,
+    buf_len: usize,
+    scale: u8,
+    options: *const NumScaledOptions,
+    out_value: *mut i64,
+    out_consumed: *mut usize,
+) -> i32 {
+    let bytes = if buf.is_null() {
+        &[][..]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(buf, buf_len) }
+    };
+    // Safety: guaranteed by the caller
+    let (_, _, sep) = unsafe { scaled_options(options) };
+    let (code, value, consumed) = if scale > NUM_SCALED_MAX_SCALE {
+        (NUM_PARSE_INVALID, 0, 0)
+    } else {
+        let scanned = scan_scaled(bytes, scale as usize, &sep);
+        let value = if scanned.negative {
+            (scanned.magnitude as i64).wrapping_neg()
+        } else {
+            scanned.magnitude as i64
+        };
+        (scanned.code, value, scanned.consumed)
+    };
+
+    // Safety: the out pointers are null or valid for writes
+    unsafe {
+        if let Some(out_value) = out_value.as_mut() {
+            *out_value = value;
+        }
+        if let Some(out_consumed) = out_consumed.as_mut() {
+            *out_consumed = consumed;
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMAS: NumGrouping = NumGrouping {
+        group_sep: b",".as_ptr(),
+        group_sep_len: 1,
+        decimal_sep: b".".as_ptr(),
+        decimal_sep_len: 1,
+        primary: 3,
+        secondary: 3,
+    };
+
+    const EUROPEAN: NumGrouping = NumGrouping {
+        group_sep: b".".as_ptr(),
+        group_sep_len: 1,
+        decimal_sep: b",".as_ptr(),
+        decimal_sep_len: 1,
+        primary: 3,
+        secondary: 3,
+    };
+
+    fn options(
+        min_fraction_digits: u8,
+        sign: u8,
+        grouping: Option<&NumGrouping>,
+    ) -> NumScaledOptions {
+        NumScaledOptions {
+            min_fraction_digits,
+            sign,
+            grouping: grouping.map_or(std::ptr::null(), |grouping| grouping),
+        }
+    }
+
+    fn scaled_test(value: i64, scale: u8, options: *const NumScaledOptions) -> String {
+        let mut buf = [0u8; 64];
+        let len =
+            unsafe { num_format_scaled_i64(value, scale, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_scaled_i64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn parse_test(text: &str, scale: u8, options: *const NumScaledOptions) -> (i32, i64, usize) {
+        let (mut value, mut consumed) = (0, 0);
+        let code = unsafe {
+            num_parse_scaled_i64(
+                text.as_ptr(),
+                text.len(),
+                scale,
+                options,
+                &mut value,
+                &mut consumed,
+            )
+        };
+        (code, value, consumed)
+    }
+
+    #[test]
+    fn test_scaled_basic() {
+        let plain = std::ptr::null();
+        assert_eq!(scaled_test(123_456, 2, plain), "1234.56");
+        assert_eq!(scaled_test(-5, 2, plain), "-0.05");
+        assert_eq!(scaled_test(120_000, 4, plain), "12");
+        assert_eq!(scaled_test(123_450, 4, plain), "12.345");
+        assert_eq!(scaled_test(0, 8, plain), "0");
+        assert_eq!(scaled_test(42, 0, plain), "42");
+        assert_eq!(scaled_test(i64::MIN, 18, plain), "-9.223372036854775808");
+    }
+
+    #[test]
+    fn test_scaled_min_fraction_digits() {
+        let cents = options(2, NUM_SIGN_MINUS, None);
+        assert_eq!(scaled_test(120_000, 4, &cents), "12.00");
+        assert_eq!(scaled_test(123_450, 4, &cents), "12.345");
+        assert_eq!(scaled_test(100, 0, &cents), "100.00");
+        assert_eq!(scaled_test(0, 2, &cents), "0.00");
+    }
+
+    #[test]
+    fn test_scaled_grouping_and_sign() {
+        let grouped = options(2, NUM_SIGN_MINUS, Some(&COMMAS));
+        assert_eq!(scaled_test(123_456_789, 2, &grouped), "1,234,567.89");
+        assert_eq!(scaled_test(-99_999, 2, &grouped), "-999.99");
+        let european = options(2, NUM_SIGN_PLUS, Some(&EUROPEAN));
+        assert_eq!(scaled_test(123_456_789, 2, &european), "+1.234.567,89");
+        let space = options(0, NUM_SIGN_SPACE, None);
+        assert_eq!(scaled_test(15, 1, &space), " 1.5");
+        assert_eq!(scaled_test(-15, 1, &space), "-1.5");
+    }
+
+    #[test]
+    fn test_scaled_invalid_options() {
+        let mut buf = [0u8; 32];
+        let bad_sign = options(0, 3, None);
+        let len = unsafe { num_format_scaled_i64(1, 2, &bad_sign, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for an unknown sign style");
+        let len = unsafe {
+            num_format_scaled_i64(
+                1,
+                NUM_SCALED_MAX_SCALE + 1,
+                std::ptr::null(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(len, 0, "Should return 0 for too large scale");
+    }
+
+    #[test]
+    fn test_scaled_reports_required_length() {
+        let len = unsafe {
+            num_format_scaled_i64(-123_456, 2, std::ptr::null(), std::ptr::null_mut(), 0)
+        };
+        assert_eq!(len, "-1234.56".len(), "Should report the required length");
+    }
+
+    #[test]
+    fn test_parse_scaled() {
+        let plain = std::ptr::null();
+        assert_eq!(parse_test("1234.56", 2, plain), (NUM_PARSE_OK, 123_456, 7));
+        assert_eq!(parse_test("-0.05", 2, plain), (NUM_PARSE_OK, -5, 5));
+        assert_eq!(parse_test("12", 4, plain), (NUM_PARSE_OK, 120_000, 2));
+        assert_eq!(parse_test("+.5 USD", 2, plain), (NUM_PARSE_OK, 50, 3));
+        assert_eq!(parse_test("7.", 2, plain), (NUM_PARSE_OK, 700, 2));
+        assert_eq!(parse_test(".0", 0, plain), (NUM_PARSE_OK, 0, 2));
+        assert_eq!(parse_test("1.500", 2, plain), (NUM_PARSE_OK, 150, 5));
+        let grouped = options(0, NUM_SIGN_MINUS, Some(&COMMAS));
+        assert_eq!(
+            parse_test("1,234,567.89, next", 2, &grouped),
+            (NUM_PARSE_OK, 123_456_789, 12)
+        );
+        let european = options(0, NUM_SIGN_MINUS, Some(&EUROPEAN));
+        assert_eq!(
+            parse_test("-1.234,5", 2, &european),
+            (NUM_PARSE_OK, -123_450, 8)
+        );
+    }
+
+    #[test]
+    fn test_parse_scaled_errors() {
+        let plain = std::ptr::null();
+        assert_eq!(parse_test("", 2, plain), (NUM_PARSE_EMPTY, 0, 0));
+        assert_eq!(parse_test("-", 2, plain), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(parse_test(".", 2, plain), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(parse_test("$1", 2, plain), (NUM_PARSE_INVALID, 0, 0));
+        assert_eq!(
+            parse_test("1.239", 2, plain),
+            (NUM_PARSE_EXCESS_PRECISION, 123, 5)
+        );
+        assert_eq!(
+            parse_test("92233720368547758.08", 2, plain),
+            (NUM_PARSE_OVERFLOW, i64::MAX, 20)
+        );
+        assert_eq!(
+            parse_test("-92233720368547758.08", 2, plain),
+            (NUM_PARSE_OK, i64::MIN, 21)
+        );
+        assert_eq!(
+            parse_test("-99999999999999999999999", 0, plain),
+            (NUM_PARSE_NEG_OVERFLOW, i64::MIN, 24)
+        );
+        assert_eq!(
+            parse_test("1", NUM_SCALED_MAX_SCALE + 1, plain),
+            (NUM_PARSE_INVALID, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_scaled_roundtrips() {
+        let grouped = options(2, NUM_SIGN_PLUS, Some(&COMMAS));
+        for (value, scale) in [
+            (0, 2),
+            (123_456_789, 2),
+            (-1, 8),
+            (i64::MAX, 4),
+            (i64::MIN, 18),
+        ] {
+            let text = scaled_test(value, scale, &grouped);
+            assert_eq!(
+                parse_test(&text, scale, &grouped),
+                (NUM_PARSE_OK, value, text.len()),
+                "Should round-trip {}",
+                text
+            );
+        }
+    }
+}
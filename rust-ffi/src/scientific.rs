@@ -0,0 +1,450 @@
+// ============================================================================
+// Scientific-notation C FFI Wrappers - Always `d.ddde±x` output
+// ============================================================================
+// zmij picks its own switch point between plain decimal and exponent output;
+// these functions always emit exponent notation with a caller controlled
+// exponent style, so exported columns line up. Functions return the number of
+// bytes written, or 0 if the buffer was too small.
+
+use crate::decimal::Decimal;
+use crate::fixed::{ZMIJ_MAX_FIXED_PRECISION, non_finite_str};
+use crate::out::Out;
+
+/// Options for the scientific-notation formatters
+///
+/// A null options pointer behaves like `{ precision: -1, uppercase: false,
+/// exp_plus_sign: false, min_exp_digits: 1 }`, e.g. `1.2345e-7`.
+#[repr(C)]
+pub struct ZmijSciOptions {
+    /// Digits after the mantissa's decimal point, or < 0 for shortest round-trip
+    pub precision: i32,
+    /// Use `E` instead of `e` as the exponent marker
+    pub uppercase: bool,
+    /// Always print the exponent sign (`e+7` instead of `e7`)
+    pub exp_plus_sign: bool,
+    /// Zero-pad the exponent to at least this many digits (`e-07` for 2)
+    pub min_exp_digits: u8,
+}
+
+const DEFAULT_SCI_OPTIONS: ZmijSciOptions = ZmijSciOptions {
+    precision: -1,
+    uppercase: false,
+    exp_plus_sign: false,
+    min_exp_digits: 1,
+};
+
+/// Exponent marker, sign and width used after the mantissa
+#[derive(Clone, Copy)]
+pub(crate) struct ExpStyle {
+    pub(crate) marker: u8,
+    pub(crate) plus_sign: bool,
+    pub(crate) min_digits: usize,
+}
+
+impl ExpStyle {
+    fn from_options(options: &ZmijSciOptions) -> Self {
+        ExpStyle {
+            marker: if options.uppercase { b'E' } else { b'e' },
+            plus_sign: options.exp_plus_sign,
+            min_digits: options.min_exp_digits as usize,
+        }
+    }
+}
+
+/// Write the exponent suffix, e.g. `e-7`, `E+07`
+pub(crate) fn write_exponent(out: &mut Out, exp: i32, style: ExpStyle) {
+    out.push(style.marker);
+    if exp < 0 {
+        out.push(b'-');
+    } else if style.plus_sign {
+        out.push(b'+');
+    }
+
+    let mut buffer = itoa::Buffer::new();
+    let digits = buffer.format(exp.unsigned_abs()).as_bytes();
+    for _ in digits.len()..style.min_digits {
+        out.push(b'0');
+    }
+    out.push_bytes(digits);
+}
+
+/// Write `decimal` as `d.ddd` followed by the exponent
+///
+/// With `Some(precision)` the decimal must already be rounded to
+/// `precision + 1` significant digits and exactly `precision` fractional
+/// digits are printed. With `None` all digits are printed (shortest output).
+pub(crate) fn write_scientific(
+    out: &mut Out,
+    decimal: &Decimal,
+    precision: Option<usize>,
+    style: ExpStyle,
+) {
+    if decimal.negative {
+        out.push(b'-');
+    }
+
+    let fraction_digits = match precision {
+        Some(precision) => precision,
+        None => decimal.len.saturating_sub(1),
+    };
+
+    out.push(decimal.digit(0));
+    if fraction_digits > 0 {
+        out.push(b'.');
+        for i in 1..=fraction_digits as i32 {
+            out.push(decimal.digit(i));
+        }
+    }
+
+    let exp = if decimal.is_zero() {
+        0
+    } else {
+        decimal.exp - 1
+    };
+    write_exponent(out, exp, style);
+}
+
+pub(crate) fn format_scientific(
+    out: &mut Out,
+    value: f64,
+    exact: impl FnOnce() -> Decimal,
+    shortest: impl FnOnce() -> Decimal,
+    options: &ZmijSciOptions,
+) {
+    if !value.is_finite() {
+        out.push_bytes(non_finite_str(value));
+        return;
+    }
+
+    let style = ExpStyle::from_options(options);
+    if options.precision < 0 {
+        write_scientific(out, &shortest(), None, style);
+    } else {
+        let precision = options.precision as usize;
+        let mut decimal = exact();
+        decimal.round(precision as i32 + 1);
+        write_scientific(out, &decimal, Some(precision), style);
+    }
+}
+
+/// Resolve a possibly null options pointer, rejecting unsupported precision
+///
+/// # Safety
+/// - options must be null or point to a valid ZmijSciOptions
+pub(crate) unsafe fn sci_options<'a>(options: *const ZmijSciOptions) -> Option<&'a ZmijSciOptions> {
+    // Safety: guaranteed by the caller
+    let options = unsafe { options.as_ref() }.unwrap_or(&DEFAULT_SCI_OPTIONS);
+    if options.precision > ZMIJ_MAX_FIXED_PRECISION as i32 {
+        return None;
+    }
+    Some(options)
+}
+
+/// Format f64 in scientific notation
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - options must be null or point to a valid ZmijSciOptions
+/// - buf_len should be >= zmij_sci_buffer_size(options) for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small or
+/// precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sci_f64(
+    value: f64,
+    options: *const ZmijSciOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if buf.is_null() {
+        return 0;
+    }
+    // Safety: options is null or valid
+    let Some(options) = (unsafe { sci_options(options) }) else {
+        return 0;
+    };
+
+    // Safety: buf is valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_scientific(
+        &mut out,
+        value,
+        || Decimal::exact_f64(value),
+        || Decimal::shortest_f64(value),
+        options,
+    );
+    out.written_or_zero()
+}
+
+/// Format f32 in scientific notation
+///
+/// # Safety
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - options must be null or point to a valid ZmijSciOptions
+/// - buf_len should be >= zmij_sci_buffer_size(options) for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if buffer was too small or
+/// precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sci_f32(
+    value: f32,
+    options: *const ZmijSciOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if buf.is_null() {
+        return 0;
+    }
+    // Safety: options is null or valid
+    let Some(options) = (unsafe { sci_options(options) }) else {
+        return 0;
+    };
+
+    // Safety: buf is valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_scientific(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        || Decimal::shortest_f32(value),
+        options,
+    );
+    out.written_or_zero()
+}
+
+/// Format f64 in scientific notation, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - options must be null or point to a valid ZmijSciOptions
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sci_f64_fit(
+    value: f64,
+    options: *const ZmijSciOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: options is null or valid
+    let Some(options) = (unsafe { sci_options(options) }) else {
+        return 0;
+    };
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_scientific(
+        &mut out,
+        value,
+        || Decimal::exact_f64(value),
+        || Decimal::shortest_f64(value),
+        options,
+    );
+    out.len()
+}
+
+/// Format f32 in scientific notation, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+/// - options must be null or point to a valid ZmijSciOptions
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if precision exceeds ZMIJ_MAX_FIXED_PRECISION
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sci_f32_fit(
+    value: f32,
+    options: *const ZmijSciOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: options is null or valid
+    let Some(options) = (unsafe { sci_options(options) }) else {
+        return 0;
+    };
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_scientific(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        || Decimal::shortest_f32(value),
+        options,
+    );
+    out.len()
+}
+
+/// Buffer size that fits any f64 or f32 formatted with the given options
+///
+/// # Safety
+/// - options must be null or point to a valid ZmijSciOptions
+///
+/// # Returns
+/// Sign + mantissa + exponent marker, sign and digits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_sci_buffer_size(options: *const ZmijSciOptions) -> usize {
+    // Safety: options is null or valid
+    let options = unsafe { options.as_ref() }.unwrap_or(&DEFAULT_SCI_OPTIONS);
+    // Shortest f64 output has at most 17 significant digits
+    let fraction_digits = if options.precision < 0 {
+        16
+    } else {
+        options.precision as usize
+    };
+    let exp_digits = (options.min_exp_digits as usize).max(3);
+    1 + 1 + 1 + fraction_digits + 2 + exp_digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(precision: i32) -> ZmijSciOptions {
+        ZmijSciOptions {
+            precision,
+            ..DEFAULT_SCI_OPTIONS
+        }
+    }
+
+    fn sci_f64_test(value: f64, options: &ZmijSciOptions) -> String {
+        let mut buf = vec![0u8; unsafe { zmij_sci_buffer_size(options) }];
+        let len = unsafe { zmij_format_sci_f64(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "zmij_format_sci_f64 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    fn sci_f32_test(value: f32, options: &ZmijSciOptions) -> String {
+        let mut buf = vec![0u8; unsafe { zmij_sci_buffer_size(options) }];
+        let len = unsafe { zmij_format_sci_f32(value, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(len > 0, "zmij_format_sci_f32 failed for value: {}", value);
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    #[test]
+    fn test_sci_f64_shortest() {
+        assert_eq!(sci_f64_test(1.2345e-7, &options(-1)), "1.2345e-7");
+        assert_eq!(sci_f64_test(123456.0, &options(-1)), "1.23456e5");
+        assert_eq!(sci_f64_test(1e20, &options(-1)), "1e20");
+        assert_eq!(sci_f64_test(-0.5, &options(-1)), "-5e-1");
+        assert_eq!(sci_f64_test(0.0, &options(-1)), "0e0");
+    }
+
+    #[test]
+    fn test_sci_f64_null_options() {
+        let mut buf = [0u8; 32];
+        let len = unsafe {
+            zmij_format_sci_f64(1.2345e-7, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"1.2345e-7");
+    }
+
+    #[test]
+    fn test_sci_f64_exponent_style() {
+        let style = ZmijSciOptions {
+            precision: -1,
+            uppercase: true,
+            exp_plus_sign: true,
+            min_exp_digits: 2,
+        };
+        assert_eq!(sci_f64_test(1.2345e-7, &style), "1.2345E-07");
+        assert_eq!(sci_f64_test(1.5e300, &style), "1.5E+300");
+        assert_eq!(sci_f64_test(42.0, &style), "4.2E+01");
+    }
+
+    #[test]
+    fn test_sci_f64_precision() {
+        assert_eq!(sci_f64_test(1234.5678, &options(2)), "1.23e3");
+        assert_eq!(sci_f64_test(9.999, &options(2)), "1.00e1");
+        assert_eq!(sci_f64_test(0.0, &options(3)), "0.000e0");
+        assert_eq!(sci_f64_test(7.0, &options(0)), "7e0");
+    }
+
+    #[test]
+    fn test_sci_f64_matches_std() {
+        let values = [
+            0.1,
+            1.0 / 3.0,
+            1e-7,
+            123456.789,
+            2.5,
+            1e22,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -std::f64::consts::E,
+        ];
+        for value in values {
+            assert_eq!(sci_f64_test(value, &options(-1)), format!("{:e}", value));
+            for precision in [0, 1, 2, 5, 16, 30] {
+                assert_eq!(
+                    sci_f64_test(value, &options(precision)),
+                    format!("{:.*e}", precision as usize, value),
+                    "mismatch for {} at precision {}",
+                    value,
+                    precision
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sci_f32_matches_std() {
+        for value in [0.1f32, 3.4e38, 1e-45, 1.75, -1234.5] {
+            assert_eq!(sci_f32_test(value, &options(-1)), format!("{:e}", value));
+            assert_eq!(sci_f32_test(value, &options(4)), format!("{:.4e}", value));
+        }
+    }
+
+    #[test]
+    fn test_sci_f64_special_values() {
+        assert_eq!(sci_f64_test(f64::NAN, &options(2)), "NaN");
+        assert_eq!(sci_f64_test(f64::INFINITY, &options(-1)), "inf");
+        assert_eq!(sci_f64_test(f64::NEG_INFINITY, &options(-1)), "-inf");
+    }
+
+    #[test]
+    fn test_sci_f64_worst_case_fits() {
+        let style = ZmijSciOptions {
+            precision: -1,
+            uppercase: false,
+            exp_plus_sign: true,
+            min_exp_digits: 5,
+        };
+        let result = sci_f64_test(-2.2250738585072014e-308, &style);
+        assert_eq!(result, "-2.2250738585072014e-00308");
+        assert!(result.len() <= unsafe { zmij_sci_buffer_size(&style) });
+    }
+
+    #[test]
+    fn test_sci_f64_buffer_too_small() {
+        let mut buf = [0u8; 4];
+        let len =
+            unsafe { zmij_format_sci_f64(1.2345e-7, &options(-1), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for buffer too small");
+    }
+
+    #[test]
+    fn test_sci_f64_fit_exact_length() {
+        let len =
+            unsafe { zmij_format_sci_f64_fit(1.5e300, std::ptr::null(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1.5e300".len());
+
+        let mut buf = [0u8; 3];
+        let len =
+            unsafe { zmij_format_sci_f32_fit(7.0f32, &options(0), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 3);
+        assert_eq!(&buf, b"7e0");
+    }
+
+    #[test]
+    fn test_sci_f64_null_buffer() {
+        let len = unsafe { zmij_format_sci_f64(1.5, &options(-1), std::ptr::null_mut(), 32) };
+        assert_eq!(len, 0, "Should return 0 for null buffer");
+    }
+}
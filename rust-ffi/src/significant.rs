@@ -0,0 +1,320 @@
+// ============================================================================
+// Significant-digits C FFI Wrappers - printf `%g` style output
+// ============================================================================
+// Rounds to N significant digits and picks fixed or exponent notation by
+// magnitude like C's `%.Ng`: `3.142`, `0.0001235`, `1.235e+20`. Trailing zeros
+// and a bare decimal point are stripped unless ZMIJ_SIG_KEEP_TRAILING_ZEROS
+// (`%#g`) is set. NaN and infinities use the zmij spellings. Functions return
+// the length the output requires and write it only if it is <= buf_len.
+
+use crate::decimal::Decimal;
+use crate::fixed::{ZMIJ_MAX_FIXED_PRECISION, non_finite_str};
+use crate::grouping::{Separators, write_grouped_decimal};
+use crate::out::Out;
+use crate::scientific::{ExpStyle, write_exponent};
+
+/// Keep trailing zeros and the decimal point, like `%#g`
+pub const ZMIJ_SIG_KEEP_TRAILING_ZEROS: u32 = 1;
+
+/// Use `E` as the exponent marker, like `%G`
+pub const ZMIJ_SIG_UPPERCASE: u32 = 2;
+
+const ALL_FLAGS: u32 = ZMIJ_SIG_KEEP_TRAILING_ZEROS | ZMIJ_SIG_UPPERCASE;
+
+/// Fraction digits to print out of `available`, honouring trailing zeros
+///
+/// `significant` is how many fraction digits of the decimal can be non-zero.
+fn fraction_digits(available: i32, significant: i32, keep_zeros: bool) -> usize {
+    if keep_zeros {
+        available.max(0) as usize
+    } else {
+        significant.clamp(0, available.max(0)) as usize
+    }
+}
+
+/// Write an exact `decimal` rounded to `digits` significant digits
+///
+/// Fixed notation uses `sep` for the decimal point and digit groups.
+pub(crate) fn write_significant(
+    out: &mut Out,
+    mut decimal: Decimal,
+    digits: u32,
+    keep_zeros: bool,
+    sep: &Separators,
+    style: ExpStyle,
+) {
+    let digits = digits.max(1) as i32;
+    decimal.round(digits);
+
+    // Exponent of the rounded value in d.ddd notation
+    let exp = if decimal.is_zero() {
+        0
+    } else {
+        decimal.exp - 1
+    };
+    let nonzero = if decimal.is_zero() {
+        0
+    } else {
+        decimal.len as i32
+    };
+
+    if (-4..digits).contains(&exp) {
+        let fraction = fraction_digits(digits - 1 - exp, nonzero - decimal.exp, keep_zeros);
+        write_grouped_decimal(out, &decimal, Some(fraction), sep);
+        if keep_zeros && fraction == 0 {
+            out.push_bytes(sep.decimal);
+        }
+    } else {
+        let fraction = fraction_digits(digits - 1, nonzero - 1, keep_zeros);
+        if decimal.negative {
+            out.push(b'-');
+        }
+        out.push(decimal.digit(0));
+        if fraction > 0 || keep_zeros {
+            out.push_bytes(sep.decimal);
+        }
+        for i in 1..=fraction as i32 {
+            out.push(decimal.digit(i));
+        }
+        write_exponent(out, exp, style);
+    }
+}
+
+pub(crate) fn format_significant(
+    out: &mut Out,
+    value: f64,
+    exact: impl FnOnce() -> Decimal,
+    digits: u32,
+    flags: u32,
+) {
+    if !value.is_finite() {
+        out.push_bytes(non_finite_str(value));
+        return;
+    }
+
+    let style = ExpStyle {
+        marker: if flags & ZMIJ_SIG_UPPERCASE != 0 {
+            b'E'
+        } else {
+            b'e'
+        },
+        plus_sign: true,
+        min_digits: 2,
+    };
+    let keep_zeros = flags & ZMIJ_SIG_KEEP_TRAILING_ZEROS != 0;
+    write_significant(out, exact(), digits, keep_zeros, &Separators::NONE, style);
+}
+
+/// Format f64 with `digits` significant digits, like printf `%.{digits}g`
+///
+/// digits 0 is treated as 1, as in C.
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if digits exceeds ZMIJ_MAX_FIXED_PRECISION or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sig_f64(
+    value: f64,
+    digits: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if digits > ZMIJ_MAX_FIXED_PRECISION || flags & !ALL_FLAGS != 0 {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_significant(&mut out, value, || Decimal::exact_f64(value), digits, flags);
+    out.len()
+}
+
+/// Format f32 with `digits` significant digits, like printf `%.{digits}g`
+///
+/// digits 0 is treated as 1, as in C.
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if digits exceeds ZMIJ_MAX_FIXED_PRECISION or flags has unknown bits
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zmij_format_sig_f32(
+    value: f32,
+    digits: u32,
+    flags: u32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    if digits > ZMIJ_MAX_FIXED_PRECISION || flags & !ALL_FLAGS != 0 {
+        return 0;
+    }
+
+    // Safety: buf is null or valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    format_significant(
+        &mut out,
+        value as f64,
+        || Decimal::exact_f32(value),
+        digits,
+        flags,
+    );
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::{c_char, c_int};
+
+    unsafe extern "C" {
+        fn snprintf(buf: *mut c_char, len: usize, format: *const c_char, ...) -> c_int;
+    }
+
+    fn sig_f64_test(value: f64, digits: u32, flags: u32) -> String {
+        let mut buf = [0u8; 1200];
+        let len = unsafe { zmij_format_sig_f64(value, digits, flags, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "zmij_format_sig_f64 failed for value: {}",
+            value
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    /// The C library's `%.{digits}g` (with `#` and `G` per flags)
+    fn printf_g(value: f64, digits: u32, flags: u32) -> String {
+        let alternate = if flags & ZMIJ_SIG_KEEP_TRAILING_ZEROS != 0 {
+            "#"
+        } else {
+            ""
+        };
+        let conversion = if flags & ZMIJ_SIG_UPPERCASE != 0 {
+            "G"
+        } else {
+            "g"
+        };
+        let format = format!("%{alternate}.{digits}{conversion}\0");
+        let mut buf = [0u8; 1200];
+        let len = unsafe {
+            snprintf(
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                format.as_ptr().cast(),
+                value,
+            )
+        };
+        String::from_utf8(buf[..len as usize].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_sig_f64_examples() {
+        assert_eq!(sig_f64_test(std::f64::consts::PI, 4, 0), "3.142");
+        assert_eq!(sig_f64_test(0.000123456, 4, 0), "0.0001235");
+        assert_eq!(sig_f64_test(1.23456e20, 4, 0), "1.235e+20");
+        assert_eq!(sig_f64_test(1.23456e20, 4, ZMIJ_SIG_UPPERCASE), "1.235E+20");
+        assert_eq!(sig_f64_test(0.00001234, 3, 0), "1.23e-05");
+    }
+
+    #[test]
+    fn test_sig_f64_trailing_zeros() {
+        assert_eq!(sig_f64_test(2.5, 6, 0), "2.5");
+        assert_eq!(
+            sig_f64_test(2.5, 6, ZMIJ_SIG_KEEP_TRAILING_ZEROS),
+            "2.50000"
+        );
+        assert_eq!(sig_f64_test(100.0, 3, 0), "100");
+        assert_eq!(sig_f64_test(100.0, 3, ZMIJ_SIG_KEEP_TRAILING_ZEROS), "100.");
+        assert_eq!(
+            sig_f64_test(1e10, 1, ZMIJ_SIG_KEEP_TRAILING_ZEROS),
+            "1.e+10"
+        );
+        assert_eq!(
+            sig_f64_test(999.9996, 3, ZMIJ_SIG_KEEP_TRAILING_ZEROS),
+            "1.00e+03"
+        );
+        assert_eq!(sig_f64_test(0.0, 4, 0), "0");
+        assert_eq!(sig_f64_test(0.0, 4, ZMIJ_SIG_KEEP_TRAILING_ZEROS), "0.000");
+    }
+
+    #[test]
+    fn test_sig_f64_rounding_changes_notation() {
+        assert_eq!(sig_f64_test(999999.5, 6, 0), "1e+06");
+        assert_eq!(sig_f64_test(9.9999e-5, 3, 0), "0.0001");
+        assert_eq!(sig_f64_test(0.5, 0, 0), "0.5");
+    }
+
+    #[test]
+    fn test_sig_f64_matches_printf() {
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            1.0 / 3.0,
+            123456.789,
+            1e-5,
+            9.5e-5,
+            0.000099996,
+            999.9996,
+            1e15,
+            1e21,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -std::f64::consts::E,
+        ];
+        for value in values {
+            for digits in [0, 1, 2, 3, 6, 10, 17, 25] {
+                // glibc's `%#g` drops zeros when rounding bumps the exponent,
+                // so the keep flag is covered by test_sig_f64_trailing_zeros
+                for flags in [0, ZMIJ_SIG_UPPERCASE] {
+                    assert_eq!(
+                        sig_f64_test(value, digits, flags),
+                        printf_g(value, digits, flags),
+                        "mismatch for {} at {} digits, flags {}",
+                        value,
+                        digits,
+                        flags
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sig_f32() {
+        let mut buf = [0u8; 32];
+        let len = unsafe { zmij_format_sig_f32(0.1f32, 9, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0.100000001");
+        let len = unsafe { zmij_format_sig_f32(3.4e38f32, 3, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"3.4e+38");
+    }
+
+    #[test]
+    fn test_sig_special_values() {
+        assert_eq!(sig_f64_test(f64::NAN, 4, 0), "NaN");
+        assert_eq!(sig_f64_test(f64::NEG_INFINITY, 4, 0), "-inf");
+    }
+
+    #[test]
+    fn test_sig_invalid_arguments() {
+        let mut buf = [0u8; 32];
+        let len = unsafe { zmij_format_sig_f64(1.0, 1075, 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for too many digits");
+        let len = unsafe { zmij_format_sig_f64(1.0, 4, 4, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 0, "Should return 0 for unknown flags");
+    }
+
+    #[test]
+    fn test_sig_reports_required_length() {
+        let len = unsafe { zmij_format_sig_f64(1.23456e20, 4, 0, std::ptr::null_mut(), 0) };
+        assert_eq!(len, "1.235e+20".len());
+    }
+}
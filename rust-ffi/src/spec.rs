@@ -0,0 +1,1032 @@
+// ============================================================================
+// Format-spec C FFI Wrappers - Python-style `[[fill]align][sign][#][0][width][grouping][.precision][type]`
+// ============================================================================
+// One entry point per value type instead of one per option: `>+12,.3e`,
+// `08x`, `_b`, `.2%`. Specs can be compiled once into an opaque NumSpec
+// handle for hot loops. Types: `d x X o b` for integers, `e E f F g G %` for
+// both, and none for shortest round-trip floats / decimal integers. Grouping
+// (`,` or `_`) is rejected with `g` and `G`. Functions return the length the
+// output requires and write it only if it is <= buf_len (snprintf-style); 0
+// means the spec was invalid or does not apply to the value type.
+
+use crate::decimal::Decimal;
+use crate::fixed::{ZMIJ_MAX_FIXED_PRECISION, round_fixed, write_fixed};
+use crate::grouping::{Separators, c_bytes, write_grouped, write_grouped_decimal};
+use crate::out::Out;
+use crate::pad::{Align, ITOA_MAX_WIDTH, Padding};
+use crate::radix::{ITOA_RADIX_UPPERCASE, write_radix};
+use crate::scientific::{ExpStyle, write_exponent, write_scientific};
+use crate::significant::{ZMIJ_SIG_KEEP_TRAILING_ZEROS, ZMIJ_SIG_UPPERCASE, format_significant};
+use crate::{i128_from_halves, u128_from_halves};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// No type: decimal integers, shortest round-trip floats
+    Default,
+    Decimal,
+    Radix {
+        radix: u32,
+        uppercase: bool,
+    },
+    Fixed {
+        uppercase: bool,
+    },
+    Scientific {
+        uppercase: bool,
+    },
+    General {
+        uppercase: bool,
+    },
+    Percent,
+}
+
+/// A compiled format spec
+///
+/// Create with num_spec_new and release with num_spec_free.
+pub struct NumSpec {
+    pad: Padding,
+    alternate: bool,
+    grouping: Option<u8>,
+    precision: Option<usize>,
+    kind: Kind,
+}
+
+/// A float and the decimal expansions the formatters need
+#[derive(Clone, Copy)]
+enum Float {
+    F64(f64),
+    F32(f32),
+}
+
+impl Float {
+    fn value(self) -> f64 {
+        match self {
+            Float::F64(value) => value,
+            Float::F32(value) => value as f64,
+        }
+    }
+
+    /// Exact expansion of the magnitude
+    fn exact(self) -> Decimal {
+        let mut decimal = match self {
+            Float::F64(value) => Decimal::exact_f64(value),
+            Float::F32(value) => Decimal::exact_f32(value),
+        };
+        decimal.negative = false;
+        decimal
+    }
+
+    /// Shortest round-trip digits of the magnitude
+    fn shortest(self) -> Decimal {
+        let mut decimal = match self {
+            Float::F64(value) => Decimal::shortest_f64(value),
+            Float::F32(value) => Decimal::shortest_f32(value),
+        };
+        decimal.negative = false;
+        decimal
+    }
+
+    /// zmij's shortest output of the magnitude
+    fn write_shortest(self, out: &mut Out) {
+        let mut buffer = zmij::Buffer::new();
+        let text = match self {
+            Float::F64(value) => buffer.format_finite(value.abs()),
+            Float::F32(value) => buffer.format_finite(value.abs()),
+        };
+        out.push_bytes(text.as_bytes());
+    }
+}
+
+fn parse_align(c: char) -> Option<Align> {
+    match c {
+        '<' => Some(Align::Left),
+        '>' => Some(Align::Right),
+        '^' => Some(Align::Center),
+        '=' => Some(Align::AfterSign),
+        _ => None,
+    }
+}
+
+/// Parse leading ASCII digits; None if they overflow `max`
+fn parse_number(rest: &mut &str, max: usize) -> Option<Option<usize>> {
+    let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+    if len == 0 {
+        return Some(None);
+    }
+    let (digits, tail) = rest.split_at(len);
+    *rest = tail;
+    let value = digits.parse::<usize>().ok().filter(|&value| value <= max)?;
+    Some(Some(value))
+}
+
+impl NumSpec {
+    /// Parse a spec string; None if it is malformed
+    fn parse(spec: &str) -> Option<NumSpec> {
+        let mut rest = spec;
+
+        let mut fill = None;
+        let mut align = None;
+        let mut chars = rest.chars();
+        if let Some(first) = chars.next() {
+            if let Some(second) = chars.next().and_then(parse_align) {
+                fill = Some(first);
+                align = Some(second);
+                rest = &rest[first.len_utf8() + 1..];
+            } else if let Some(first) = parse_align(first) {
+                align = Some(first);
+                rest = &rest[1..];
+            }
+        }
+
+        let positive_sign = match rest.as_bytes().first() {
+            Some(b'+') => Some(b'+'),
+            Some(b' ') => Some(b' '),
+            _ => None,
+        };
+        if positive_sign.is_some() || rest.starts_with('-') {
+            rest = &rest[1..];
+        }
+
+        let alternate = rest.starts_with('#');
+        if alternate {
+            rest = &rest[1..];
+        }
+
+        // `0` is shorthand for `0=`, keeping an explicit fill or align
+        if rest.starts_with('0') {
+            rest = &rest[1..];
+            fill = fill.or(Some('0'));
+            align = align.or(Some(Align::AfterSign));
+        }
+
+        let width = parse_number(&mut rest, ITOA_MAX_WIDTH as usize)?.unwrap_or(0);
+
+        let grouping = match rest.as_bytes().first() {
+            Some(&sep @ (b',' | b'_')) => Some(sep),
+            _ => None,
+        };
+        if grouping.is_some() {
+            rest = &rest[1..];
+        }
+
+        let precision = match rest.strip_prefix('.') {
+            Some(tail) => {
+                rest = tail;
+                Some(parse_number(&mut rest, ZMIJ_MAX_FIXED_PRECISION as usize)??)
+            }
+            None => None,
+        };
+
+        let kind = match rest {
+            "" => Kind::Default,
+            "d" => Kind::Decimal,
+            "x" => Kind::Radix {
+                radix: 16,
+                uppercase: false,
+            },
+            "X" => Kind::Radix {
+                radix: 16,
+                uppercase: true,
+            },
+            "o" => Kind::Radix {
+                radix: 8,
+                uppercase: false,
+            },
+            "b" => Kind::Radix {
+                radix: 2,
+                uppercase: false,
+            },
+            "f" => Kind::Fixed { uppercase: false },
+            "F" => Kind::Fixed { uppercase: true },
+            "e" => Kind::Scientific { uppercase: false },
+            "E" => Kind::Scientific { uppercase: true },
+            "g" => Kind::General { uppercase: false },
+            "G" => Kind::General { uppercase: true },
+            "%" => Kind::Percent,
+            _ => return None,
+        };
+
+        let fill = fill.map_or(0, u32::from);
+        let pad = Padding::new(width, fill, align.unwrap_or(Align::Right), positive_sign)?;
+        let spec = NumSpec {
+            pad,
+            alternate,
+            grouping,
+            precision,
+            kind,
+        };
+        let bad_grouping = match (grouping, kind) {
+            (Some(_), _) if spec.is_general() => true,
+            (Some(b','), Kind::Radix { .. }) => true,
+            _ => false,
+        };
+        if bad_grouping {
+            return None;
+        }
+        Some(spec)
+    }
+
+    /// `g`/`G`, or no type with a precision (which formats like `g`)
+    fn is_general(&self) -> bool {
+        matches!(self.kind, Kind::General { .. })
+            || (self.kind == Kind::Default && self.precision.is_some())
+    }
+
+    /// Separators for `grouping`, `group_size` digits per group
+    fn separators(&self, group_size: usize) -> Option<Separators<'static>> {
+        self.grouping.map(|sep| Separators {
+            group: if sep == b',' { b"," } else { b"_" },
+            decimal: b".",
+            primary: group_size,
+            secondary: group_size,
+        })
+    }
+
+    /// Measure `body`, then write it padded
+    fn write_padded(&self, out: &mut Out, negative: bool, prefix: &[u8], body: impl Fn(&mut Out)) {
+        let mut measure = Out::new(&mut []);
+        body(&mut measure);
+        self.pad.write(out, negative, prefix, measure.len(), body);
+    }
+
+    /// Format an integer; false if the spec does not apply to integers
+    fn format_int(&self, out: &mut Out, negative: bool, magnitude: u128) -> bool {
+        let (radix, uppercase) = match self.kind {
+            Kind::Default | Kind::Decimal => (10, false),
+            Kind::Radix { radix, uppercase } => (radix, uppercase),
+            _ => {
+                // Float presentation types convert the integer, like Python
+                let value = magnitude as f64;
+                let value = if negative { -value } else { value };
+                return self.format_float(out, Float::F64(value));
+            }
+        };
+        if self.precision.is_some() {
+            return false;
+        }
+
+        let mut itoa_buffer = itoa::Buffer::new();
+        let mut radix_digits = [0u8; 128];
+        let digits = if radix == 10 {
+            itoa_buffer.format(magnitude).as_bytes()
+        } else {
+            let mut digits_out = Out::new(&mut radix_digits);
+            let flags = if uppercase { ITOA_RADIX_UPPERCASE } else { 0 };
+            write_radix(&mut digits_out, false, magnitude, radix, flags);
+            let count = digits_out.len();
+            &radix_digits[..count]
+        };
+        let count = digits.len();
+
+        let prefix: &[u8] = match (self.alternate, radix, uppercase) {
+            (false, _, _) => b"",
+            (true, 16, false) => b"0x",
+            (true, 16, true) => b"0X",
+            (true, 8, _) => b"0o",
+            (true, 2, _) => b"0b",
+            _ => b"",
+        };
+        let group_size = if radix == 10 { 3 } else { 4 };
+        match self.separators(group_size) {
+            Some(sep) => self.write_padded(out, negative, prefix, |out| {
+                write_grouped(out, count, |i| digits[i], &sep)
+            }),
+            None => self.write_padded(out, negative, prefix, |out| out.push_bytes(digits)),
+        }
+        true
+    }
+
+    /// Format an i64; false if the spec does not apply to integers
+    pub(crate) fn format_i64(&self, out: &mut Out, value: i64) -> bool {
+        self.format_int(out, value < 0, value.unsigned_abs() as u128)
+    }
+
+    /// Format an f64; false if the spec does not apply to floats
+    pub(crate) fn format_f64(&self, out: &mut Out, value: f64) -> bool {
+        self.format_float(out, Float::F64(value))
+    }
+
+    /// Format a float; false if the spec does not apply to floats
+    fn format_float(&self, out: &mut Out, float: Float) -> bool {
+        if matches!(self.kind, Kind::Decimal | Kind::Radix { .. }) {
+            return false;
+        }
+
+        let value = float.value();
+        let negative = value.is_sign_negative() && !value.is_nan();
+        if !value.is_finite() {
+            let uppercase = matches!(
+                self.kind,
+                Kind::Fixed { uppercase: true }
+                    | Kind::Scientific { uppercase: true }
+                    | Kind::General { uppercase: true }
+            );
+            let text: &[u8] = match (value.is_nan(), uppercase) {
+                (true, false) => b"NaN",
+                (true, true) => b"NAN",
+                (false, false) => b"inf",
+                (false, true) => b"INF",
+            };
+            let percent = self.kind == Kind::Percent;
+            self.write_padded(out, negative, b"", |out| {
+                out.push_bytes(text);
+                if percent {
+                    out.push(b'%');
+                }
+            });
+            return true;
+        }
+
+        match self.kind {
+            Kind::Fixed { .. } => {
+                let precision = self.precision.unwrap_or(6);
+                let mut decimal = float.exact();
+                round_fixed(&mut decimal, precision);
+                self.write_padded(out, negative, b"", |out| {
+                    self.write_fixed_body(out, &decimal, precision)
+                });
+            }
+            Kind::Percent => {
+                let precision = self.precision.unwrap_or(6);
+                let mut decimal = Float::F64(value * 100.0).exact();
+                round_fixed(&mut decimal, precision);
+                self.write_padded(out, negative, b"", |out| {
+                    self.write_fixed_body(out, &decimal, precision);
+                    out.push(b'%');
+                });
+            }
+            Kind::Scientific { uppercase } => {
+                let precision = self.precision.unwrap_or(6);
+                let mut decimal = float.exact();
+                decimal.round(precision as i32 + 1);
+                let style = ExpStyle {
+                    marker: if uppercase { b'E' } else { b'e' },
+                    plus_sign: true,
+                    min_digits: 2,
+                };
+                self.write_padded(out, negative, b"", |out| {
+                    if self.alternate && precision == 0 {
+                        let exp = if decimal.is_zero() {
+                            0
+                        } else {
+                            decimal.exp - 1
+                        };
+                        out.push(decimal.digit(0));
+                        out.push(b'.');
+                        write_exponent(out, exp, style);
+                    } else {
+                        write_scientific(out, &decimal, Some(precision), style);
+                    }
+                });
+            }
+            _ if self.is_general() => {
+                let digits = self.precision.unwrap_or(6) as u32;
+                let mut flags = 0;
+                if self.alternate {
+                    flags |= ZMIJ_SIG_KEEP_TRAILING_ZEROS;
+                }
+                if matches!(self.kind, Kind::General { uppercase: true }) {
+                    flags |= ZMIJ_SIG_UPPERCASE;
+                }
+                self.write_padded(out, negative, b"", |out| {
+                    format_significant(out, value.abs(), || float.exact(), digits, flags)
+                });
+            }
+            _ => match self.separators(3) {
+                Some(sep) => {
+                    let decimal = float.shortest();
+                    self.write_padded(out, negative, b"", |out| {
+                        write_grouped_decimal(out, &decimal, None, &sep)
+                    });
+                }
+                None => self.write_padded(out, negative, b"", |out| float.write_shortest(out)),
+            },
+        }
+        true
+    }
+
+    /// Fixed digits with optional grouping, and `#` keeping a bare point
+    fn write_fixed_body(&self, out: &mut Out, decimal: &Decimal, precision: usize) {
+        match self.separators(3) {
+            Some(sep) => write_grouped_decimal(out, decimal, Some(precision), &sep),
+            None => write_fixed(out, decimal, precision),
+        }
+        if self.alternate && precision == 0 {
+            out.push(b'.');
+        }
+    }
+}
+
+/// Parse a possibly null C spec string
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes
+unsafe fn parse_spec(spec: *const u8, spec_len: usize) -> Option<NumSpec> {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { c_bytes(spec, spec_len) };
+    NumSpec::parse(std::str::from_utf8(spec).ok()?)
+}
+
+/// Resolve a possibly null compiled spec, null meaning the empty spec
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+pub(crate) unsafe fn spec_ref<'a>(spec: *const NumSpec) -> &'a NumSpec {
+    // Safety: guaranteed by the caller
+    unsafe { spec.as_ref() }.unwrap_or(&EMPTY_SPEC)
+}
+
+const EMPTY_SPEC: NumSpec = NumSpec {
+    pad: Padding::NONE,
+    alternate: false,
+    grouping: None,
+    precision: None,
+    kind: Kind::Default,
+};
+
+/// Run `format` on a fresh output over buf, returning the required length
+///
+/// # Safety
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn format_into(
+    buf: *mut u8,
+    buf_len: usize,
+    format: impl FnOnce(&mut Out) -> bool,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !format(&mut out) {
+        return 0;
+    }
+    out.len()
+}
+
+/// Compile a format spec such as `>+12,.3e` for the num_spec_format_* functions
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+///
+/// # Returns
+/// A handle to release with num_spec_free, or null if the spec is malformed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_new(spec: *const u8, spec_len: usize) -> *mut NumSpec {
+    // Safety: guaranteed by the caller
+    match unsafe { parse_spec(spec, spec_len) } {
+        Some(spec) => Box::into_raw(Box::new(spec)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Release a spec returned by num_spec_new (null is ignored)
+///
+/// # Safety
+/// - spec must be null or a pointer returned by num_spec_new, not yet freed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_free(spec: *mut NumSpec) {
+    if !spec.is_null() {
+        // Safety: spec came from Box::into_raw in num_spec_new
+        drop(unsafe { Box::from_raw(spec) });
+    }
+}
+
+/// Format i64 with a compiled spec (null spec = empty spec)
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to integers (e.g. a precision with `d`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_i64(
+    spec: *const NumSpec,
+    value: i64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    let magnitude = value.unsigned_abs() as u128;
+    // Safety: guaranteed by the caller
+    unsafe {
+        format_into(buf, buf_len, |out| {
+            spec.format_int(out, value < 0, magnitude)
+        })
+    }
+}
+
+/// Format u64 with a compiled spec (null spec = empty spec)
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to integers (e.g. a precision with `d`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_u64(
+    spec: *const NumSpec,
+    value: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        format_into(buf, buf_len, |out| {
+            spec.format_int(out, false, value as u128)
+        })
+    }
+}
+
+/// Format i128, passed as `(hi << 64) | lo`, with a compiled spec
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to integers (e.g. a precision with `d`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_i128(
+    spec: *const NumSpec,
+    hi: i64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    let value = i128_from_halves(hi, lo);
+    // Safety: guaranteed by the caller
+    unsafe {
+        format_into(buf, buf_len, |out| {
+            spec.format_int(out, value < 0, value.unsigned_abs())
+        })
+    }
+}
+
+/// Format u128, passed as `(hi << 64) | lo`, with a compiled spec
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to integers (e.g. a precision with `d`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_u128(
+    spec: *const NumSpec,
+    hi: u64,
+    lo: u64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    let value = u128_from_halves(hi, lo);
+    // Safety: guaranteed by the caller
+    unsafe { format_into(buf, buf_len, |out| spec.format_int(out, false, value)) }
+}
+
+/// Format f64 with a compiled spec (null spec = empty spec)
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to floats (e.g. `x`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_f64(
+    spec: *const NumSpec,
+    value: f64,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        format_into(buf, buf_len, |out| {
+            spec.format_float(out, Float::F64(value))
+        })
+    }
+}
+
+/// Format f32 with a compiled spec (null spec = empty spec)
+///
+/// # Safety
+/// - spec must be null or a live pointer returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec does not apply to floats (e.g. `x`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_spec_format_f32(
+    spec: *const NumSpec,
+    value: f32,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let spec = unsafe { spec_ref(spec) };
+    // Safety: guaranteed by the caller
+    unsafe {
+        format_into(buf, buf_len, |out| {
+            spec.format_float(out, Float::F32(value))
+        })
+    }
+}
+
+/// Format i64 with a spec string, parsed on every call
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to integers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_i64(
+    value: i64,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_i64(&spec, value, buf, buf_len) }
+}
+
+/// Format u64 with a spec string, parsed on every call
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to integers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_u64(
+    value: u64,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_u64(&spec, value, buf, buf_len) }
+}
+
+/// Format i128, passed as `(hi << 64) | lo`, with a spec string
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to integers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_i128(
+    hi: i64,
+    lo: u64,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_i128(&spec, hi, lo, buf, buf_len) }
+}
+
+/// Format u128, passed as `(hi << 64) | lo`, with a spec string
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to integers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_u128(
+    hi: u64,
+    lo: u64,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_u128(&spec, hi, lo, buf, buf_len) }
+}
+
+/// Format f64 with a spec string, parsed on every call
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to floats
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_f64(
+    value: f64,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_f64(&spec, value, buf, buf_len) }
+}
+
+/// Format f32 with a spec string, parsed on every call
+///
+/// # Safety
+/// - spec must be null or valid for reads of spec_len bytes (UTF-8)
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if the spec is malformed or does not apply to floats
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_spec_f32(
+    value: f32,
+    spec: *const u8,
+    spec_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    let Some(spec) = (unsafe { parse_spec(spec, spec_len) }) else {
+        return 0;
+    };
+    // Safety: guaranteed by the caller
+    unsafe { num_spec_format_f32(&spec, value, buf, buf_len) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec_i64_test(value: i64, spec: &str) -> String {
+        let mut buf = [0u8; 256];
+        let len = unsafe {
+            num_format_spec_i64(
+                value,
+                spec.as_ptr(),
+                spec.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_spec_i64 failed for spec: {}",
+            spec
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn spec_f64_test(value: f64, spec: &str) -> String {
+        let mut buf = [0u8; 256];
+        let len = unsafe {
+            num_format_spec_f64(
+                value,
+                spec.as_ptr(),
+                spec.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert!(
+            len > 0 && len <= buf.len(),
+            "num_format_spec_f64 failed for spec: {}",
+            spec
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_spec_integers() {
+        assert_eq!(spec_i64_test(42, ""), "42");
+        assert_eq!(spec_i64_test(255, "08x"), "000000ff");
+        assert_eq!(spec_i64_test(255, "#010x"), "0x000000ff");
+        assert_eq!(spec_i64_test(255, "#X"), "0XFF");
+        assert_eq!(spec_i64_test(-255, "x"), "-ff");
+        assert_eq!(spec_i64_test(5, "#b"), "0b101");
+        assert_eq!(spec_i64_test(8, "o"), "10");
+        assert_eq!(spec_i64_test(-1234567, ","), "-1,234,567");
+        assert_eq!(spec_i64_test(0xdeadbeef, "_x"), "dead_beef");
+        assert_eq!(spec_i64_test(42, "+d"), "+42");
+        assert_eq!(spec_i64_test(42, " d"), " 42");
+        assert_eq!(spec_i64_test(-42, "05"), "-0042");
+        assert_eq!(spec_i64_test(42, "*^8"), "***42***");
+        assert_eq!(spec_i64_test(42, "<6"), "42    ");
+        assert_eq!(spec_i64_test(-42, "=+6"), "-   42");
+    }
+
+    #[test]
+    fn test_spec_floats() {
+        assert_eq!(spec_f64_test(1234.5678, ">+12,.3e"), "  +1.235e+03");
+        assert_eq!(spec_f64_test(1234567.891, ",.2f"), "1,234,567.89");
+        assert_eq!(spec_f64_test(0.25, ".1%"), "25.0%");
+        assert_eq!(spec_f64_test(std::f64::consts::PI, ".4g"), "3.142");
+        assert_eq!(spec_f64_test(1e20, ".3G"), "1E+20");
+        assert_eq!(spec_f64_test(-2.5, "010.2f"), "-000002.50");
+        assert_eq!(spec_f64_test(2.0, "#.0f"), "2.");
+        assert_eq!(spec_f64_test(2.0, "#.0e"), "2.e+00");
+        assert_eq!(spec_f64_test(0.1, ""), "0.1");
+        assert_eq!(spec_f64_test(-0.0, ""), "-0.0");
+        assert_eq!(spec_f64_test(1234567.5, "_"), "1_234_567.5");
+        assert_eq!(spec_f64_test(3.0, "f"), "3.000000");
+        assert_eq!(spec_f64_test(3.0, "e"), "3.000000e+00");
+    }
+
+    #[test]
+    fn test_spec_matches_std() {
+        let values = [
+            0.0,
+            1.5,
+            -1.5,
+            0.125,
+            123456.789,
+            -9.999,
+            1e-7,
+            6.02214076e23,
+        ];
+        for value in values {
+            for precision in [0, 1, 3, 10] {
+                assert_eq!(
+                    spec_f64_test(value, &format!(".{precision}f")),
+                    format!("{value:.precision$}")
+                );
+                assert_eq!(
+                    spec_f64_test(value, &format!(">+20.{precision}f")),
+                    format!("{value:>+20.precision$}")
+                );
+                assert_eq!(
+                    spec_f64_test(value, &format!("020.{precision}f")),
+                    format!("{value:020.precision$}")
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spec_non_finite() {
+        assert_eq!(spec_f64_test(f64::NAN, ">5"), "  NaN");
+        assert_eq!(spec_f64_test(f64::NEG_INFINITY, "F"), "-INF");
+        assert_eq!(spec_f64_test(f64::INFINITY, "+%"), "+inf%");
+    }
+
+    #[test]
+    fn test_spec_int_with_float_type() {
+        assert_eq!(spec_i64_test(3, ".2f"), "3.00");
+        assert_eq!(spec_i64_test(-1, "e"), "-1.000000e+00");
+    }
+
+    #[test]
+    fn test_spec_multibyte_fill() {
+        assert_eq!(spec_i64_test(7, "·>4"), "···7");
+        assert_eq!(spec_f64_test(1.5, "→<5"), "1.5→→");
+    }
+
+    #[test]
+    fn test_spec_compiled_handle() {
+        let text = ">8.2f";
+        let spec = unsafe { num_spec_new(text.as_ptr(), text.len()) };
+        assert!(!spec.is_null(), "Should compile a valid spec");
+
+        let mut buf = [0u8; 32];
+        for (value, expected) in [(1.0, "    1.00"), (-12.345, "  -12.35")] {
+            let len = unsafe { num_spec_format_f64(spec, value, buf.as_mut_ptr(), buf.len()) };
+            assert_eq!(&buf[..len], expected.as_bytes());
+        }
+        let len = unsafe { num_spec_format_f32(spec, 0.5, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"    0.50");
+        let len = unsafe { num_spec_format_i64(spec, 3, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"    3.00");
+
+        unsafe { num_spec_free(spec) };
+        unsafe { num_spec_free(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_spec_null_is_empty_spec() {
+        let mut buf = [0u8; 64];
+        let len =
+            unsafe { num_spec_format_f64(std::ptr::null(), 0.1, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"0.1");
+        let len =
+            unsafe { num_format_spec_u64(7, std::ptr::null(), 0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"7");
+    }
+
+    #[test]
+    fn test_spec_128_bit() {
+        let mut buf = [0u8; 256];
+        let spec = ",";
+        let len = unsafe {
+            num_format_spec_u128(
+                u64::MAX,
+                u64::MAX,
+                spec.as_ptr(),
+                spec.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(
+            &buf[..len],
+            b"340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+        let spec = "#_b";
+        let len = unsafe {
+            num_format_spec_i128(
+                -1,
+                u64::MAX - 14,
+                spec.as_ptr(),
+                spec.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(&buf[..len], b"-0b1111");
+    }
+
+    #[test]
+    fn test_spec_invalid() {
+        let mut buf = [0u8; 64];
+        for spec in [
+            "q",
+            "5.",
+            ".2d",
+            ",x",
+            ",g",
+            ".1075f",
+            "99999999999",
+            "4097d",
+            "4294967295d",
+            "<<<",
+            "d ",
+        ] {
+            let compiled = unsafe { num_spec_new(spec.as_ptr(), spec.len()) };
+            let len = unsafe {
+                num_format_spec_i64(1, spec.as_ptr(), spec.len(), buf.as_mut_ptr(), buf.len())
+            };
+            if compiled.is_null() {
+                assert_eq!(len, 0, "Should return 0 for malformed spec {:?}", spec);
+            } else {
+                assert_eq!(len, 0, "Should return 0 for spec {:?} on an integer", spec);
+                unsafe { num_spec_free(compiled) };
+            }
+        }
+        let spec = "x";
+        let len = unsafe {
+            num_format_spec_f64(1.0, spec.as_ptr(), spec.len(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(len, 0, "Should return 0 for an integer type on a float");
+    }
+
+    #[test]
+    fn test_spec_reports_required_length() {
+        let spec = "*^40,.2f";
+        let len =
+            unsafe { num_format_spec_f64(1e6, spec.as_ptr(), spec.len(), std::ptr::null_mut(), 0) };
+        assert_eq!(len, 40, "Should report the required length");
+    }
+}
@@ -0,0 +1,366 @@
+// ============================================================================
+// Table C FFI Wrappers - Aligned text tables from numeric matrices
+// ============================================================================
+// Format a row-major matrix in one call: every cell is formatted with its
+// column's compiled NumSpec (null for shortest round-trip / plain decimal),
+// right-aligned with spaces to the column width, and columns are separated by
+// a single space. Each row ends with `\n`. Cells wider than their column are
+// written in full. Functions return the length the output requires and write
+// it only if it is <= buf_len (snprintf-style); 0 means a column spec does
+// not apply to the value type or the shape was invalid.
+
+use std::slice;
+
+use crate::out::Out;
+use crate::pad::ITOA_MAX_WIDTH;
+use crate::spec::{NumSpec, spec_ref};
+
+/// Format and width of one table column
+#[repr(C)]
+pub struct NumTableColumn {
+    /// Compiled spec from num_spec_new, or null for the empty spec
+    pub spec: *const NumSpec,
+    /// Minimum width in characters (at most ITOA_MAX_WIDTH); cells are
+    /// right-aligned with spaces
+    pub width: u32,
+}
+
+/// Number of characters in UTF-8 text
+fn char_count(text: &[u8]) -> usize {
+    text.iter().filter(|&&byte| byte & 0xC0 != 0x80).count()
+}
+
+/// Write `rows` rows of `columns.len()` cells; false if a spec does not apply
+///
+/// # Safety
+/// - every column spec must be null or a live pointer returned by num_spec_new
+unsafe fn write_table<T: Copy>(
+    out: &mut Out,
+    values: &[T],
+    columns: &[NumTableColumn],
+    format: impl Fn(&NumSpec, &mut Out, T) -> bool,
+) -> bool {
+    // Cells are formatted here first so they can be right-aligned; the
+    // buffer only grows (and the cell is formatted again) when one is longer
+    let mut cell = vec![0u8; 64];
+    for row in values.chunks_exact(columns.len()) {
+        for (index, (&value, column)) in row.iter().zip(columns).enumerate() {
+            // Safety: guaranteed by the caller
+            let spec = unsafe { spec_ref(column.spec) };
+            let mut cell_out = Out::new(&mut cell);
+            if !format(spec, &mut cell_out, value) {
+                return false;
+            }
+            let len = cell_out.len();
+            if len > cell.len() {
+                cell.resize(len, 0);
+                format(spec, &mut Out::new(&mut cell), value);
+            }
+            let text = &cell[..len];
+
+            if index > 0 {
+                out.push(b' ');
+            }
+            for _ in char_count(text)..column.width as usize {
+                out.push(b' ');
+            }
+            out.push_bytes(text);
+        }
+        out.push(b'\n');
+    }
+    true
+}
+
+/// Shared argument handling for the exported table functions
+///
+/// # Safety
+/// - values must be valid for reads of rows * cols elements
+/// - columns must be valid for reads of cols elements whose specs are null or live
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+unsafe fn table_into<T: Copy>(
+    values: *const T,
+    rows: usize,
+    cols: usize,
+    columns: *const NumTableColumn,
+    buf: *mut u8,
+    buf_len: usize,
+    format: impl Fn(&NumSpec, &mut Out, T) -> bool,
+) -> usize {
+    let Some(count) = rows.checked_mul(cols) else {
+        return 0;
+    };
+    if cols == 0 || columns.is_null() || (values.is_null() && count > 0) {
+        return 0;
+    }
+    // Safety: guaranteed by the caller
+    let columns = unsafe { slice::from_raw_parts(columns, cols) };
+    if columns.iter().any(|column| column.width > ITOA_MAX_WIDTH) {
+        return 0;
+    }
+    let values = if count == 0 {
+        &[]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(values, count) }
+    };
+    // Safety: guaranteed by the caller
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    // Safety: guaranteed by the caller
+    if !unsafe { write_table(&mut out, values, columns, format) } {
+        return 0;
+    }
+    out.len()
+}
+
+/// Format a row-major f64 matrix as right-aligned text rows
+///
+/// # Safety
+/// - values must be valid for reads of rows * cols elements
+/// - columns must be valid for reads of cols NumTableColumn values whose
+///   specs are null or live pointers returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if cols is 0, a pointer is null, a column is wider than ITOA_MAX_WIDTH or
+/// a spec does not apply to floats
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_table_f64(
+    values: *const f64,
+    rows: usize,
+    cols: usize,
+    columns: *const NumTableColumn,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        table_into(
+            values,
+            rows,
+            cols,
+            columns,
+            buf,
+            buf_len,
+            |spec, out, value| spec.format_f64(out, value),
+        )
+    }
+}
+
+/// Format a row-major i64 matrix as right-aligned text rows
+///
+/// # Safety
+/// - values must be valid for reads of rows * cols elements
+/// - columns must be valid for reads of cols NumTableColumn values whose
+///   specs are null or live pointers returned by num_spec_new
+/// - buf must be null or a valid mutable pointer to at least buf_len bytes
+///
+/// # Returns
+/// Length of the formatted output; written only if it is <= buf_len.
+/// 0 if cols is 0, a pointer is null, a column is wider than ITOA_MAX_WIDTH or
+/// a spec does not apply to integers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_table_i64(
+    values: *const i64,
+    rows: usize,
+    cols: usize,
+    columns: *const NumTableColumn,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    // Safety: guaranteed by the caller
+    unsafe {
+        table_into(
+            values,
+            rows,
+            cols,
+            columns,
+            buf,
+            buf_len,
+            |spec, out, value| spec.format_i64(out, value),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{num_spec_free, num_spec_new};
+
+    /// Compile a spec, returning a handle the test must free
+    fn spec(text: &str) -> *mut NumSpec {
+        let spec = unsafe { num_spec_new(text.as_ptr(), text.len()) };
+        assert!(!spec.is_null(), "invalid spec {}", text);
+        spec
+    }
+
+    fn table_f64_test(values: &[f64], columns: &[NumTableColumn]) -> String {
+        let mut buf = [0u8; 256];
+        let rows = values.len() / columns.len();
+        let len = unsafe {
+            num_format_table_f64(
+                values.as_ptr(),
+                rows,
+                columns.len(),
+                columns.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert!(len > 0 && len <= buf.len(), "num_format_table_f64 failed");
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_table_f64() {
+        let fixed = spec(".2f");
+        let percent = spec(".1%");
+        let columns = [
+            NumTableColumn {
+                spec: std::ptr::null(),
+                width: 6,
+            },
+            NumTableColumn {
+                spec: fixed,
+                width: 9,
+            },
+            NumTableColumn {
+                spec: percent,
+                width: 6,
+            },
+        ];
+        let values = [1.5, 1234.567, 0.25, -0.125, 3.0, 1.0];
+        assert_eq!(
+            table_f64_test(&values, &columns),
+            "   1.5   1234.57  25.0%\n-0.125      3.00 100.0%\n"
+        );
+        unsafe {
+            num_spec_free(fixed);
+            num_spec_free(percent);
+        }
+    }
+
+    #[test]
+    fn test_table_wide_cells_are_not_truncated() {
+        let columns = [
+            NumTableColumn {
+                spec: std::ptr::null(),
+                width: 2,
+            },
+            NumTableColumn {
+                spec: std::ptr::null(),
+                width: 0,
+            },
+        ];
+        assert_eq!(table_f64_test(&[123.5, 7.0], &columns), "123.5 7.0\n");
+    }
+
+    #[test]
+    fn test_table_cells_longer_than_scratch_buffer() {
+        let wide = spec("*>100");
+        let columns = [NumTableColumn {
+            spec: wide,
+            width: 0,
+        }];
+        let expected = format!("{:*>100}\n{:*>100}\n", "1.5", "2.0");
+        assert_eq!(table_f64_test(&[1.5, 2.0], &columns), expected);
+        unsafe { num_spec_free(wide) };
+    }
+
+    #[test]
+    fn test_table_counts_characters() {
+        let grouped = spec("·>8,");
+        let columns = [NumTableColumn {
+            spec: grouped,
+            width: 10,
+        }];
+        let mut buf = [0u8; 64];
+        let values = [1234i64, -5];
+        let len = unsafe {
+            num_format_table_i64(
+                values.as_ptr(),
+                2,
+                1,
+                columns.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(
+            std::str::from_utf8(&buf[..len]).unwrap(),
+            "  ···1,234\n  ······-5\n"
+        );
+        unsafe { num_spec_free(grouped) };
+    }
+
+    #[test]
+    fn test_table_invalid_arguments() {
+        let hex = spec("x");
+        let columns = [NumTableColumn {
+            spec: hex,
+            width: 4,
+        }];
+        let mut buf = [0u8; 64];
+        let len = unsafe {
+            num_format_table_f64(
+                [1.0].as_ptr(),
+                1,
+                1,
+                columns.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(
+            len, 0,
+            "Should return 0 for a spec that does not apply to floats"
+        );
+        let len = unsafe {
+            num_format_table_f64(
+                [1.0].as_ptr(),
+                1,
+                0,
+                columns.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(len, 0, "Should return 0 for zero columns");
+        let too_wide = [NumTableColumn {
+            spec: std::ptr::null(),
+            width: ITOA_MAX_WIDTH + 1,
+        }];
+        let len = unsafe {
+            num_format_table_f64(
+                [1.0].as_ptr(),
+                1,
+                1,
+                too_wide.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        assert_eq!(len, 0, "Should return 0 for too large column width");
+        unsafe { num_spec_free(hex) };
+    }
+
+    #[test]
+    fn test_table_reports_required_length() {
+        let columns = [NumTableColumn {
+            spec: std::ptr::null(),
+            width: 8,
+        }];
+        let values = [1i64, 2, 3];
+        let len = unsafe {
+            num_format_table_i64(
+                values.as_ptr(),
+                3,
+                1,
+                columns.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        assert_eq!(len, 27, "Should report the required length");
+    }
+}
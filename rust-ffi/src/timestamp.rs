@@ -0,0 +1,649 @@
+// ============================================================================
+// Timestamp C FFI Wrappers - RFC 3339 `2026-10-16T12:34:56.123456789Z`
+// ============================================================================
+// Convert Unix time (seconds + nanoseconds, or i64 nanoseconds) to RFC 3339 /
+// ISO 8601 text and back, using the proleptic Gregorian calendar without
+// leap seconds. Years are limited to the four digits RFC 3339 allows
+// (0000-9999). Like rust_itoa_*, the formatters return the number of bytes
+// written, or 0 if the buffer was too small or the arguments were invalid;
+// NUM_TIMESTAMP_BUFFER_SIZE fits every output. The parsers follow the
+// rust_atoi_* contract: they read a timestamp at the start of the input and
+// return a NUM_PARSE_* code.
+
+use std::slice;
+
+use crate::out::Out;
+use crate::parse::{
+    NUM_PARSE_EMPTY, NUM_PARSE_INVALID, NUM_PARSE_NEG_OVERFLOW, NUM_PARSE_OK, NUM_PARSE_OVERFLOW,
+};
+
+/// Buffer size that fits any timestamp (`9999-12-31T23:59:59.999999999+23:59`)
+pub const NUM_TIMESTAMP_BUFFER_SIZE: usize = 35;
+
+/// Options for the timestamp formatters
+///
+/// A null pointer behaves like a zeroed struct: whole seconds in UTC with a
+/// `Z` suffix.
+#[repr(C)]
+pub struct NumTimestampOptions {
+    /// Fraction digits (0..=9, truncated), or -1 for as many as needed
+    pub fraction_digits: i8,
+    /// Offset from UTC in minutes (-1439..=1439); the time is shown in that zone
+    pub offset_minutes: i16,
+    /// Write `+00:00` instead of `Z` for a zero offset
+    pub numeric_utc: bool,
+}
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+const MAX_OFFSET_MINUTES: i16 = 23 * 60 + 59;
+
+/// Unix time of 0000-01-01T00:00:00
+const MIN_SECONDS: i64 = -62_167_219_200;
+
+/// Unix time of 9999-12-31T23:59:59
+const MAX_SECONDS: i64 = 253_402_300_799;
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian (year, month, day) of a day count since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Push `value` as exactly `width` decimal digits
+fn push_digits(out: &mut Out, value: u32, width: u32) {
+    for place in (0..width).rev() {
+        out.push(b'0' + (value / 10u32.pow(place) % 10) as u8);
+    }
+}
+
+/// Write an RFC 3339 timestamp; false if the options or time are out of range
+fn write_timestamp(out: &mut Out, seconds: i64, nanos: u32, options: &NumTimestampOptions) -> bool {
+    let offset = options.offset_minutes;
+    if !(-1..=9).contains(&options.fraction_digits)
+        || !(-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES).contains(&offset)
+        || i64::from(nanos) >= NANOS_PER_SECOND
+    {
+        return false;
+    }
+    let Some(local) = seconds.checked_add(i64::from(offset) * 60) else {
+        return false;
+    };
+    if !(MIN_SECONDS..=MAX_SECONDS).contains(&local) {
+        return false;
+    }
+
+    let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+    let time = local.rem_euclid(SECONDS_PER_DAY) as u32;
+    push_digits(out, year as u32, 4);
+    out.push(b'-');
+    push_digits(out, month as u32, 2);
+    out.push(b'-');
+    push_digits(out, day as u32, 2);
+    out.push(b'T');
+    push_digits(out, time / 3600, 2);
+    out.push(b':');
+    push_digits(out, time / 60 % 60, 2);
+    out.push(b':');
+    push_digits(out, time % 60, 2);
+
+    let (fraction, width) = match options.fraction_digits {
+        -1 if nanos == 0 => (0, 0),
+        -1 => {
+            let (mut fraction, mut width) = (nanos, 9);
+            while fraction.is_multiple_of(10) {
+                fraction /= 10;
+                width -= 1;
+            }
+            (fraction, width)
+        }
+        digits => {
+            let width = digits as u32;
+            (nanos / 10u32.pow(9 - width), width)
+        }
+    };
+    if width > 0 {
+        out.push(b'.');
+        push_digits(out, fraction, width);
+    }
+
+    if offset == 0 && !options.numeric_utc {
+        out.push(b'Z');
+    } else {
+        out.push(if offset < 0 { b'-' } else { b'+' });
+        let magnitude = offset.unsigned_abs() as u32;
+        push_digits(out, magnitude / 60, 2);
+        out.push(b':');
+        push_digits(out, magnitude % 60, 2);
+    }
+    debug_assert!(out.len() <= NUM_TIMESTAMP_BUFFER_SIZE);
+    true
+}
+
+/// Format Unix seconds and nanoseconds as an RFC 3339 timestamp
+///
+/// # Safety
+/// - options must be null or point to a valid NumTimestampOptions
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= NUM_TIMESTAMP_BUFFER_SIZE for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if the buffer was too small,
+/// nanos is not below 1e9, the options are out of range or the local year is
+/// outside 0000-9999
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_timestamp(
+    seconds: i64,
+    nanos: u32,
+    options: *const NumTimestampOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    const UTC: NumTimestampOptions = NumTimestampOptions {
+        fraction_digits: 0,
+        offset_minutes: 0,
+        numeric_utc: false,
+    };
+    // Safety: guaranteed by the caller
+    let options = unsafe { options.as_ref() }.unwrap_or(&UTC);
+    // Safety: buf is valid for buf_len bytes
+    let mut out = unsafe { Out::from_raw(buf, buf_len) };
+    if !write_timestamp(&mut out, seconds, nanos, options) {
+        return 0;
+    }
+    out.written_or_zero()
+}
+
+/// Format Unix time in nanoseconds as an RFC 3339 timestamp
+///
+/// # Safety
+/// - options must be null or point to a valid NumTimestampOptions
+/// - buf must be a valid mutable pointer to at least buf_len bytes
+/// - buf_len should be >= NUM_TIMESTAMP_BUFFER_SIZE for guaranteed success
+///
+/// # Returns
+/// Number of bytes written to buffer, or 0 if the buffer was too small or the
+/// options are out of range
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_format_timestamp_ns(
+    nanoseconds: i64,
+    options: *const NumTimestampOptions,
+    buf: *mut u8,
+    buf_len: usize,
+) -> usize {
+    let seconds = nanoseconds.div_euclid(NANOS_PER_SECOND);
+    let nanos = nanoseconds.rem_euclid(NANOS_PER_SECOND) as u32;
+    // Safety: guaranteed by the caller
+    unsafe { num_format_timestamp(seconds, nanos, options, buf, buf_len) }
+}
+
+/// A timestamp read from text
+struct ParsedTimestamp {
+    /// Unix seconds (UTC)
+    seconds: i64,
+    nanos: u32,
+    offset_minutes: i16,
+    consumed: usize,
+}
+
+/// Value of an all-digit field, None if any byte is not a digit
+fn digits(field: &[u8]) -> Option<i64> {
+    field.iter().try_fold(0i64, |value, &b| {
+        b.is_ascii_digit().then(|| value * 10 + i64::from(b - b'0'))
+    })
+}
+
+/// Read `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` at the start of `bytes`
+///
+/// `T` and `Z` may be lowercase as RFC 3339 allows. The fraction has 1 to 9
+/// digits; leap seconds (`:60`) are rejected.
+fn scan_timestamp(bytes: &[u8]) -> Option<ParsedTimestamp> {
+    // Everything up to the seconds has fixed positions
+    let head = bytes.get(..19)?;
+    let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+    if separators
+        .iter()
+        .any(|&(at, separator)| !head[at].eq_ignore_ascii_case(&separator))
+    {
+        return None;
+    }
+    let year = digits(&head[0..4])?;
+    let month = digits(&head[5..7])?;
+    let day = digits(&head[8..10])?;
+    let hour = digits(&head[11..13])?;
+    let minute = digits(&head[14..16])?;
+    let second = digits(&head[17..19])?;
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let mut i = head.len();
+    let mut nanos = 0u32;
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        let start = i;
+        while let Some(&b) = bytes.get(i).filter(|b| b.is_ascii_digit()) {
+            if i - start == 9 {
+                return None;
+            }
+            nanos = nanos * 10 + u32::from(b - b'0');
+            i += 1;
+        }
+        let width = i - start;
+        if width == 0 {
+            return None;
+        }
+        nanos *= 10u32.pow(9 - width as u32);
+    }
+
+    let offset_minutes = match bytes.get(i)? {
+        b'Z' | b'z' => {
+            i += 1;
+            0
+        }
+        &sign @ (b'+' | b'-') => {
+            let field = bytes.get(i + 1..i + 6)?;
+            let hours = digits(&field[0..2]).filter(|&hours| hours <= 23)?;
+            let minutes = digits(&field[3..5]).filter(|&minutes| minutes <= 59)?;
+            if field[2] != b':' {
+                return None;
+            }
+            i += 6;
+            let offset = (hours * 60 + minutes) as i16;
+            if sign == b'-' { -offset } else { offset }
+        }
+        _ => return None,
+    };
+
+    let local =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second;
+    Some(ParsedTimestamp {
+        seconds: local - i64::from(offset_minutes) * 60,
+        nanos,
+        offset_minutes,
+        consumed: i,
+    })
+}
+
+/// Scan a C buffer and store the bytes consumed
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_consumed must be null or valid for writes
+unsafe fn scan_into(
+    buf: *const u8,
+    buf_len: usize,
+    out_consumed: *mut usize,
+) -> Result<ParsedTimestamp, i32> {
+    let bytes = if buf.is_null() {
+        &[][..]
+    } else {
+        // Safety: guaranteed by the caller
+        unsafe { slice::from_raw_parts(buf, buf_len) }
+    };
+    let scanned = if bytes.is_empty() {
+        Err(NUM_PARSE_EMPTY)
+    } else {
+        scan_timestamp(bytes).ok_or(NUM_PARSE_INVALID)
+    };
+
+    // Safety: out_consumed is null or valid for writes
+    if let Some(out_consumed) = unsafe { out_consumed.as_mut() } {
+        *out_consumed = scanned.as_ref().map_or(0, |parsed| parsed.consumed);
+    }
+    scanned
+}
+
+/// Parse the RFC 3339 timestamp at the start of buf as Unix seconds and nanoseconds
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_seconds, out_nanos, out_offset_minutes and out_consumed must each be
+///   null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_OK, NUM_PARSE_EMPTY or NUM_PARSE_INVALID. On success the out
+/// pointers receive the UTC Unix time, the offset the text was written in and
+/// the bytes parsed; on failure they receive 0
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_parse_timestamp(
+    buf: *const u8,
+    buf_len: usize,
+    out_seconds: *mut i64,
+    out_nanos: *mut u32,
+    out_offset_minutes: *mut i16,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { scan_into(buf, buf_len, out_consumed) };
+    let (seconds, nanos, offset) = match &scanned {
+        Ok(parsed) => (parsed.seconds, parsed.nanos, parsed.offset_minutes),
+        Err(_) => (0, 0, 0),
+    };
+    // Safety: the out pointers are null or valid for writes
+    unsafe {
+        if let Some(out_seconds) = out_seconds.as_mut() {
+            *out_seconds = seconds;
+        }
+        if let Some(out_nanos) = out_nanos.as_mut() {
+            *out_nanos = nanos;
+        }
+        if let Some(out_offset_minutes) = out_offset_minutes.as_mut() {
+            *out_offset_minutes = offset;
+        }
+    }
+    scanned.map_or_else(|code| code, |_| NUM_PARSE_OK)
+}
+
+/// Parse the RFC 3339 timestamp at the start of buf as Unix nanoseconds
+///
+/// # Safety
+/// - buf must be null or a valid pointer to at least buf_len bytes
+/// - out_nanoseconds and out_consumed must each be null or valid for writes
+///
+/// # Returns
+/// NUM_PARSE_* code. out_nanoseconds receives the value (0 on EMPTY/INVALID,
+/// i64::MIN/i64::MAX on NEG_OVERFLOW/OVERFLOW outside 1677-2262) and
+/// out_consumed the bytes parsed
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn num_parse_timestamp_ns(
+    buf: *const u8,
+    buf_len: usize,
+    out_nanoseconds: *mut i64,
+    out_consumed: *mut usize,
+) -> i32 {
+    // Safety: buf and out_consumed are guaranteed by the caller
+    let scanned = unsafe { scan_into(buf, buf_len, out_consumed) };
+    let (code, value) = match scanned {
+        Err(code) => (code, 0),
+        Ok(parsed) => {
+            let wide = i128::from(parsed.seconds) * i128::from(NANOS_PER_SECOND)
+                + i128::from(parsed.nanos);
+            match i64::try_from(wide) {
+                Ok(value) => (NUM_PARSE_OK, value),
+                Err(_) if wide < 0 => (NUM_PARSE_NEG_OVERFLOW, i64::MIN),
+                Err(_) => (NUM_PARSE_OVERFLOW, i64::MAX),
+            }
+        }
+    };
+    // Safety: out_nanoseconds is null or valid for writes
+    if let Some(out_nanoseconds) = unsafe { out_nanoseconds.as_mut() } {
+        *out_nanoseconds = value;
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-10-16T12:34:56Z
+    const SECONDS: i64 = 1_792_154_096;
+
+    fn options(fraction_digits: i8, offset_minutes: i16) -> NumTimestampOptions {
+        NumTimestampOptions {
+            fraction_digits,
+            offset_minutes,
+            numeric_utc: false,
+        }
+    }
+
+    fn timestamp_test(seconds: i64, nanos: u32, options: *const NumTimestampOptions) -> String {
+        let mut buf = [0u8; NUM_TIMESTAMP_BUFFER_SIZE];
+        let len =
+            unsafe { num_format_timestamp(seconds, nanos, options, buf.as_mut_ptr(), buf.len()) };
+        assert!(
+            len > 0,
+            "num_format_timestamp failed for value: {}",
+            seconds
+        );
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    fn parse_test(text: &str) -> (i32, i64, u32, i16, usize) {
+        let (mut seconds, mut nanos, mut offset, mut consumed) = (0, 0, 0, 0);
+        let code = unsafe {
+            num_parse_timestamp(
+                text.as_ptr(),
+                text.len(),
+                &mut seconds,
+                &mut nanos,
+                &mut offset,
+                &mut consumed,
+            )
+        };
+        (code, seconds, nanos, offset, consumed)
+    }
+
+    #[test]
+    fn test_timestamp_utc() {
+        let utc = std::ptr::null();
+        assert_eq!(timestamp_test(SECONDS, 0, utc), "2026-10-16T12:34:56Z");
+        assert_eq!(timestamp_test(0, 0, utc), "1970-01-01T00:00:00Z");
+        assert_eq!(timestamp_test(-1, 0, utc), "1969-12-31T23:59:59Z");
+        assert_eq!(timestamp_test(951_782_400, 0, utc), "2000-02-29T00:00:00Z");
+        assert_eq!(timestamp_test(MIN_SECONDS, 0, utc), "0000-01-01T00:00:00Z");
+        assert_eq!(timestamp_test(MAX_SECONDS, 0, utc), "9999-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn test_timestamp_fraction_digits() {
+        let nanos = 123_456_789;
+        assert_eq!(
+            timestamp_test(SECONDS, nanos, &options(9, 0)),
+            "2026-10-16T12:34:56.123456789Z"
+        );
+        assert_eq!(
+            timestamp_test(SECONDS, nanos, &options(3, 0)),
+            "2026-10-16T12:34:56.123Z"
+        );
+        assert_eq!(
+            timestamp_test(SECONDS, 500_000_000, &options(-1, 0)),
+            "2026-10-16T12:34:56.5Z"
+        );
+        assert_eq!(
+            timestamp_test(SECONDS, 0, &options(-1, 0)),
+            "2026-10-16T12:34:56Z"
+        );
+        assert_eq!(
+            timestamp_test(SECONDS, 999_999_999, &options(1, 0)),
+            "2026-10-16T12:34:56.9Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_offsets() {
+        assert_eq!(
+            timestamp_test(SECONDS, 0, &options(0, 330)),
+            "2026-10-16T18:04:56+05:30"
+        );
+        assert_eq!(
+            timestamp_test(SECONDS, 0, &options(0, -13 * 60)),
+            "2026-10-15T23:34:56-13:00"
+        );
+        let mut numeric = options(0, 0);
+        numeric.numeric_utc = true;
+        assert_eq!(
+            timestamp_test(SECONDS, 0, &numeric),
+            "2026-10-16T12:34:56+00:00"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_ns() {
+        let mut buf = [0u8; NUM_TIMESTAMP_BUFFER_SIZE];
+        let len =
+            unsafe { num_format_timestamp_ns(-1, &options(-1, 0), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(&buf[..len], b"1969-12-31T23:59:59.999999999Z");
+        let len = unsafe {
+            num_format_timestamp_ns(i64::MAX, &options(-1, 0), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(&buf[..len], b"2262-04-11T23:47:16.854775807Z");
+    }
+
+    #[test]
+    fn test_timestamp_invalid() {
+        let mut buf = [0u8; NUM_TIMESTAMP_BUFFER_SIZE];
+        let cases = [
+            (SECONDS, 1_000_000_000, options(0, 0)),
+            (SECONDS, 0, options(10, 0)),
+            (SECONDS, 0, options(-2, 0)),
+            (SECONDS, 0, options(0, 24 * 60)),
+            (MAX_SECONDS + 1, 0, options(0, 0)),
+            (MAX_SECONDS, 0, options(0, 1)),
+            (MIN_SECONDS - 1, 0, options(0, 0)),
+            (i64::MAX, 0, options(0, 60)),
+        ];
+        for (seconds, nanos, options) in cases {
+            let len = unsafe {
+                num_format_timestamp(seconds, nanos, &options, buf.as_mut_ptr(), buf.len())
+            };
+            assert_eq!(len, 0, "Should return 0 for out of range arguments");
+        }
+    }
+
+    #[test]
+    fn test_timestamp_buffer_too_small() {
+        let mut buf = [0u8; 19];
+        let len = unsafe {
+            num_format_timestamp(SECONDS, 0, std::ptr::null(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(len, 0, "Should return 0 when buffer is too small");
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(
+            parse_test("2026-10-16T12:34:56.123456789Z"),
+            (NUM_PARSE_OK, SECONDS, 123_456_789, 0, 30)
+        );
+        assert_eq!(
+            parse_test("2026-10-16t18:04:56.5+05:30 GET /"),
+            (NUM_PARSE_OK, SECONDS, 500_000_000, 330, 27)
+        );
+        assert_eq!(
+            parse_test("2026-10-15T23:34:56-13:00"),
+            (NUM_PARSE_OK, SECONDS, 0, -780, 25)
+        );
+        assert_eq!(parse_test("2000-02-29T00:00:00z").1, 951_782_400);
+    }
+
+    #[test]
+    fn test_parse_timestamp_strict() {
+        assert_eq!(parse_test(""), (NUM_PARSE_EMPTY, 0, 0, 0, 0));
+        for text in [
+            "2026-10-16T12:34:56",
+            "2026-10-16 12:34:56Z",
+            "2026-10-16T12:34Z",
+            "26-10-16T12:34:56Z",
+            "2026-13-01T00:00:00Z",
+            "2026-02-29T00:00:00Z",
+            "1900-02-29T00:00:00Z",
+            "2026-10-16T24:00:00Z",
+            "2026-10-16T12:34:60Z",
+            "2026-10-16T12:34:56.Z",
+            "2026-10-16T12:34:56.1234567891Z",
+            "2026-10-16T12:34:56.9999999999Z",
+            "2026-10-16T12:34:56+0530",
+            "2026-10-16T12:34:56+24:00",
+        ] {
+            assert_eq!(
+                parse_test(text),
+                (NUM_PARSE_INVALID, 0, 0, 0, 0),
+                "Should reject {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_roundtrips() {
+        let mut buf = [0u8; NUM_TIMESTAMP_BUFFER_SIZE];
+        for (seconds, nanos, offset) in [
+            (SECONDS, 123_456_789, 0),
+            (MIN_SECONDS, 1, 0),
+            (MAX_SECONDS - 3600, 999_999_999, 59),
+            (-1, 0, -90),
+        ] {
+            let len = unsafe {
+                num_format_timestamp(
+                    seconds,
+                    nanos,
+                    &options(9, offset),
+                    buf.as_mut_ptr(),
+                    buf.len(),
+                )
+            };
+            let text = std::str::from_utf8(&buf[..len]).unwrap();
+            assert_eq!(
+                parse_test(text),
+                (NUM_PARSE_OK, seconds, nanos, offset, len)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_ns() {
+        let parse = |text: &str| {
+            let mut value = 0;
+            let code = unsafe {
+                num_parse_timestamp_ns(text.as_ptr(), text.len(), &mut value, std::ptr::null_mut())
+            };
+            (code, value)
+        };
+        assert_eq!(
+            parse("1970-01-01T00:00:01.5Z"),
+            (NUM_PARSE_OK, 1_500_000_000)
+        );
+        assert_eq!(
+            parse("2262-04-11T23:47:16.854775807Z"),
+            (NUM_PARSE_OK, i64::MAX)
+        );
+        assert_eq!(
+            parse("2262-04-11T23:47:16.854775808Z"),
+            (NUM_PARSE_OVERFLOW, i64::MAX)
+        );
+        assert_eq!(
+            parse("1600-01-01T00:00:00Z"),
+            (NUM_PARSE_NEG_OVERFLOW, i64::MIN)
+        );
+        assert_eq!(parse("soon"), (NUM_PARSE_INVALID, 0));
+    }
+}